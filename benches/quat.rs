@@ -4,7 +4,7 @@ mod macros;
 mod support;
 
 use criterion::{criterion_group, criterion_main, Criterion};
-use glam::Quat;
+use glam::{Quat, Vec3, Vec3A};
 use std::ops::Mul;
 use support::*;
 
@@ -65,6 +65,31 @@ bench_trinop!(
 
 bench_from_ypr!(quat_from_ypr, "quat from ypr", ty => Quat);
 
+fn quat_rotate_vec3a_slice(c: &mut Criterion) {
+    const SIZE: usize = 1 << 13;
+    let mut rng = support::PCG32::default();
+    let q = random_quat(&mut rng);
+    let inputs = (0..SIZE)
+        .map(|_| random_vec3a(&mut rng))
+        .collect::<Vec<Vec3A>>();
+    let mut outputs = vec![Vec3A::ZERO; SIZE];
+    c.bench_function("quat rotate vec3a slice", |b| {
+        b.iter(|| {
+            for (o, i) in outputs.iter_mut().zip(criterion::black_box(&inputs)) {
+                *o = q * *i;
+            }
+        })
+    });
+    c.bench_function("quat rotate vec3a slice via vec3", |b| {
+        b.iter(|| {
+            for (o, i) in outputs.iter_mut().zip(criterion::black_box(&inputs)) {
+                *o = (q * Vec3::from(*i)).into();
+            }
+        })
+    });
+    criterion::black_box(outputs);
+}
+
 criterion_group!(
     benches,
     quat_conjugate,
@@ -74,7 +99,8 @@ criterion_group!(
     quat_mul_quat,
     quat_mul_vec3,
     quat_mul_vec3a,
-    quat_from_ypr
+    quat_from_ypr,
+    quat_rotate_vec3a_slice
 );
 
 criterion_main!(benches);
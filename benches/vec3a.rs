@@ -95,11 +95,58 @@ bench_select!(
     from => random_vec3a
 );
 
+#[inline]
+fn vec3a_normalize_dot(v: Vec3A) -> Vec3A {
+    v * (1.0 / v.dot(v).sqrt())
+}
+
+#[inline]
+fn vec3a_div_dot(v: Vec3A) -> Vec3A {
+    v / v.dot(v)
+}
+
+#[inline]
+fn vec3a_div_dot_into_vec(v: Vec3A) -> Vec3A {
+    v / v.dot_into_vec(v)
+}
+
+bench_unop!(
+    vec3a_normalize,
+    "vec3a normalize",
+    op => normalize,
+    from => random_vec3a
+);
+
+bench_func!(
+    vec3a_normalize_scalar_dot,
+    "vec3a normalize scalar dot",
+    op => vec3a_normalize_dot,
+    from => random_vec3a
+);
+
+bench_func!(
+    vec3a_div_by_dot,
+    "vec3a div by dot",
+    op => vec3a_div_dot,
+    from => random_vec3a
+);
+
+bench_func!(
+    vec3a_div_by_dot_into_vec,
+    "vec3a div by dot_into_vec",
+    op => vec3a_div_dot_into_vec,
+    from => random_vec3a
+);
+
 criterion_group!(
     benches,
     vec3a_mul_vec3a,
     vec3a_angle_between,
+    vec3a_div_by_dot,
+    vec3a_div_by_dot_into_vec,
     vec3a_euler,
+    vec3a_normalize,
+    vec3a_normalize_scalar_dot,
     vec3a_select,
     vec3a_to_array_deref,
     vec3a_to_array_into,
@@ -24,6 +24,57 @@ bench_select!(
     from => random_vec4
 );
 
-criterion_group!(benches, vec4_mul_vec4, vec4_select);
+#[inline]
+fn vec4_normalize_dot(v: Vec4) -> Vec4 {
+    v * (1.0 / v.dot(v).sqrt())
+}
+
+#[inline]
+fn vec4_div_dot(v: Vec4) -> Vec4 {
+    v / v.dot(v)
+}
+
+#[inline]
+fn vec4_div_dot_into_vec(v: Vec4) -> Vec4 {
+    v / v.dot_into_vec(v)
+}
+
+bench_unop!(
+    vec4_normalize,
+    "vec4 normalize",
+    op => normalize,
+    from => random_vec4
+);
+
+bench_func!(
+    vec4_normalize_scalar_dot,
+    "vec4 normalize scalar dot",
+    op => vec4_normalize_dot,
+    from => random_vec4
+);
+
+bench_func!(
+    vec4_div_by_dot,
+    "vec4 div by dot",
+    op => vec4_div_dot,
+    from => random_vec4
+);
+
+bench_func!(
+    vec4_div_by_dot_into_vec,
+    "vec4 div by dot_into_vec",
+    op => vec4_div_dot_into_vec,
+    from => random_vec4
+);
+
+criterion_group!(
+    benches,
+    vec4_mul_vec4,
+    vec4_select,
+    vec4_normalize,
+    vec4_normalize_scalar_dot,
+    vec4_div_by_dot,
+    vec4_div_by_dot_into_vec,
+);
 
 criterion_main!(benches);
@@ -5,7 +5,12 @@ use core::fmt;
 use core::ops::*;
 
 /// A 2-dimensional `bool` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec2::select()`].
+///
+/// [`Vec2::select()`]: crate::Vec2::select
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(1))]
 pub struct BVec2 {
     pub x: bool,
@@ -72,6 +77,15 @@ impl Default for BVec2 {
     }
 }
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
+impl core::hash::Hash for BVec2 {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec2 {
     type Output = Self;
     #[inline]
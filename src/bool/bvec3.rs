@@ -5,7 +5,12 @@ use core::fmt;
 use core::ops::*;
 
 /// A 3-dimensional `bool` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec3::select()`].
+///
+/// [`Vec3::select()`]: crate::Vec3::select
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(1))]
 pub struct BVec3 {
     pub x: bool,
@@ -77,6 +82,15 @@ impl Default for BVec3 {
     }
 }
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
+impl core::hash::Hash for BVec3 {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec3 {
     type Output = Self;
     #[inline]
@@ -5,7 +5,12 @@ use core::fmt;
 use core::ops::*;
 
 /// A 4-dimensional `bool` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec4::select()`].
+///
+/// [`Vec4::select()`]: crate::Vec4::select
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(1))]
 pub struct BVec4 {
     pub x: bool,
@@ -79,6 +84,15 @@ impl Default for BVec4 {
     }
 }
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
+impl core::hash::Hash for BVec4 {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec4 {
     type Output = Self;
     #[inline]
@@ -5,7 +5,12 @@ use core::fmt;
 use core::ops::*;
 
 /// A 3-dimensional `u32` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec3A::select()`].
+///
+/// [`Vec3A::select()`]: crate::Vec3A::select
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(16))]
 pub struct BVec3A {
     pub x: u32,
@@ -81,6 +86,15 @@ impl Default for BVec3A {
     }
 }
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
+impl core::hash::Hash for BVec3A {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec3A {
     type Output = Self;
     #[inline]
@@ -5,7 +5,12 @@ use core::fmt;
 use core::ops::*;
 
 /// A 4-dimensional `u32` vector mask.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec4::select()`].
+///
+/// [`Vec4::select()`]: crate::Vec4::select
+#[derive(Clone, Copy, PartialEq, Eq)]
 #[repr(C, align(16))]
 pub struct BVec4A {
     pub x: u32,
@@ -84,6 +89,15 @@ impl Default for BVec4A {
     }
 }
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
+impl core::hash::Hash for BVec4A {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bitmask().hash(state);
+    }
+}
+
 impl BitAnd for BVec4A {
     type Output = Self;
     #[inline]
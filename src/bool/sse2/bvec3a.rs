@@ -17,6 +17,11 @@ union UnionCast {
 /// A 3-dimensional SIMD vector mask.
 ///
 /// This type is 16 byte aligned.
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec3A::select()`].
+///
+/// [`Vec3A::select()`]: crate::Vec3A::select
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct BVec3A(pub(crate) __m128);
@@ -101,6 +106,8 @@ impl PartialEq for BVec3A {
 
 impl Eq for BVec3A {}
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
 impl core::hash::Hash for BVec3A {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
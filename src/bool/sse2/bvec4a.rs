@@ -17,6 +17,11 @@ union UnionCast {
 /// A 4-dimensional SIMD vector mask.
 ///
 /// This type is 16 byte aligned.
+///
+/// Masks are returned by the comparison methods of the vector types and can be used to blend
+/// two vectors element-wise without branching, see [`Vec4::select()`].
+///
+/// [`Vec4::select()`]: crate::Vec4::select
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct BVec4A(pub(crate) __m128);
@@ -112,6 +117,8 @@ impl PartialEq for BVec4A {
 
 impl Eq for BVec4A {}
 
+/// Hashes the [`Self::bitmask()`] so that equal masks hash the same regardless of the
+/// underlying storage.
 impl core::hash::Hash for BVec4A {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
@@ -0,0 +1,47 @@
+/*
+Errors for the checked conversions between integer vector types.
+*/
+
+use core::fmt;
+
+/// An error returned when a checked conversion between integer vector types fails because an
+/// element is out of range of the target type.
+///
+/// The error records the index of the first element that is out of range.
+///
+/// # Example
+///
+/// ```
+/// use glam::{IVec3, UVec3};
+///
+/// let err = UVec3::try_from(IVec3::new(1, -2, -3)).unwrap_err();
+/// assert_eq!(err.index(), 1);
+/// assert_eq!(err.to_string(), "element `y` is out of range of the target type");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TryFromIntVecError {
+    index: usize,
+}
+
+impl TryFromIntVecError {
+    #[inline]
+    pub(crate) const fn new(index: usize) -> Self {
+        Self { index }
+    }
+
+    /// Returns the index of the first element that is out of range, e.g. `0` for `x`.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for TryFromIntVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = ["x", "y", "z", "w"][self.index];
+        write!(f, "element `{}` is out of range of the target type", name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntVecError {}
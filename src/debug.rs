@@ -0,0 +1,68 @@
+/*
+Alternate `Debug` formatting shared by the matrix types.
+*/
+
+use core::fmt::{self, Write};
+
+/// Counts the characters written to it, used to measure formatted elements without
+/// allocating.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+fn write_element<W: Write, T: fmt::Debug>(
+    w: &mut W,
+    value: &T,
+    width: usize,
+    precision: Option<usize>,
+) -> fmt::Result {
+    match precision {
+        Some(precision) => write!(w, "{:>width$.precision$?}", value),
+        None => write!(w, "{:>width$?}", value),
+    }
+}
+
+/// Writes a matrix with one labelled column per line, e.g.
+///
+/// ```text
+/// Mat2 {
+///     x_axis: Vec2(1.0, -2.0),
+///     y_axis: Vec2(3.5,  4.0),
+/// }
+/// ```
+///
+/// Elements are right aligned to the widest element so rows line up. The formatter's
+/// precision is applied to every element.
+pub(crate) fn fmt_matrix_alternate<T: fmt::Debug, const C: usize, const R: usize>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    col_name: &str,
+    axes: [&str; C],
+    cols: [[T; R]; C],
+) -> fmt::Result {
+    let precision = f.precision();
+    let mut width = 0;
+    for value in cols.iter().flatten() {
+        let mut count = CharCount(0);
+        write_element(&mut count, value, 0, precision)?;
+        width = width.max(count.0);
+    }
+
+    writeln!(f, "{} {{", name)?;
+    for (axis, col) in axes.iter().zip(cols.iter()) {
+        write!(f, "    {}: {}(", axis, col_name)?;
+        for (i, value) in col.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write_element(f, value, width, precision)?;
+        }
+        f.write_str("),\n")?;
+    }
+    f.write_str("}")
+}
@@ -136,6 +136,37 @@ macro_rules! impl_from_quat {
                     ),
                 }
             }
+
+            fn convert_quat(self, q: $quat) -> ($t, $t, $t) {
+                use crate::$t::math;
+                use EulerRot::*;
+                let second = self.second(q);
+                // At gimbal lock the first and third axes are aligned so only their combined
+                // rotation can be recovered. Assign all of it to the first axis, as the inputs
+                // to `first` and `third` are close to zero and dominated by rounding error.
+                if math::abs(second) >= core::$t::consts::FRAC_PI_2 - 1e-6 {
+                    // remove the second rotation, leaving a rotation about the first axis
+                    let r = q * match self {
+                        ZXY | YXZ => $quat::from_rotation_x(-second),
+                        ZYX | XYZ => $quat::from_rotation_y(-second),
+                        YZX | XZY => $quat::from_rotation_z(-second),
+                    };
+                    let s = match self {
+                        XYZ | XZY => r.x,
+                        YXZ | YZX => r.y,
+                        ZYX | ZXY => r.z,
+                    };
+                    // use the same hemisphere for `r` and `-r` so the angle is in `[-PI, PI]`
+                    let first = if r.w < 0.0 {
+                        2.0 * math::atan2(-s, -r.w)
+                    } else {
+                        2.0 * math::atan2(s, r.w)
+                    };
+                    (first, second, 0.0)
+                } else {
+                    (self.first(q), second, self.third(q))
+                }
+            }
         }
         // End - impl EulerFromQuaternion
     };
@@ -46,11 +46,12 @@ impl Affine2 {
 
     /// Creates an affine transform from a `[f32; 6]` array stored in column major order.
     #[inline]
-    pub fn from_cols_array(m: &[f32; 6]) -> Self {
-        Self {
-            matrix2: Mat2::from_cols_slice(&m[0..4]),
-            translation: Vec2::from_slice(&m[4..6]),
-        }
+    pub const fn from_cols_array(m: &[f32; 6]) -> Self {
+        Self::from_cols(
+            Vec2::new(m[0], m[1]),
+            Vec2::new(m[2], m[3]),
+            Vec2::new(m[4], m[5]),
+        )
     }
 
     /// Creates a `[f32; 6]` array storing data in column major order.
@@ -67,11 +68,12 @@ impl Affine2 {
     /// If your data is in row major order you will need to `transpose` the returned
     /// matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f32; 2]; 3]) -> Self {
-        Self {
-            matrix2: Mat2::from_cols(m[0].into(), m[1].into()),
-            translation: m[2].into(),
-        }
+    pub const fn from_cols_array_2d(m: &[[f32; 2]; 3]) -> Self {
+        Self::from_cols(
+            Vec2::from_array(m[0]),
+            Vec2::from_array(m[1]),
+            Vec2::from_array(m[2]),
+        )
     }
 
     /// Creates a `[[f32; 2]; 3]` 2D array storing data in
@@ -187,6 +189,7 @@ impl Affine2 {
     }
 
     /// The given `Mat3` must be an affine transform,
+    /// i.e. contain no perspective transform.
     #[inline]
     pub fn from_mat3(m: Mat3) -> Self {
         use crate::swizzles::Vec3Swizzles;
@@ -197,6 +200,7 @@ impl Affine2 {
     }
 
     /// The given [`Mat3A`] must be an affine transform,
+    /// i.e. contain no perspective transform.
     #[inline]
     pub fn from_mat3a(m: Mat3A) -> Self {
         use crate::swizzles::Vec3Swizzles;
@@ -307,14 +311,31 @@ impl core::fmt::Debug for Affine2 {
     }
 }
 
+/// Formats the transform as a list of the columns of `matrix2` followed by
+/// `translation`, so each inner array is a column and not a row.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for Affine2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "[{}, {}, {}]",
-            self.matrix2.x_axis, self.matrix2.y_axis, self.translation
-        )
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        core::fmt::Display::fmt(&self.matrix2.x_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.matrix2.y_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.translation, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Affine2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis] = crate::parse::parse_array::<Vec2, 3>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis))
     }
 }
 
@@ -350,6 +371,15 @@ impl From<Affine2> for Mat3 {
     }
 }
 
+/// The given `Mat3` must be an affine transform, i.e. contain no perspective transform.
+/// See [`Affine2::from_mat3()`].
+impl From<Mat3> for Affine2 {
+    #[inline]
+    fn from(m: Mat3) -> Affine2 {
+        Self::from_mat3(m)
+    }
+}
+
 impl Mul<Mat3> for Affine2 {
     type Output = Mat3;
 
@@ -379,6 +409,15 @@ impl From<Affine2> for Mat3A {
     }
 }
 
+/// The given [`Mat3A`] must be an affine transform, i.e. contain no perspective transform.
+/// See [`Affine2::from_mat3a()`].
+impl From<Mat3A> for Affine2 {
+    #[inline]
+    fn from(m: Mat3A) -> Affine2 {
+        Self::from_mat3a(m)
+    }
+}
+
 impl Mul<Mat3A> for Affine2 {
     type Output = Mat3A;
 
@@ -48,11 +48,13 @@ impl Affine3A {
 
     /// Creates an affine transform from a `[f32; 12]` array stored in column major order.
     #[inline]
-    pub fn from_cols_array(m: &[f32; 12]) -> Self {
-        Self {
-            matrix3: Mat3A::from_cols_slice(&m[0..9]),
-            translation: Vec3A::from_slice(&m[9..12]),
-        }
+    pub const fn from_cols_array(m: &[f32; 12]) -> Self {
+        Self::from_cols(
+            Vec3A::new(m[0], m[1], m[2]),
+            Vec3A::new(m[3], m[4], m[5]),
+            Vec3A::new(m[6], m[7], m[8]),
+            Vec3A::new(m[9], m[10], m[11]),
+        )
     }
 
     /// Creates a `[f32; 12]` array storing data in column major order.
@@ -70,11 +72,13 @@ impl Affine3A {
     /// If your data is in row major order you will need to `transpose` the returned
     /// matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f32; 3]; 4]) -> Self {
-        Self {
-            matrix3: Mat3A::from_cols(m[0].into(), m[1].into(), m[2].into()),
-            translation: m[3].into(),
-        }
+    pub const fn from_cols_array_2d(m: &[[f32; 3]; 4]) -> Self {
+        Self::from_cols(
+            Vec3A::from_array(m[0]),
+            Vec3A::from_array(m[1]),
+            Vec3A::from_array(m[2]),
+            Vec3A::from_array(m[3]),
+        )
     }
 
     /// Creates a `[[f32; 3]; 4]` 3D array storing data in
@@ -467,14 +471,33 @@ impl core::fmt::Debug for Affine3A {
     }
 }
 
+/// Formats the transform as a list of the columns of `matrix3` followed by
+/// `translation`, so each inner array is a column and not a row.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for Affine3A {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "[{}, {}, {}, {}]",
-            self.matrix3.x_axis, self.matrix3.y_axis, self.matrix3.z_axis, self.translation
-        )
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        core::fmt::Display::fmt(&self.matrix3.x_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.matrix3.y_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.matrix3.z_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.translation, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Affine3A {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis, w_axis] = crate::parse::parse_array::<Vec3A, 4>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis, w_axis))
     }
 }
 
@@ -511,6 +534,15 @@ impl From<Affine3A> for Mat4 {
     }
 }
 
+/// The given `Mat4` must be an affine transform, i.e. contain no perspective transform.
+/// See [`Affine3A::from_mat4()`].
+impl From<Mat4> for Affine3A {
+    #[inline]
+    fn from(m: Mat4) -> Affine3A {
+        Self::from_mat4(m)
+    }
+}
+
 impl Mul<Mat4> for Affine3A {
     type Output = Mat4;
 
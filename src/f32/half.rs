@@ -0,0 +1,77 @@
+/*
+Conversion between `f32` and IEEE 754 binary16 (half precision) bit patterns.
+*/
+
+/// Converts `value` to the nearest half precision float, returned as its bit pattern.
+///
+/// Rounds to nearest with ties to even. Values too large for a half overflow to infinity,
+/// values too small underflow to a subnormal or signed zero and `NAN` stays `NAN`.
+#[inline]
+pub(crate) fn from_f32(value: f32) -> u16 {
+    let x = value.to_bits();
+    let sign = ((x >> 16) & 0x8000) as u16;
+    let exp = (x >> 23) & 0xff;
+    let man = x & 0x007f_ffff;
+
+    if exp == 0xff {
+        // Infinity, or NAN with the quiet bit set so the payload can't become zero.
+        let nan = if man != 0 {
+            0x0200 | (man >> 13) as u16
+        } else {
+            0
+        };
+        return sign | 0x7c00 | nan;
+    }
+
+    let e = exp as i32 - 127;
+    if e >= 16 {
+        return sign | 0x7c00;
+    }
+
+    if e >= -14 {
+        // Normal half. A carry out of the mantissa when rounding up correctly increments the
+        // exponent, which rounds the largest values up to infinity.
+        let mut h = (((e + 15) as u32) << 10) | (man >> 13);
+        let round_bit = 0x1000;
+        // Round up if above half way, or exactly half way and the result would be odd.
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            h += 1;
+        }
+        return sign | h as u16;
+    }
+
+    if e >= -25 {
+        // Subnormal half, in units of `2^-24`. Rounding up the largest subnormal correctly
+        // produces the smallest normal.
+        let m = man | 0x0080_0000;
+        let shift = (-1 - e) as u32;
+        let mut h = m >> shift;
+        let round_bit = 1 << (shift - 1);
+        if (m & round_bit) != 0 && ((m & (round_bit - 1)) != 0 || (h & 1) != 0) {
+            h += 1;
+        }
+        return sign | h as u16;
+    }
+
+    sign
+}
+
+/// Converts the half precision float bit pattern `bits` to `f32`.
+///
+/// This is exact, every half precision value is representable as an `f32`.
+#[inline]
+pub(crate) fn to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exp = ((bits >> 10) & 0x1f) as u32;
+    let man = (bits & 0x03ff) as u32;
+
+    let x = if exp == 0x1f {
+        sign | 0x7f80_0000 | (man << 13)
+    } else if exp != 0 {
+        sign | ((exp + 112) << 23) | (man << 13)
+    } else {
+        // Zero or subnormal, `man * 2^-24` is exact in `f32`.
+        sign | (man as f32 * (1.0 / 16_777_216.0)).to_bits()
+    };
+    f32::from_bits(x)
+}
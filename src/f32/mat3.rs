@@ -4,7 +4,7 @@ use crate::{f32::math, swizzles::*, DMat3, EulerRot, Mat2, Mat3A, Mat4, Quat, Ve
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Creates a 3x3 matrix from column vectors.
 #[inline(always)]
@@ -142,6 +142,7 @@ impl Mat3 {
     }
 
     /// Creates a 3x3 matrix from a 4x4 matrix, discarding the 4th row and column.
+    #[inline]
     pub fn from_mat4(m: Mat4) -> Self {
         Self::from_cols(m.x_axis.xyz(), m.y_axis.xyz(), m.z_axis.xyz())
     }
@@ -372,6 +373,9 @@ impl Mat3 {
 
     /// Returns the matrix row for the given `index`.
     ///
+    /// Matrices are stored in column major order so this gathers an element from each column,
+    /// [`Self::col()`] is cheaper when either will do.
+    ///
     /// # Panics
     ///
     /// Panics if `index` is greater than 2.
@@ -489,6 +493,41 @@ impl Mat3 {
         )
     }
 
+    /// Multiplies two 3x3 matrices and adds a third, computing `(self * rhs) + add`.
+    ///
+    /// Each column is accumulated using [`Vec3::mul_add()`] so there is only one rounding
+    /// error per multiply-add step. The result may differ slightly from `self * rhs + add`, which
+    /// rounds after every multiplication and addition.
+    #[inline]
+    pub fn mul_add(&self, rhs: &Self, add: &Self) -> Self {
+        Self::from_cols(
+            {
+                let col = rhs.x_axis;
+                let mut res = add.x_axis;
+                res = self.x_axis.mul_add(Vec3::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec3::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec3::splat(col.z), res);
+                res
+            },
+            {
+                let col = rhs.y_axis;
+                let mut res = add.y_axis;
+                res = self.x_axis.mul_add(Vec3::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec3::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec3::splat(col.z), res);
+                res
+            },
+            {
+                let col = rhs.z_axis;
+                let mut res = add.z_axis;
+                res = self.x_axis.mul_add(Vec3::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec3::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec3::splat(col.z), res);
+                res
+            },
+        )
+    }
+
     /// Adds two 3x3 matrices.
     #[inline]
     pub fn add_mat3(&self, rhs: &Self) -> Self {
@@ -535,6 +574,7 @@ impl Mat3 {
             && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
     #[inline]
     pub fn as_dmat3(&self) -> DMat3 {
         DMat3::from_cols(
@@ -655,6 +695,14 @@ impl From<Mat3A> for Mat3 {
     }
 }
 
+impl From<Mat4> for Mat3 {
+    /// Creates a 3x3 matrix from a 4x4 matrix, discarding the 4th row and column.
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        Self::from_mat4(m)
+    }
+}
+
 impl Sum<Self> for Mat3 {
     fn sum<I>(iter: I) -> Self
     where
@@ -698,6 +746,36 @@ impl PartialEq for Mat3 {
     }
 }
 
+impl Index<usize> for Mat3 {
+    type Output = Vec3;
+    /// Returns a reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat3 {
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.col_mut(index)
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl AsRef<[f32; 9]> for Mat3 {
     #[inline]
@@ -714,20 +792,68 @@ impl AsMut<[f32; 9]> for Mat3 {
     }
 }
 
+impl From<[[f32; 3]; 3]> for Mat3 {
+    /// Creates a matrix from a 2D array of columns, see [`Self::from_cols_array_2d()`].
+    #[inline]
+    fn from(m: [[f32; 3]; 3]) -> Self {
+        Self::from_cols_array_2d(&m)
+    }
+}
+
+impl From<Mat3> for [[f32; 3]; 3] {
+    /// Creates a 2D array of columns from a matrix, see [`Mat3::to_cols_array_2d()`].
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        m.to_cols_array_2d()
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Debug for Mat3 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(stringify!(Mat3))
-            .field("x_axis", &self.x_axis)
-            .field("y_axis", &self.y_axis)
-            .field("z_axis", &self.z_axis)
-            .finish()
+        if fmt.alternate() {
+            // one column per line with the elements aligned
+            crate::debug::fmt_matrix_alternate(
+                fmt,
+                stringify!(Mat3),
+                stringify!(Vec3),
+                ["x_axis", "y_axis", "z_axis"],
+                self.to_cols_array_2d(),
+            )
+        } else {
+            fmt.debug_struct(stringify!(Mat3))
+                .field("x_axis", &self.x_axis)
+                .field("y_axis", &self.y_axis)
+                .field("z_axis", &self.z_axis)
+                .finish()
+        }
     }
 }
 
+/// Formats the matrix as a list of its columns, so each inner array is a column and not a row,
+/// e.g. `[[1, 2], [3, 4]]` is a `Mat2` with an `x_axis` of `[1, 2]`.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z_axis, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat3 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis] = crate::parse::parse_array::<Vec3, 3>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis))
     }
 }
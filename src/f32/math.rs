@@ -110,16 +110,46 @@ mod libm_math {
         libm::floorf(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn trunc(f: f32) -> f32 {
+        libm::truncf(f)
+    }
+
     #[inline(always)]
     pub(crate) fn exp(f: f32) -> f32 {
         libm::expf(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f32) -> f32 {
+        libm::logf(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f32, n: f32) -> f32 {
         libm::powf(f, n)
     }
 
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f32, b: f32) -> f32 {
+        // Based on `f32::div_euclid` in https://doc.rust-lang.org/src/std/f32.rs.html
+        let q = libm::truncf(a / b);
+        if a % b < 0.0 {
+            return if b > 0.0 { q - 1.0 } else { q + 1.0 };
+        }
+        q
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f32, b: f32) -> f32 {
+        let r = a % b;
+        if r < 0.0 {
+            r + abs(b)
+        } else {
+            r
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
         libm::fmaf(a, b, c)
@@ -194,16 +224,36 @@ mod std_math {
         f32::floor(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn trunc(f: f32) -> f32 {
+        f32::trunc(f)
+    }
+
     #[inline(always)]
     pub(crate) fn exp(f: f32) -> f32 {
         f32::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f32) -> f32 {
+        f32::ln(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f32, n: f32) -> f32 {
         f32::powf(f, n)
     }
 
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f32, b: f32) -> f32 {
+        f32::div_euclid(a, b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f32, b: f32) -> f32 {
+        f32::rem_euclid(a, b)
+    }
+
     #[inline(always)]
     pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
         f32::mul_add(a, b, c)
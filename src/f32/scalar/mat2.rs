@@ -4,7 +4,7 @@ use crate::{f32::math, swizzles::*, DMat2, Mat3, Mat3A, Vec2};
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Creates a 2x2 matrix from column vectors.
 #[inline(always)]
@@ -167,6 +167,9 @@ impl Mat2 {
 
     /// Returns the matrix row for the given `index`.
     ///
+    /// Matrices are stored in column major order so this gathers an element from each column,
+    /// [`Self::col()`] is cheaper when either will do.
+    ///
     /// # Panics
     ///
     /// Panics if `index` is greater than 1.
@@ -247,6 +250,31 @@ impl Mat2 {
         Self::from_cols(self.mul(rhs.x_axis), self.mul(rhs.y_axis))
     }
 
+    /// Multiplies two 2x2 matrices and adds a third, computing `(self * rhs) + add`.
+    ///
+    /// Each column is accumulated using [`Vec2::mul_add()`] so there is only one rounding
+    /// error per multiply-add step. The result may differ slightly from `self * rhs + add`, which
+    /// rounds after every multiplication and addition.
+    #[inline]
+    pub fn mul_add(&self, rhs: &Self, add: &Self) -> Self {
+        Self::from_cols(
+            {
+                let col = rhs.x_axis;
+                let mut res = add.x_axis;
+                res = self.x_axis.mul_add(Vec2::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec2::splat(col.y), res);
+                res
+            },
+            {
+                let col = rhs.y_axis;
+                let mut res = add.y_axis;
+                res = self.x_axis.mul_add(Vec2::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec2::splat(col.y), res);
+                res
+            },
+        )
+    }
+
     /// Adds two 2x2 matrices.
     #[inline]
     pub fn add_mat2(&self, rhs: &Self) -> Self {
@@ -280,6 +308,7 @@ impl Mat2 {
             && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
     #[inline]
     pub fn as_dmat2(&self) -> DMat2 {
         DMat2::from_cols(self.x_axis.as_dvec2(), self.y_axis.as_dvec2())
@@ -420,6 +449,35 @@ impl PartialEq for Mat2 {
     }
 }
 
+impl Index<usize> for Mat2 {
+    type Output = Vec2;
+    /// Returns a reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat2 {
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 1.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.col_mut(index)
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl AsRef<[f32; 4]> for Mat2 {
     #[inline]
@@ -436,19 +494,65 @@ impl AsMut<[f32; 4]> for Mat2 {
     }
 }
 
+impl From<[[f32; 2]; 2]> for Mat2 {
+    /// Creates a matrix from a 2D array of columns, see [`Self::from_cols_array_2d()`].
+    #[inline]
+    fn from(m: [[f32; 2]; 2]) -> Self {
+        Self::from_cols_array_2d(&m)
+    }
+}
+
+impl From<Mat2> for [[f32; 2]; 2] {
+    /// Creates a 2D array of columns from a matrix, see [`Mat2::to_cols_array_2d()`].
+    #[inline]
+    fn from(m: Mat2) -> Self {
+        m.to_cols_array_2d()
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Debug for Mat2 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(stringify!(Mat2))
-            .field("x_axis", &self.x_axis)
-            .field("y_axis", &self.y_axis)
-            .finish()
+        if fmt.alternate() {
+            // one column per line with the elements aligned
+            crate::debug::fmt_matrix_alternate(
+                fmt,
+                stringify!(Mat2),
+                stringify!(Vec2),
+                ["x_axis", "y_axis"],
+                self.to_cols_array_2d(),
+            )
+        } else {
+            fmt.debug_struct(stringify!(Mat2))
+                .field("x_axis", &self.x_axis)
+                .field("y_axis", &self.y_axis)
+                .finish()
+        }
     }
 }
 
+/// Formats the matrix as a list of its columns, so each inner array is a column and not a row,
+/// e.g. `[[1, 2], [3, 4]]` is a `Mat2` with an `x_axis` of `[1, 2]`.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y_axis, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis] = crate::parse::parse_array::<Vec2, 2>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis))
     }
 }
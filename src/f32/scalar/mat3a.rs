@@ -4,7 +4,7 @@ use crate::{f32::math, swizzles::*, DMat3, EulerRot, Mat2, Mat3, Mat4, Quat, Vec
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Creates a 3x3 matrix from column vectors.
 #[inline(always)]
@@ -36,6 +36,9 @@ pub const fn mat3a(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Mat3A {
 /// 2D inputs as 3D vectors with an implicit `z` value of `1` for points and `0` for
 /// vectors respectively. These methods assume that `Self` contains a valid affine
 /// transform.
+///
+/// Each column contains a padding element so this type does not implement `AsRef` or `AsMut`
+/// for a flat array, use [`Self::to_cols_array()`] instead.
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct Mat3A {
@@ -142,6 +145,7 @@ impl Mat3A {
     }
 
     /// Creates a 3x3 matrix from a 4x4 matrix, discarding the 4th row and column.
+    #[inline]
     pub fn from_mat4(m: Mat4) -> Self {
         Self::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
     }
@@ -376,6 +380,9 @@ impl Mat3A {
 
     /// Returns the matrix row for the given `index`.
     ///
+    /// Matrices are stored in column major order so this gathers an element from each column,
+    /// [`Self::col()`] is cheaper when either will do.
+    ///
     /// # Panics
     ///
     /// Panics if `index` is greater than 2.
@@ -493,6 +500,41 @@ impl Mat3A {
         )
     }
 
+    /// Multiplies two 3x3 matrices and adds a third, computing `(self * rhs) + add`.
+    ///
+    /// Each column is accumulated using [`Vec3A::mul_add()`] so there is only one rounding
+    /// error per multiply-add step. The result may differ slightly from `self * rhs + add`, which
+    /// rounds after every multiplication and addition.
+    #[inline]
+    pub fn mul_add(&self, rhs: &Self, add: &Self) -> Self {
+        Self::from_cols(
+            {
+                let col = rhs.x_axis;
+                let mut res = add.x_axis;
+                res = self.x_axis.mul_add(Vec3A::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec3A::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec3A::splat(col.z), res);
+                res
+            },
+            {
+                let col = rhs.y_axis;
+                let mut res = add.y_axis;
+                res = self.x_axis.mul_add(Vec3A::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec3A::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec3A::splat(col.z), res);
+                res
+            },
+            {
+                let col = rhs.z_axis;
+                let mut res = add.z_axis;
+                res = self.x_axis.mul_add(Vec3A::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec3A::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec3A::splat(col.z), res);
+                res
+            },
+        )
+    }
+
     /// Adds two 3x3 matrices.
     #[inline]
     pub fn add_mat3(&self, rhs: &Self) -> Self {
@@ -539,6 +581,7 @@ impl Mat3A {
             && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
     #[inline]
     pub fn as_dmat3(&self) -> DMat3 {
         DMat3::from_cols(
@@ -659,6 +702,14 @@ impl From<Mat3> for Mat3A {
     }
 }
 
+impl From<Mat4> for Mat3A {
+    /// Creates a 3x3 matrix from a 4x4 matrix, discarding the 4th row and column.
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        Self::from_mat4(m)
+    }
+}
+
 impl Sum<Self> for Mat3A {
     fn sum<I>(iter: I) -> Self
     where
@@ -702,20 +753,98 @@ impl PartialEq for Mat3A {
     }
 }
 
+impl Index<usize> for Mat3A {
+    type Output = Vec3A;
+    /// Returns a reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat3A {
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 2.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.col_mut(index)
+    }
+}
+
+impl From<[[f32; 3]; 3]> for Mat3A {
+    /// Creates a matrix from a 2D array of columns, see [`Self::from_cols_array_2d()`].
+    #[inline]
+    fn from(m: [[f32; 3]; 3]) -> Self {
+        Self::from_cols_array_2d(&m)
+    }
+}
+
+impl From<Mat3A> for [[f32; 3]; 3] {
+    /// Creates a 2D array of columns from a matrix, see [`Mat3A::to_cols_array_2d()`].
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        m.to_cols_array_2d()
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Debug for Mat3A {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(stringify!(Mat3A))
-            .field("x_axis", &self.x_axis)
-            .field("y_axis", &self.y_axis)
-            .field("z_axis", &self.z_axis)
-            .finish()
+        if fmt.alternate() {
+            // one column per line with the elements aligned
+            crate::debug::fmt_matrix_alternate(
+                fmt,
+                stringify!(Mat3A),
+                stringify!(Vec3A),
+                ["x_axis", "y_axis", "z_axis"],
+                self.to_cols_array_2d(),
+            )
+        } else {
+            fmt.debug_struct(stringify!(Mat3A))
+                .field("x_axis", &self.x_axis)
+                .field("y_axis", &self.y_axis)
+                .field("z_axis", &self.z_axis)
+                .finish()
+        }
     }
 }
 
+/// Formats the matrix as a list of its columns, so each inner array is a column and not a row,
+/// e.g. `[[1, 2], [3, 4]]` is a `Mat2` with an `x_axis` of `[1, 2]`.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat3A {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z_axis, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat3A {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis] = crate::parse::parse_array::<Vec3A, 3>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis))
     }
 }
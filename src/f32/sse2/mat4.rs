@@ -6,7 +6,7 @@ use crate::{
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::iter::{Product, Sum};
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
@@ -512,6 +512,9 @@ impl Mat4 {
 
     /// Returns the matrix row for the given `index`.
     ///
+    /// Matrices are stored in column major order so this gathers an element from each column,
+    /// [`Self::col()`] is cheaper when either will do.
+    ///
     /// # Panics
     ///
     /// Panics if `index` is greater than 3.
@@ -755,6 +758,10 @@ impl Mat4 {
     /// direction.
     ///
     /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=forward`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `dir` and `up` are parallel or zero length when `glam_assert` is enabled.
     #[inline]
     pub fn look_to_lh(eye: Vec3, dir: Vec3, up: Vec3) -> Self {
         Self::look_to_rh(eye, -dir, up)
@@ -764,10 +771,17 @@ impl Mat4 {
     /// direction.
     ///
     /// For a view coordinate system with `+X=right`, `+Y=up` and `+Z=back`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `dir` and `up` are parallel or zero length when `glam_assert` is enabled.
     #[inline]
     pub fn look_to_rh(eye: Vec3, dir: Vec3, up: Vec3) -> Self {
         let f = dir.normalize();
-        let s = f.cross(up).normalize();
+        let s = f.cross(up);
+        // if `dir` and `up` are parallel there is no unique side vector
+        glam_assert!(s.length_squared() > 1e-12 * up.length_squared());
+        let s = s.normalize();
         let u = s.cross(f);
 
         Self::from_cols(
@@ -871,6 +885,9 @@ impl Mat4 {
 
     /// Creates an infinite left-handed perspective projection matrix with `[0,1]` depth range.
     ///
+    /// Like `perspective_lh`, but with an infinite value for `z_far`. `z_near` maps to a depth
+    /// of `0.0` and points approaching infinity map to a depth of `1.0`.
+    ///
     /// # Panics
     ///
     /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
@@ -888,7 +905,12 @@ impl Mat4 {
         )
     }
 
-    /// Creates an infinite left-handed perspective projection matrix with `[0,1]` depth range.
+    /// Creates an infinite reverse left-handed perspective projection matrix with `[0,1]` depth
+    /// range.
+    ///
+    /// Similar to `perspective_infinite_lh`, but maps `z_near` to a depth of `1.0` and points
+    /// approaching infinity to a depth of `0.0`. Reversing the depth range distributes floating
+    /// point precision more evenly across the view distance.
     ///
     /// # Panics
     ///
@@ -911,8 +933,14 @@ impl Mat4 {
         )
     }
 
-    /// Creates an infinite right-handed perspective projection matrix with
-    /// `[0,1]` depth range.
+    /// Creates an infinite right-handed perspective projection matrix with `[0,1]` depth range.
+    ///
+    /// Like `perspective_rh`, but with an infinite value for `z_far`. `z_near` maps to a depth
+    /// of `0.0` and points approaching infinity map to a depth of `1.0`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
     #[inline]
     pub fn perspective_infinite_rh(fov_y_radians: f32, aspect_ratio: f32, z_near: f32) -> Self {
         glam_assert!(z_near > 0.0);
@@ -925,8 +953,16 @@ impl Mat4 {
         )
     }
 
-    /// Creates an infinite reverse right-handed perspective projection matrix
-    /// with `[0,1]` depth range.
+    /// Creates an infinite reverse right-handed perspective projection matrix with `[0,1]` depth
+    /// range.
+    ///
+    /// Similar to `perspective_infinite_rh`, but maps `z_near` to a depth of `1.0` and points
+    /// approaching infinity to a depth of `0.0`. Reversing the depth range distributes floating
+    /// point precision more evenly across the view distance.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `z_near` is less than or equal to zero when `glam_assert` is enabled.
     #[inline]
     pub fn perspective_infinite_reverse_rh(
         fov_y_radians: f32,
@@ -1028,7 +1064,10 @@ impl Mat4 {
     /// This is the equivalent of multiplying the 3D vector as a 4D vector where `w` is `1.0`.
     /// The perspective divide is performed meaning the resulting 3D vector is divided by `w`.
     ///
-    /// This method assumes that `self` contains a projective transform.
+    /// This method assumes that `self` contains a projective transform, e.g. a perspective
+    /// projection matrix mapping view space points to normalized device coordinates. For affine
+    /// transforms `w` is always `1.0` and [`Self::transform_point3()`] gives the same result
+    /// without the division.
     #[inline]
     pub fn project_point3(&self, rhs: Vec3) -> Vec3 {
         let mut res = self.x_axis.mul(rhs.x);
@@ -1061,7 +1100,7 @@ impl Mat4 {
         res.xyz()
     }
 
-    /// Transforms the give 3D vector as a direction.
+    /// Transforms the given 3D vector as a direction.
     ///
     /// This is the equivalent of multiplying the 3D vector as a 4D vector where `w` is
     /// `0.0`.
@@ -1083,6 +1122,13 @@ impl Mat4 {
     /// Transforms the given [`Vec3A`] as 3D point.
     ///
     /// This is the equivalent of multiplying the [`Vec3A`] as a 4D vector where `w` is `1.0`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform, see
+    /// [`Self::transform_point3()`].
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
     #[inline]
     pub fn transform_point3a(&self, rhs: Vec3A) -> Vec3A {
         glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
@@ -1093,9 +1139,15 @@ impl Mat4 {
         res.into()
     }
 
-    /// Transforms the give [`Vec3A`] as 3D vector.
+    /// Transforms the given [`Vec3A`] as a direction.
     ///
     /// This is the equivalent of multiplying the [`Vec3A`] as a 4D vector where `w` is `0.0`.
+    ///
+    /// This method assumes that `self` contains a valid affine transform.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the 3rd row of `self` is not `(0, 0, 0, 1)` when `glam_assert` is enabled.
     #[inline]
     pub fn transform_vector3a(&self, rhs: Vec3A) -> Vec3A {
         glam_assert!(self.row(3).abs_diff_eq(Vec4::W, 1e-6));
@@ -1126,6 +1178,53 @@ impl Mat4 {
         )
     }
 
+    /// Multiplies two 4x4 matrices and adds a third, computing `(self * rhs) + add`.
+    ///
+    /// Each column is accumulated using [`Vec4::mul_add()`] so there is only one rounding
+    /// error per multiply-add step. The result may differ slightly from `self * rhs + add`, which
+    /// rounds after every multiplication and addition.
+    #[inline]
+    pub fn mul_add(&self, rhs: &Self, add: &Self) -> Self {
+        Self::from_cols(
+            {
+                let col = rhs.x_axis;
+                let mut res = add.x_axis;
+                res = self.x_axis.mul_add(Vec4::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec4::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec4::splat(col.z), res);
+                res = self.w_axis.mul_add(Vec4::splat(col.w), res);
+                res
+            },
+            {
+                let col = rhs.y_axis;
+                let mut res = add.y_axis;
+                res = self.x_axis.mul_add(Vec4::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec4::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec4::splat(col.z), res);
+                res = self.w_axis.mul_add(Vec4::splat(col.w), res);
+                res
+            },
+            {
+                let col = rhs.z_axis;
+                let mut res = add.z_axis;
+                res = self.x_axis.mul_add(Vec4::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec4::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec4::splat(col.z), res);
+                res = self.w_axis.mul_add(Vec4::splat(col.w), res);
+                res
+            },
+            {
+                let col = rhs.w_axis;
+                let mut res = add.w_axis;
+                res = self.x_axis.mul_add(Vec4::splat(col.x), res);
+                res = self.y_axis.mul_add(Vec4::splat(col.y), res);
+                res = self.z_axis.mul_add(Vec4::splat(col.z), res);
+                res = self.w_axis.mul_add(Vec4::splat(col.w), res);
+                res
+            },
+        )
+    }
+
     /// Adds two 4x4 matrices.
     #[inline]
     pub fn add_mat4(&self, rhs: &Self) -> Self {
@@ -1176,6 +1275,7 @@ impl Mat4 {
             && self.w_axis.abs_diff_eq(rhs.w_axis, max_abs_diff)
     }
 
+    /// Casts all elements of `self` to `f64`.
     #[inline]
     pub fn as_dmat4(&self) -> DMat4 {
         DMat4::from_cols(
@@ -1283,6 +1383,24 @@ impl MulAssign<f32> for Mat4 {
     }
 }
 
+impl From<Mat3> for Mat4 {
+    /// Creates an affine transformation matrix from the given 3x3 linear transformation
+    /// matrix.
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        Self::from_mat3(m)
+    }
+}
+
+impl From<Mat3A> for Mat4 {
+    /// Creates an affine transformation matrix from the given 3x3 linear transformation
+    /// matrix.
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        Self::from_mat3a(m)
+    }
+}
+
 impl Sum<Self> for Mat4 {
     fn sum<I>(iter: I) -> Self
     where
@@ -1329,6 +1447,37 @@ impl PartialEq for Mat4 {
     }
 }
 
+impl Index<usize> for Mat4 {
+    type Output = Vec4;
+    /// Returns a reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            3 => &self.w_axis,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl IndexMut<usize> for Mat4 {
+    /// Returns a mutable reference to the matrix column for the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than 3.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.col_mut(index)
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl AsRef<[f32; 16]> for Mat4 {
     #[inline]
@@ -1345,25 +1494,71 @@ impl AsMut<[f32; 16]> for Mat4 {
     }
 }
 
+impl From<[[f32; 4]; 4]> for Mat4 {
+    /// Creates a matrix from a 2D array of columns, see [`Self::from_cols_array_2d()`].
+    #[inline]
+    fn from(m: [[f32; 4]; 4]) -> Self {
+        Self::from_cols_array_2d(&m)
+    }
+}
+
+impl From<Mat4> for [[f32; 4]; 4] {
+    /// Creates a 2D array of columns from a matrix, see [`Mat4::to_cols_array_2d()`].
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        m.to_cols_array_2d()
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Debug for Mat4 {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt.debug_struct(stringify!(Mat4))
-            .field("x_axis", &self.x_axis)
-            .field("y_axis", &self.y_axis)
-            .field("z_axis", &self.z_axis)
-            .field("w_axis", &self.w_axis)
-            .finish()
+        if fmt.alternate() {
+            // one column per line with the elements aligned
+            crate::debug::fmt_matrix_alternate(
+                fmt,
+                stringify!(Mat4),
+                stringify!(Vec4),
+                ["x_axis", "y_axis", "z_axis", "w_axis"],
+                self.to_cols_array_2d(),
+            )
+        } else {
+            fmt.debug_struct(stringify!(Mat4))
+                .field("x_axis", &self.x_axis)
+                .field("y_axis", &self.y_axis)
+                .field("z_axis", &self.z_axis)
+                .field("w_axis", &self.w_axis)
+                .finish()
+        }
     }
 }
 
+/// Formats the matrix as a list of its columns, so each inner array is a column and not a row,
+/// e.g. `[[1, 2], [3, 4]]` is a `Mat2` with an `x_axis` of `[1, 2]`.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Mat4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "[{}, {}, {}, {}]",
-            self.x_axis, self.y_axis, self.z_axis, self.w_axis
-        )
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z_axis, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.w_axis, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Mat4 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis, w_axis] = crate::parse::parse_array::<Vec4, 4>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis, w_axis))
     }
 }
@@ -70,7 +70,7 @@ impl Quat {
         unsafe { UnionCast { a: [x, y, z, w] }.v }
     }
 
-    /// Creates a rotation quaternion from an array.
+    /// Creates a rotation quaternion from an array in `[x, y, z, w]` order.
     ///
     /// # Preconditions
     ///
@@ -81,7 +81,9 @@ impl Quat {
         Self::from_xyzw(a[0], a[1], a[2], a[3])
     }
 
-    /// Creates a new rotation quaternion from a 4D vector.
+    /// Creates a new rotation quaternion from a 4D vector, where `v.w` is the scalar part.
+    ///
+    /// This is a register move on SIMD builds, use `Vec4::from(q)` for the inverse.
     ///
     /// # Preconditions
     ///
@@ -92,7 +94,7 @@ impl Quat {
         Self(v.0)
     }
 
-    /// Creates a rotation quaternion from a slice.
+    /// Creates a rotation quaternion from the first 4 values in `slice` in `[x, y, z, w]` order.
     ///
     /// # Preconditions
     ///
@@ -108,7 +110,8 @@ impl Quat {
         Self(unsafe { _mm_loadu_ps(slice.as_ptr()) })
     }
 
-    /// Writes the quaternion to an unaligned slice.
+    /// Writes the quaternion to the first 4 elements of an unaligned slice in `[x, y, z, w]`
+    /// order.
     ///
     /// # Panics
     ///
@@ -176,6 +179,7 @@ impl Quat {
     /// From the columns of a 3x3 rotation matrix.
     #[inline]
     pub(crate) fn from_rotation_axes(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        glam_assert!(x_axis.is_normalized() && y_axis.is_normalized() && z_axis.is_normalized());
         // Based on https://github.com/microsoft/DirectXMath `XM$quaternionRotationMatrix`
         let (m00, m01, m02) = x_axis.into();
         let (m10, m11, m12) = y_axis.into();
@@ -234,18 +238,36 @@ impl Quat {
     }
 
     /// Creates a quaternion from a 3x3 rotation matrix.
+    ///
+    /// The rotation is extracted by branching on the largest of the quaternion components to
+    /// keep the square root well conditioned. Note if the input matrix contains scales, shears,
+    /// or other non-rotation transformations then the resulting quaternion will be ill-defined.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any input matrix column is not normalized when `glam_assert` is enabled.
     #[inline]
     pub fn from_mat3(mat: &Mat3) -> Self {
         Self::from_rotation_axes(mat.x_axis, mat.y_axis, mat.z_axis)
     }
 
     /// Creates a quaternion from a 3x3 SIMD aligned rotation matrix.
+    ///
+    /// See [`Self::from_mat3()`] for details.
     #[inline]
     pub fn from_mat3a(mat: &Mat3A) -> Self {
         Self::from_rotation_axes(mat.x_axis.into(), mat.y_axis.into(), mat.z_axis.into())
     }
 
     /// Creates a quaternion from a 3x3 rotation matrix inside a homogeneous 4x4 matrix.
+    ///
+    /// The rotation is extracted by branching on the largest of the quaternion components to
+    /// keep the square root well conditioned. Note if the input matrix contains scales, shears,
+    /// or other non-rotation transformations then the resulting quaternion will be ill-defined.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any input matrix column is not normalized when `glam_assert` is enabled.
     #[inline]
     pub fn from_mat4(mat: &Mat4) -> Self {
         Self::from_rotation_axes(
@@ -348,13 +370,25 @@ impl Quat {
     }
 
     /// Returns the rotation axis (normalized) and angle (in radians) of `self`.
+    ///
+    /// The returned angle is in the range `[0, PI]`, rotations greater than `PI` are returned
+    /// as the equivalent rotation about the negated axis.
+    ///
+    /// If the rotation angle is close to zero the axis is undefined and `(X, 0.0)` is
+    /// returned.
     #[inline]
     pub fn to_axis_angle(self) -> (Vec3, f32) {
         const EPSILON: f32 = 1.0e-8;
-        let v = Vec3::new(self.x, self.y, self.z);
+        // `self` and `-self` represent the same rotation, use the one with a non-negative `w`
+        // so the angle is in the range `[0, PI]`
+        let (v, w) = if self.w < 0.0 {
+            (Vec3::new(-self.x, -self.y, -self.z), -self.w)
+        } else {
+            (Vec3::new(self.x, self.y, self.z), self.w)
+        };
         let length = v.length();
         if length >= EPSILON {
-            let angle = 2.0 * math::atan2(length, self.w);
+            let angle = 2.0 * math::atan2(length, w);
             let axis = v / length;
             (axis, angle)
         } else {
@@ -370,6 +404,10 @@ impl Quat {
     }
 
     /// Returns the rotation angles for the given euler rotation sequence.
+    ///
+    /// At gimbal lock, when the second angle is `±PI/2`, the first and third axes are aligned and
+    /// only their combined rotation is known. In this case the third angle is returned as `0.0`
+    /// and the whole rotation is returned as the first angle.
     #[inline]
     pub fn to_euler(self, euler: EulerRot) -> (f32, f32, f32) {
         euler.convert_quat(self)
@@ -377,8 +415,8 @@ impl Quat {
 
     /// `[x, y, z, w]`
     #[inline]
-    pub fn to_array(&self) -> [f32; 4] {
-        [self.x, self.y, self.z, self.w]
+    pub const fn to_array(&self) -> [f32; 4] {
+        unsafe { *(self as *const Self as *const [f32; 4]) }
     }
 
     /// Returns the vector part of the quaternion.
@@ -448,6 +486,8 @@ impl Quat {
     ///
     /// For valid results, `self` must _not_ be of length zero.
     ///
+    /// See also [`Self::try_normalize()`] and [`Self::normalize_or_identity()`].
+    ///
     /// Panics
     ///
     /// Will panic if `self` is zero length when `glam_assert` is enabled.
@@ -457,6 +497,29 @@ impl Quat {
         Self::from_vec4(Vec4::from(self).normalize())
     }
 
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be `None`.
+    ///
+    /// See also [`Self::normalize_or_identity()`].
+    #[must_use]
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        Vec4::from(self).try_normalize().map(Self::from_vec4)
+    }
+
+    /// Returns `self` normalized to length 1.0 if possible, else returns the identity.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be [`Self::IDENTITY`]. This uses the same check as
+    /// [`Self::try_normalize()`].
+    #[must_use]
+    #[inline]
+    pub fn normalize_or_identity(self) -> Self {
+        self.try_normalize().unwrap_or(Self::IDENTITY)
+    }
+
     /// Returns `true` if, and only if, all elements are finite.
     /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
     #[inline]
@@ -526,12 +589,26 @@ impl Quat {
         Vec4::from(self).abs_diff_eq(Vec4::from(rhs), max_abs_diff)
     }
 
+    /// Returns true if `self` and `rhs` represent approximately the same rotation.
+    ///
+    /// Unlike [`Self::abs_diff_eq()`] this accounts for `q` and `-q` representing the same
+    /// rotation, returning true if the absolute difference of all elements between `self` and
+    /// either `rhs` or `-rhs` is less than or equal to `max_abs_diff`.
+    #[inline]
+    pub fn rotation_abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(rhs, max_abs_diff) || self.abs_diff_eq(-rhs, max_abs_diff)
+    }
+
     /// Performs a linear interpolation between `self` and `rhs` based on
     /// the value `s`.
     ///
     /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
     /// is `1.0`, the result will be equal to `rhs`.
     ///
+    /// The interpolation follows the shortest path, if the dot product of `self` and `end` is
+    /// negative `-end` is used instead as it represents the same rotation. The result is
+    /// normalized.
+    ///
     /// # Panics
     ///
     /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
@@ -563,6 +640,10 @@ impl Quat {
     /// When `s` is `0.0`, the result will be equal to `self`.  When `s`
     /// is `1.0`, the result will be equal to `end`.
     ///
+    /// The interpolation follows the shortest path, if the dot product of `self` and `end` is
+    /// negative `-end` is used instead as it represents the same rotation. When the angle
+    /// between the inputs is very small this falls back to [`Self::lerp()`].
+    ///
     /// # Panics
     ///
     /// Will panic if `self` or `end` are not normalized when `glam_assert` is enabled.
@@ -612,6 +693,28 @@ impl Quat {
         }
     }
 
+    /// Rotates `self` towards `rhs` by at most `max_angle` (in radians).
+    ///
+    /// The rotation follows the shortest path like [`Self::slerp()`]. When `rhs` is within
+    /// `max_angle` of `self`, `rhs` is returned. A negative `max_angle` is treated as `0.0`.
+    ///
+    /// Both quaternions must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `rhs` are not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn rotate_towards(self, rhs: Self, max_angle: f32) -> Self {
+        glam_assert!(self.is_normalized() && rhs.is_normalized());
+        let max_angle = max_angle.max(0.0);
+        let angle = self.angle_between(rhs);
+        if angle <= max_angle {
+            return rhs;
+        }
+        let s = max_angle / angle;
+        self.slerp(rhs, s)
+    }
+
     /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
     ///
     /// # Panics
@@ -675,6 +778,8 @@ impl Quat {
     }
 
     /// Creates a quaternion from a 3x3 rotation matrix inside a 3D affine transform.
+    ///
+    /// See [`Self::from_mat3()`] for details.
     #[inline]
     pub fn from_affine3(a: &crate::Affine3A) -> Self {
         #[allow(clippy::useless_conversion)]
@@ -703,10 +808,17 @@ impl Quat {
         }
     }
 
+    /// Casts all elements of `self` to `f64`.
     #[inline]
-    pub fn as_f64(self) -> DQuat {
+    pub fn as_dquat(self) -> DQuat {
         DQuat::from_xyzw(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
     }
+
+    /// Casts all elements of `self` to `f64`, the same as [`Self::as_dquat()`].
+    #[inline]
+    pub fn as_f64(self) -> DQuat {
+        self.as_dquat()
+    }
 }
 
 #[cfg(not(target_arch = "spirv"))]
@@ -723,8 +835,19 @@ impl fmt::Debug for Quat {
 
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Quat {
-    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(fmt, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Vec4::from(*self), f)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Quat {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    ///
+    /// The parsed quaternion is not normalized.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -764,6 +887,17 @@ impl Mul<f32> for Quat {
     }
 }
 
+impl Mul<Quat> for f32 {
+    type Output = Quat;
+    /// Multiplies a scalar value by a quaternion.
+    ///
+    /// The product is not guaranteed to be normalized.
+    #[inline]
+    fn mul(self, rhs: Quat) -> Quat {
+        rhs * self
+    }
+}
+
 impl Div<f32> for Quat {
     type Output = Self;
     /// Divides a quaternion by a scalar value.
@@ -850,6 +984,14 @@ impl AsRef<[f32; 4]> for Quat {
     }
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl AsMut<[f32; 4]> for Quat {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 4] {
+        unsafe { &mut *(self as *mut Self as *mut [f32; 4]) }
+    }
+}
+
 impl Sum<Self> for Quat {
     fn sum<I>(iter: I) -> Self
     where
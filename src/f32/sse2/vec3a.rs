@@ -31,7 +31,8 @@ pub const fn vec3a(x: f32, y: f32, z: f32) -> Vec3A {
 /// It is possible to convert between [`Vec3`] and [`Vec3A`] types using [`From`]
 /// or [`Into`] trait implementations.
 ///
-/// This type is 16 byte aligned.
+/// This type is 16 byte aligned. The `AsRef<[f32; 3]>` and `AsMut<[f32; 3]>` implementations
+/// only give access to the `x`, `y` and `z` elements, the padding element is never exposed.
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Vec3A(pub(crate) __m128);
@@ -46,9 +47,21 @@ impl Vec3A {
     /// All negative ones.
     pub const NEG_ONE: Self = Self::splat(-1.0);
 
+    /// All `f32::MIN`.
+    pub const MIN: Self = Self::splat(f32::MIN);
+
+    /// All `f32::MAX`.
+    pub const MAX: Self = Self::splat(f32::MAX);
+
     /// All NAN.
     pub const NAN: Self = Self::splat(f32::NAN);
 
+    /// All `f32::INFINITY`.
+    pub const INFINITY: Self = Self::splat(f32::INFINITY);
+
+    /// All `f32::NEG_INFINITY`.
+    pub const NEG_INFINITY: Self = Self::splat(f32::NEG_INFINITY);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1.0, 0.0, 0.0);
 
@@ -82,6 +95,17 @@ impl Vec3A {
         unsafe { UnionCast { a: [v; 4] }.v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    ///
+    /// `f` is only called for the `x`, `y` and `z` elements, never for the padding element.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -103,6 +127,17 @@ impl Vec3A {
         Self::new(a[0], a[1], a[2])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> f32,
+    {
+        Self::new(f(0), f(1), f(2))
+    }
+
     /// `[x, y, z]`
     #[inline]
     pub const fn to_array(&self) -> [f32; 3] {
@@ -141,7 +176,10 @@ impl Vec3A {
     /// Creates a 4D vector from `self` and the given `w` value.
     #[inline]
     pub fn extend(self, w: f32) -> Vec4 {
-        Vec4::new(self.x, self.y, self.z, w)
+        unsafe {
+            let zw = _mm_shuffle_ps(self.0, _mm_set1_ps(w), 0b00_00_00_10);
+            Vec4(_mm_shuffle_ps(self.0, zw, 0b10_00_01_00))
+        }
     }
 
     /// Creates a 2D vector from the `x` and `y` elements of `self`, discarding `z`.
@@ -160,6 +198,9 @@ impl Vec3A {
     }
 
     /// Returns a vector where every component is the dot product of `self` and `rhs`.
+    ///
+    /// The result is kept in a SIMD register rather than being extracted to a scalar and
+    /// splatted again, which can be useful when it is only used in further vector operations.
     #[inline]
     pub fn dot_into_vec(self, rhs: Self) -> Self {
         Self(unsafe { dot3_into_m128(self.0, rhs.0) })
@@ -238,6 +279,68 @@ impl Vec3A {
         }
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    /// `NaN` elements are ignored unless all elements are `NaN`, in which case `0` is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+        if self.y < min || (min.is_nan() && !self.y.is_nan()) {
+            min = self.y;
+            index = 1;
+        }
+        if self.z < min || (min.is_nan() && !self.z.is_nan()) {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    /// `NaN` elements are ignored unless all elements are `NaN`, in which case `0` is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+        if self.y > max || (max.is_nan() && !self.y.is_nan()) {
+            max = self.y;
+            index = 1;
+        }
+        if self.z > max || (max.is_nan() && !self.z.is_nan()) {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    #[inline]
+    pub fn element_sum(self) -> f32 {
+        unsafe {
+            let v = self.0;
+            let v = _mm_add_ps(v, _mm_shuffle_ps(self.0, self.0, 0b00_00_00_01));
+            let v = _mm_add_ps(v, _mm_shuffle_ps(self.0, self.0, 0b00_00_00_10));
+            _mm_cvtss_f32(v)
+        }
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    #[inline]
+    pub fn element_product(self) -> f32 {
+        unsafe {
+            let v = self.0;
+            let v = _mm_mul_ps(v, _mm_shuffle_ps(self.0, self.0, 0b00_00_00_01));
+            let v = _mm_mul_ps(v, _mm_shuffle_ps(self.0, self.0, 0b00_00_00_10));
+            _mm_cvtss_f32(v)
+        }
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -319,6 +422,10 @@ impl Vec3A {
     }
 
     /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    ///
+    /// Each element behaves like [`f32::copysign`], only the sign bit of `rhs` is
+    /// used so a `-0.0` or negative `NAN` element in `rhs` results in a negative element. A
+    /// `NAN` element in `self` will remain `NAN`.
     #[inline]
     pub fn copysign(self, rhs: Self) -> Self {
         unsafe {
@@ -381,6 +488,8 @@ impl Vec3A {
 
     /// Computes `1.0 / length()`.
     ///
+    /// This uses a full precision division rather than a reciprocal square root approximation.
+    ///
     /// For valid results, `self` must _not_ be of length zero.
     #[inline]
     pub fn length_recip(self) -> f32 {
@@ -397,6 +506,9 @@ impl Vec3A {
     }
 
     /// Compute the squared euclidean distance between two points in space.
+    ///
+    /// This is faster than `distance()` as it avoids a square root operation, which makes it
+    /// preferable when comparing distances.
     #[inline]
     pub fn distance_squared(self, rhs: Self) -> f32 {
         (self - rhs).length_squared()
@@ -428,7 +540,11 @@ impl Vec3A {
     /// In particular, if the input is zero (or very close to zero), or non-finite,
     /// the result of this operation will be `None`.
     ///
-    /// See also [`Self::normalize_or_zero()`].
+    /// There is no fixed epsilon, the input is rejected when the reciprocal of its length is
+    /// not finite. This includes inputs whose `length_squared()` underflows to zero, while
+    /// small inputs with a subnormal `length_squared()` are still normalized.
+    ///
+    /// See also [`Self::normalize_or()`] and [`Self::normalize_or_zero()`].
     #[must_use]
     #[inline]
     pub fn try_normalize(self) -> Option<Self> {
@@ -440,21 +556,29 @@ impl Vec3A {
         }
     }
 
+    /// Returns `self` normalized to length 1.0 if possible, else returns `fallback`.
+    ///
+    /// In particular, if the input is zero (or very close to zero), or non-finite,
+    /// the result of this operation will be `fallback`. This uses the same check as
+    /// [`Self::try_normalize()`].
+    ///
+    /// See also [`Self::normalize_or_zero()`].
+    #[must_use]
+    #[inline]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
     /// Returns `self` normalized to length 1.0 if possible, else returns zero.
     ///
     /// In particular, if the input is zero (or very close to zero), or non-finite,
     /// the result of this operation will be zero.
     ///
-    /// See also [`Self::try_normalize()`].
+    /// See also [`Self::try_normalize()`] and [`Self::normalize_or()`].
     #[must_use]
     #[inline]
     pub fn normalize_or_zero(self) -> Self {
-        let rcp = self.length_recip();
-        if rcp.is_finite() && rcp > 0.0 {
-            self * rcp
-        } else {
-            Self::ZERO
-        }
+        self.normalize_or(Self::ZERO)
     }
 
     /// Returns whether `self` is length `1.0` or not.
@@ -484,7 +608,7 @@ impl Vec3A {
     /// Returns the vector rejection of `self` from `rhs`.
     ///
     /// The vector rejection is the vector perpendicular to the projection of `self` onto
-    /// `rhs`, in rhs words the result of `self - self.project_onto(rhs)`.
+    /// `rhs`, in other words the result of `self - self.project_onto(rhs)`.
     ///
     /// `rhs` must be of non-zero length.
     ///
@@ -514,7 +638,7 @@ impl Vec3A {
     /// Returns the vector rejection of `self` from `rhs`.
     ///
     /// The vector rejection is the vector perpendicular to the projection of `self` onto
-    /// `rhs`, in rhs words the result of `self - self.project_onto(rhs)`.
+    /// `rhs`, in other words the result of `self - self.project_onto(rhs)`.
     ///
     /// `rhs` must be normalized.
     ///
@@ -527,6 +651,46 @@ impl Vec3A {
         self - self.project_onto_normalized(rhs)
     }
 
+    /// Returns the reflection vector for a given incident vector `self` and surface normal
+    /// `normal`.
+    ///
+    /// `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `normal` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn reflect(self, normal: Self) -> Self {
+        glam_assert!(normal.is_normalized());
+        self - 2.0 * self.dot(normal) * normal
+    }
+
+    /// Returns the refraction direction for a given incident vector `self`, surface normal
+    /// `normal` and ratio of indices of refraction, `eta`.
+    ///
+    /// Follows the GLSL `refract` semantics, when total internal reflection occurs a zero
+    /// vector is returned.
+    ///
+    /// `self` and `normal` must be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` or `normal` is not normalized when `glam_assert` is enabled.
+    #[must_use]
+    #[inline]
+    pub fn refract(self, normal: Self, eta: f32) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(normal.is_normalized());
+        let n_dot_i = normal.dot(self);
+        let k = 1.0 - eta * eta * (1.0 - n_dot_i * n_dot_i);
+        if k >= 0.0 {
+            eta * self - (eta * n_dot_i + math::sqrt(k)) * normal
+        } else {
+            Self::ZERO
+        }
+    }
+
     /// Returns a vector containing the nearest integer to a number for each element of `self`.
     /// Round half-way cases away from 0.0.
     #[inline]
@@ -548,15 +712,75 @@ impl Vec3A {
         Self(unsafe { m128_ceil(self.0) })
     }
 
+    /// Returns a vector containing the integer part of each element of `self`, rounding towards
+    /// zero.
+    #[inline]
+    pub fn trunc(self) -> Self {
+        Self(unsafe { m128_trunc(self.0) })
+    }
+
     /// Returns a vector containing the fractional part of the vector, e.g. `self -
     /// self.floor()`.
     ///
+    /// Each element is in the range `[0, 1)`, including for negative elements, so `-0.25`
+    /// results in `0.75`. This differs from [`f32::fract`], which is
+    /// `self - self.trunc()` and keeps the sign of `self`.
+    ///
     /// Note that this is fast but not precise for large numbers.
     #[inline]
     pub fn fract(self) -> Self {
         self - self.floor()
     }
 
+    /// Converts each element of `self` from radians to degrees.
+    ///
+    /// The result is identical to calling [`f32::to_degrees()`] on each element.
+    #[inline]
+    pub fn to_degrees(self) -> Self {
+        // scaling by `1.0.to_degrees()` is exactly what the scalar implementation does
+        self * Self::splat(1.0f32.to_degrees())
+    }
+
+    /// Converts each element of `self` from degrees to radians.
+    ///
+    /// The result is identical to calling [`f32::to_radians()`] on each element.
+    #[inline]
+    pub fn to_radians(self) -> Self {
+        // scaling by `1.0.to_radians()` is exactly what the scalar implementation does
+        self * Self::splat(1.0f32.to_radians())
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// [Euclidean division]: f32::div_euclid
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::div_euclid(self.x, rhs.x),
+            math::div_euclid(self.y, rhs.y),
+            math::div_euclid(self.z, rhs.z),
+        )
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element, however due to rounding it
+    /// may be equal to `rhs.abs()` for very small negative elements, see
+    /// [`f32::rem_euclid`].
+    ///
+    /// This differs from the `%` operator, which matches the scalar `%` operator by truncating
+    /// the quotient so the result has the same sign as `self`.
+    ///
+    /// [Euclidean division]: f32::rem_euclid
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self::new(
+            math::rem_euclid(self.x, rhs.x),
+            math::rem_euclid(self.y, rhs.y),
+            math::rem_euclid(self.z, rhs.z),
+        )
+    }
+
     /// Returns a vector containing `e^self` (the exponential function) for each element of
     /// `self`.
     #[inline]
@@ -564,6 +788,14 @@ impl Vec3A {
         Self::new(math::exp(self.x), math::exp(self.y), math::exp(self.z))
     }
 
+    /// Returns a vector containing the natural logarithm of each element of `self`.
+    ///
+    /// Elements that are negative result in `NAN` and zero elements result in `NEG_INFINITY`.
+    #[inline]
+    pub fn ln(self) -> Self {
+        Self::new(math::ln(self.x), math::ln(self.y), math::ln(self.z))
+    }
+
     /// Returns a vector containing each element of `self` raised to the power of `n`.
     #[inline]
     pub fn powf(self, n: f32) -> Self {
@@ -575,6 +807,11 @@ impl Vec3A {
     }
 
     /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
+    ///
+    /// This uses a full precision division rather than a reciprocal approximation, so results
+    /// are identical on all platforms. When dividing many vectors by the same `rhs`,
+    /// multiplying by `rhs.recip()` avoids repeating the division, but the result may differ
+    /// from `self / rhs` in the last bit.
     #[inline]
     pub fn recip(self) -> Self {
         Self(unsafe { _mm_div_ps(Self::ONE.0, self.0) })
@@ -591,6 +828,74 @@ impl Vec3A {
         self + ((rhs - self) * s)
     }
 
+    /// Performs a smooth Hermite interpolation between `self` and `rhs` based on the value `s`.
+    ///
+    /// `s` is clamped to the range `[0, 1]` and eased by `3s² - 2s³` before interpolating, so
+    /// the result eases in from `self` and out to `rhs`. When `s` is `0.0` the result will be
+    /// equal to `self` and when `s` is `1.0` it will be equal to `rhs`.
+    ///
+    /// See [`Self::smoothstep()`] for the GLSL style function taking edges.
+    #[inline]
+    pub fn smooth_lerp(self, rhs: Self, s: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        self.lerp(rhs, s * s * (3.0 - 2.0 * s))
+    }
+
+    /// Moves `self` towards `rhs` by at most `max_delta`.
+    ///
+    /// If `rhs` is within `max_delta` of `self` the result is equal to `rhs`, it will never move
+    /// past `rhs`. When `max_delta` is zero or negative the result is equal to `self`.
+    #[must_use]
+    #[inline]
+    pub fn move_towards(self, rhs: Self, max_delta: f32) -> Self {
+        if max_delta <= 0.0 {
+            return self;
+        }
+        let delta = rhs - self;
+        let length = delta.length();
+        // also covers `self == rhs`, avoiding a division by zero
+        if length <= max_delta {
+            rhs
+        } else {
+            self + delta * (max_delta / length)
+        }
+    }
+
+    /// Calculates the midpoint between `self` and `rhs`.
+    ///
+    /// The midpoint is the average of, or halfway point between, two vectors.
+    /// `a.midpoint(b)` should yield the same result as `a.lerp(b, 0.5)`
+    /// while being slightly cheaper to compute.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        (self + rhs) * 0.5
+    }
+
+    /// Returns a vector containing `0.0` for each element of `self` that is less than the
+    /// corresponding element of `edge` and `1.0` otherwise.
+    ///
+    /// This matches the GLSL `step(edge, x)` function. Use [`Self::splat()`] for a scalar
+    /// `edge`.
+    #[inline]
+    pub fn step(self, edge: Self) -> Self {
+        Self::select(self.cmplt(edge), Self::ZERO, Self::ONE)
+    }
+
+    /// Performs smooth Hermite interpolation of each element of `self` between `edge0` and
+    /// `edge1`.
+    ///
+    /// This matches the GLSL `smoothstep(edge0, edge1, x)` function, the interpolant is clamped
+    /// to `[0, 1]` before applying the cubic `t * t * (3 - 2 * t)`. Use [`Self::splat()`] for
+    /// scalar edges.
+    ///
+    /// The result is undefined for elements where `edge0 >= edge1`.
+    #[inline]
+    pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = ((self - edge0) / (edge1 - edge0)).clamp(Self::ZERO, Self::ONE);
+        t * t * (Self::splat(3.0) - Self::splat(2.0) * t)
+    }
+
     /// Returns true if the absolute difference of all elements between `self` and `rhs` is
     /// less than or equal to `max_abs_diff`.
     ///
@@ -607,14 +912,18 @@ impl Vec3A {
 
     /// Returns a vector with a length no less than `min` and no more than `max`
     ///
+    /// A zero length vector has no direction to scale along and is returned unchanged.
+    ///
     /// # Panics
     ///
-    /// Will panic if `min` is greater than `max` when `glam_assert` is enabled.
+    /// Will panic if `min` is greater than `max`, or if `min` is negative when `glam_assert` is
+    /// enabled.
     #[inline]
     pub fn clamp_length(self, min: f32, max: f32) -> Self {
+        glam_assert!(0.0 <= min);
         glam_assert!(min <= max);
         let length_sq = self.length_squared();
-        if length_sq < min * min {
+        if length_sq < min * min && length_sq > 0.0 {
             min * (self / math::sqrt(length_sq))
         } else if length_sq > max * max {
             max * (self / math::sqrt(length_sq))
@@ -624,7 +933,13 @@ impl Vec3A {
     }
 
     /// Returns a vector with a length no more than `max`
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `max` is negative when `glam_assert` is enabled.
+    #[inline]
     pub fn clamp_length_max(self, max: f32) -> Self {
+        glam_assert!(0.0 <= max);
         let length_sq = self.length_squared();
         if length_sq > max * max {
             max * (self / math::sqrt(length_sq))
@@ -634,9 +949,17 @@ impl Vec3A {
     }
 
     /// Returns a vector with a length no less than `min`
+    ///
+    /// A zero length vector has no direction to scale along and is returned unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `min` is negative when `glam_assert` is enabled.
+    #[inline]
     pub fn clamp_length_min(self, min: f32) -> Self {
+        glam_assert!(0.0 <= min);
         let length_sq = self.length_squared();
-        if length_sq < min * min {
+        if length_sq < min * min && length_sq > 0.0 {
             min * (self / math::sqrt(length_sq))
         } else {
             self
@@ -650,6 +973,11 @@ impl Vec3A {
     /// architecture has a dedicated fma CPU instruction. However, this is not always true,
     /// and will be heavily dependant on designing algorithms with specific target hardware in
     /// mind.
+    ///
+    /// The result is always fused, so it is the same for every backend and may differ in the
+    /// last bit from `self * a + b`, which rounds after both the multiply and the add. When the
+    /// `fma` target feature is enabled this uses the fma instruction, otherwise a slower
+    /// software implementation is used. Use `self * a + b` if a fused result is not required.
     #[inline]
     pub fn mul_add(self, a: Self, b: Self) -> Self {
         #[cfg(target_feature = "fma")]
@@ -664,15 +992,14 @@ impl Vec3A {
         )
     }
 
-    /// Returns the angle (in radians) between two vectors.
+    /// Returns the angle (in radians) between two vectors in the range `[0, +π]`.
     ///
     /// The input vectors do not need to be unit length however they must be non-zero.
     #[inline]
     pub fn angle_between(self, rhs: Self) -> f32 {
-        math::acos_approx(
-            self.dot(rhs)
-                .div(math::sqrt(self.length_squared().mul(rhs.length_squared()))),
-        )
+        // `atan2` stays accurate near `0` and `π` where `acos` of the normalized dot product
+        // loses precision
+        math::atan2(self.cross(rhs).length(), self.dot(rhs))
     }
 
     /// Returns some vector that is orthogonal to the given one.
@@ -710,6 +1037,9 @@ impl Vec3A {
     /// Given a unit-length vector return two other vectors that together form an orthonormal
     /// basis.  That is, all three vectors are orthogonal to each other and are normalized.
     ///
+    /// The basis is right-handed, for the returned pair `(a, b)` it holds that
+    /// `a.cross(b) == self`.
+    ///
     /// # Panics
     ///
     /// Will panic if `self` is not normalized when `glam_assert` is enabled.
@@ -726,6 +1056,31 @@ impl Vec3A {
         )
     }
 
+    /// Encodes a unit vector as a point in `[-1, 1]²` using an octahedral mapping, e.g. for
+    /// compact normal storage. Use [`Vec2::octahedral_decode()`] to get the vector back.
+    ///
+    /// The vector is projected onto the octahedron `|x| + |y| + |z| = 1` and the lower
+    /// hemisphere is folded over the diagonals onto the corners of the square. Without
+    /// quantization, decoding returns each element to within `4.0 * f32::EPSILON` of
+    /// `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `self` is not normalized when `glam_assert` is enabled.
+    #[inline]
+    pub fn octahedral_encode(&self) -> Vec2 {
+        glam_assert!(self.is_normalized());
+        let n = *self / (math::abs(self.x) + math::abs(self.y) + math::abs(self.z));
+        if n.z >= 0.0 {
+            Vec2::new(n.x, n.y)
+        } else {
+            Vec2::new(
+                (1.0 - math::abs(n.y)) * math::signum(n.x),
+                (1.0 - math::abs(n.x)) * math::signum(n.y),
+            )
+        }
+    }
+
     /// Casts all elements of `self` to `f64`.
     #[inline]
     pub fn as_dvec3(&self) -> crate::DVec3 {
@@ -733,28 +1088,110 @@ impl Vec3A {
     }
 
     /// Casts all elements of `self` to `i32`.
+    ///
+    /// Like the `as` operator, elements are truncated towards zero, out of range elements
+    /// saturate to `i32::MIN` or `i32::MAX` and `NAN` elements become `0`.
     #[inline]
     pub fn as_ivec3(&self) -> crate::IVec3 {
         crate::IVec3::new(self.x as i32, self.y as i32, self.z as i32)
     }
 
     /// Casts all elements of `self` to `u32`.
+    ///
+    /// Like the `as` operator, elements are truncated towards zero, out of range elements
+    /// saturate to `u32::MIN` or `u32::MAX` and `NAN` elements become `0`.
     #[inline]
     pub fn as_uvec3(&self) -> crate::UVec3 {
         crate::UVec3::new(self.x as u32, self.y as u32, self.z as u32)
     }
 
     /// Casts all elements of `self` to `i64`.
+    ///
+    /// Like the `as` operator, elements are truncated towards zero, out of range elements
+    /// saturate to `i64::MIN` or `i64::MAX` and `NAN` elements become `0`.
     #[inline]
     pub fn as_i64vec3(&self) -> crate::I64Vec3 {
         crate::I64Vec3::new(self.x as i64, self.y as i64, self.z as i64)
     }
 
     /// Casts all elements of `self` to `u64`.
+    ///
+    /// Like the `as` operator, elements are truncated towards zero, out of range elements
+    /// saturate to `u64::MIN` or `u64::MAX` and `NAN` elements become `0`.
     #[inline]
     pub fn as_u64vec3(&self) -> crate::U64Vec3 {
         crate::U64Vec3::new(self.x as u64, self.y as u64, self.z as u64)
     }
+
+    /// Rounds all elements of `self` towards negative infinity and casts them to `i32`.
+    ///
+    /// Unlike [`Self::as_ivec3()`], which truncates towards zero, negative elements
+    /// round down, so this is suitable for converting positions to grid coordinates. Out of
+    /// range elements saturate to `i32::MIN` or `i32::MAX` and `NAN` elements become `0`.
+    #[inline]
+    pub fn floor_to_ivec(&self) -> crate::IVec3 {
+        self.floor().as_ivec3()
+    }
+
+    /// Rounds all elements of `self` to the nearest integer and casts them to `i32`.
+    ///
+    /// Half-way cases round away from zero, see [`Self::round()`]. Out of range elements
+    /// saturate to `i32::MIN` or `i32::MAX` and `NAN` elements become `0`.
+    #[inline]
+    pub fn round_to_ivec(&self) -> crate::IVec3 {
+        self.round().as_ivec3()
+    }
+
+    /// Rounds all elements of `self` towards positive infinity and casts them to `i32`.
+    ///
+    /// Out of range elements saturate to `i32::MIN` or `i32::MAX` and `NAN` elements become
+    /// `0`.
+    #[inline]
+    pub fn ceil_to_ivec(&self) -> crate::IVec3 {
+        self.ceil().as_ivec3()
+    }
+
+    /// Converts an RGB color with elements in the range `[0, 1]` to 8 bit channels.
+    ///
+    /// Each element is clamped to `[0, 1]`, scaled by `255.0` and rounded to the nearest
+    /// integer. `NAN` elements become `0`.
+    #[inline]
+    pub fn to_rgb_u8(&self) -> [u8; 3] {
+        let v = *self * 255.0 + 0.5;
+        [v.x as u8, v.y as u8, v.z as u8]
+    }
+
+    /// Creates an RGB color from 8 bit channels, dividing each channel by `255.0`.
+    #[inline]
+    pub fn from_rgb_u8(rgb: [u8; 3]) -> Self {
+        Self::new(rgb[0] as f32, rgb[1] as f32, rgb[2] as f32) / 255.0
+    }
+
+    /// Converts all elements of `self` to half precision floats, returned as their IEEE 754
+    /// binary16 bit patterns.
+    ///
+    /// Rounds to nearest with ties to even. Elements too large for a half become infinity,
+    /// elements too small become a subnormal or signed zero and `NAN` elements stay `NAN`.
+    #[inline]
+    pub fn to_f16_array(&self) -> [u16; 3] {
+        [
+            crate::f32::half::from_f32(self.x),
+            crate::f32::half::from_f32(self.y),
+            crate::f32::half::from_f32(self.z),
+        ]
+    }
+
+    /// Creates a vector from an array of half precision float bit patterns.
+    ///
+    /// The conversion is exact, every half precision value is representable as an `f32`.
+    #[inline]
+    pub fn from_f16_array(a: [u16; 3]) -> Self {
+        Self::new(
+            crate::f32::half::to_f32(a[0]),
+            crate::f32::half::to_f32(a[1]),
+            crate::f32::half::to_f32(a[2]),
+        )
+    }
 }
 
 impl Default for Vec3A {
@@ -928,8 +1365,10 @@ impl Rem<Vec3A> for Vec3A {
     #[inline]
     fn rem(self, rhs: Self) -> Self {
         unsafe {
-            let n = m128_floor(_mm_div_ps(self.0, rhs.0));
-            Self(_mm_sub_ps(self.0, _mm_mul_ps(n, rhs.0)))
+            let n = m128_trunc(_mm_div_ps(self.0, rhs.0));
+            let r = _mm_sub_ps(self.0, _mm_mul_ps(n, rhs.0));
+            // like the scalar `%` the result has the sign of `self`, including zero results
+            Self(_mm_or_ps(r, _mm_and_ps(self.0, _mm_set1_ps(-0.0))))
         }
     }
 }
@@ -1056,7 +1495,24 @@ impl IndexMut<usize> for Vec3A {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for Vec3A {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for Vec3A {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
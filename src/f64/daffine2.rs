@@ -46,11 +46,12 @@ impl DAffine2 {
 
     /// Creates an affine transform from a `[f64; 6]` array stored in column major order.
     #[inline]
-    pub fn from_cols_array(m: &[f64; 6]) -> Self {
-        Self {
-            matrix2: DMat2::from_cols_slice(&m[0..4]),
-            translation: DVec2::from_slice(&m[4..6]),
-        }
+    pub const fn from_cols_array(m: &[f64; 6]) -> Self {
+        Self::from_cols(
+            DVec2::new(m[0], m[1]),
+            DVec2::new(m[2], m[3]),
+            DVec2::new(m[4], m[5]),
+        )
     }
 
     /// Creates a `[f64; 6]` array storing data in column major order.
@@ -67,11 +68,12 @@ impl DAffine2 {
     /// If your data is in row major order you will need to `transpose` the returned
     /// matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f64; 2]; 3]) -> Self {
-        Self {
-            matrix2: DMat2::from_cols(m[0].into(), m[1].into()),
-            translation: m[2].into(),
-        }
+    pub const fn from_cols_array_2d(m: &[[f64; 2]; 3]) -> Self {
+        Self::from_cols(
+            DVec2::from_array(m[0]),
+            DVec2::from_array(m[1]),
+            DVec2::from_array(m[2]),
+        )
     }
 
     /// Creates a `[[f64; 2]; 3]` 2D array storing data in
@@ -187,6 +189,7 @@ impl DAffine2 {
     }
 
     /// The given `DMat3` must be an affine transform,
+    /// i.e. contain no perspective transform.
     #[inline]
     pub fn from_mat3(m: DMat3) -> Self {
         use crate::swizzles::Vec3Swizzles;
@@ -297,14 +300,31 @@ impl core::fmt::Debug for DAffine2 {
     }
 }
 
+/// Formats the transform as a list of the columns of `matrix2` followed by
+/// `translation`, so each inner array is a column and not a row.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for DAffine2 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "[{}, {}, {}]",
-            self.matrix2.x_axis, self.matrix2.y_axis, self.translation
-        )
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        core::fmt::Display::fmt(&self.matrix2.x_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.matrix2.y_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.translation, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for DAffine2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis] = crate::parse::parse_array::<DVec2, 3>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis))
     }
 }
 
@@ -340,6 +360,15 @@ impl From<DAffine2> for DMat3 {
     }
 }
 
+/// The given `DMat3` must be an affine transform, i.e. contain no perspective transform.
+/// See [`DAffine2::from_mat3()`].
+impl From<DMat3> for DAffine2 {
+    #[inline]
+    fn from(m: DMat3) -> DAffine2 {
+        Self::from_mat3(m)
+    }
+}
+
 impl Mul<DMat3> for DAffine2 {
     type Output = DMat3;
 
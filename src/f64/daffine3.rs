@@ -46,11 +46,13 @@ impl DAffine3 {
 
     /// Creates an affine transform from a `[f64; 12]` array stored in column major order.
     #[inline]
-    pub fn from_cols_array(m: &[f64; 12]) -> Self {
-        Self {
-            matrix3: DMat3::from_cols_slice(&m[0..9]),
-            translation: DVec3::from_slice(&m[9..12]),
-        }
+    pub const fn from_cols_array(m: &[f64; 12]) -> Self {
+        Self::from_cols(
+            DVec3::new(m[0], m[1], m[2]),
+            DVec3::new(m[3], m[4], m[5]),
+            DVec3::new(m[6], m[7], m[8]),
+            DVec3::new(m[9], m[10], m[11]),
+        )
     }
 
     /// Creates a `[f64; 12]` array storing data in column major order.
@@ -68,11 +70,13 @@ impl DAffine3 {
     /// If your data is in row major order you will need to `transpose` the returned
     /// matrix.
     #[inline]
-    pub fn from_cols_array_2d(m: &[[f64; 3]; 4]) -> Self {
-        Self {
-            matrix3: DMat3::from_cols(m[0].into(), m[1].into(), m[2].into()),
-            translation: m[3].into(),
-        }
+    pub const fn from_cols_array_2d(m: &[[f64; 3]; 4]) -> Self {
+        Self::from_cols(
+            DVec3::from_array(m[0]),
+            DVec3::from_array(m[1]),
+            DVec3::from_array(m[2]),
+            DVec3::from_array(m[3]),
+        )
     }
 
     /// Creates a `[[f64; 3]; 4]` 3D array storing data in
@@ -454,14 +458,33 @@ impl core::fmt::Debug for DAffine3 {
     }
 }
 
+/// Formats the transform as a list of the columns of `matrix3` followed by
+/// `translation`, so each inner array is a column and not a row.
+///
+/// Formatting flags such as precision and width are applied to every element.
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for DAffine3 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(
-            f,
-            "[{}, {}, {}, {}]",
-            self.matrix3.x_axis, self.matrix3.y_axis, self.matrix3.z_axis, self.translation
-        )
+        // the column vectors pass the formatter on to each element
+        f.write_str("[")?;
+        core::fmt::Display::fmt(&self.matrix3.x_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.matrix3.y_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.matrix3.z_axis, f)?;
+        f.write_str(", ")?;
+        core::fmt::Display::fmt(&self.translation, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for DAffine3 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`, a list of column vectors.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x_axis, y_axis, z_axis, w_axis] = crate::parse::parse_array::<DVec3, 4>(s)?;
+        Ok(Self::from_cols(x_axis, y_axis, z_axis, w_axis))
     }
 }
 
@@ -498,6 +521,15 @@ impl From<DAffine3> for DMat4 {
     }
 }
 
+/// The given `DMat4` must be an affine transform, i.e. contain no perspective transform.
+/// See [`DAffine3::from_mat4()`].
+impl From<DMat4> for DAffine3 {
+    #[inline]
+    fn from(m: DMat4) -> DAffine3 {
+        Self::from_mat4(m)
+    }
+}
+
 impl Mul<DMat4> for DAffine3 {
     type Output = DMat4;
 
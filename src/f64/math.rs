@@ -75,16 +75,46 @@ mod libm_math {
         libm::floor(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn trunc(f: f64) -> f64 {
+        libm::trunc(f)
+    }
+
     #[inline(always)]
     pub(crate) fn exp(f: f64) -> f64 {
         libm::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f64) -> f64 {
+        libm::log(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f64, n: f64) -> f64 {
         libm::pow(f, n)
     }
 
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f64, b: f64) -> f64 {
+        // Based on `f64::div_euclid` in https://doc.rust-lang.org/src/std/f64.rs.html
+        let q = libm::trunc(a / b);
+        if a % b < 0.0 {
+            return if b > 0.0 { q - 1.0 } else { q + 1.0 };
+        }
+        q
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f64, b: f64) -> f64 {
+        let r = a % b;
+        if r < 0.0 {
+            r + abs(b)
+        } else {
+            r
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
         libm::fma(a, b, c)
@@ -158,16 +188,36 @@ mod std_math {
         f64::floor(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn trunc(f: f64) -> f64 {
+        f64::trunc(f)
+    }
+
     #[inline(always)]
     pub(crate) fn exp(f: f64) -> f64 {
         f64::exp(f)
     }
 
+    #[inline(always)]
+    pub(crate) fn ln(f: f64) -> f64 {
+        f64::ln(f)
+    }
+
     #[inline(always)]
     pub(crate) fn powf(f: f64, n: f64) -> f64 {
         f64::powf(f, n)
     }
 
+    #[inline(always)]
+    pub(crate) fn div_euclid(a: f64, b: f64) -> f64 {
+        f64::div_euclid(a, b)
+    }
+
+    #[inline(always)]
+    pub(crate) fn rem_euclid(a: f64, b: f64) -> f64 {
+        f64::rem_euclid(a, b)
+    }
+
     #[inline(always)]
     pub(crate) fn mul_add(a: f64, b: f64, c: f64) -> f64 {
         f64::mul_add(a, b, c)
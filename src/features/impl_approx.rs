@@ -209,4 +209,49 @@ mod test {
         impl_approx_test!(f64, DMat3, DMat3::from_cols_slice(&ONESF64));
         impl_approx_test!(f64, DMat4, DMat4::from_cols_slice(&ONESF64));
     }
+
+    #[test]
+    fn test_approx_affine() {
+        let a = Affine2::from_scale_angle_translation(Vec2::new(2.0, 3.0), 0.5, Vec2::ONE);
+        let b = Affine2::from_cols(a.x_axis, a.y_axis, a.z_axis + Vec2::splat(1e-7));
+        approx::assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        approx::assert_relative_eq!(a, b, max_relative = 1e-6);
+        approx::assert_abs_diff_ne!(a, Affine2::IDENTITY);
+
+        let a = Affine3A::from_scale_rotation_translation(
+            Vec3::new(2.0, 3.0, 4.0),
+            Quat::from_rotation_y(0.5),
+            Vec3::ONE,
+        );
+        let b = Affine3A::from_cols(a.x_axis, a.y_axis, a.z_axis, a.w_axis + Vec3A::splat(1e-7));
+        approx::assert_abs_diff_eq!(a, b, epsilon = 1e-6);
+        approx::assert_relative_eq!(a, b, max_relative = 1e-6);
+        approx::assert_abs_diff_ne!(a, Affine3A::IDENTITY);
+
+        let a = DAffine2::from_scale_angle_translation(DVec2::new(2.0, 3.0), 0.5, DVec2::ONE);
+        approx::assert_ulps_eq!(a, a);
+        approx::assert_abs_diff_ne!(a, DAffine2::IDENTITY);
+
+        let a = DAffine3::from_scale_rotation_translation(
+            DVec3::new(2.0, 3.0, 4.0),
+            DQuat::from_rotation_y(0.5),
+            DVec3::ONE,
+        );
+        approx::assert_ulps_eq!(a, a);
+        approx::assert_abs_diff_ne!(a, DAffine3::IDENTITY);
+    }
+
+    #[test]
+    fn test_approx_quat_element_wise() {
+        // comparisons are element-wise, `q` and `-q` are the same rotation but are not equal
+        let q = Quat::from_rotation_y(0.5);
+        approx::assert_abs_diff_eq!(q, q);
+        approx::assert_abs_diff_ne!(q, -q);
+        approx::assert_relative_ne!(q, -q);
+        approx::assert_ulps_ne!(q, -q);
+
+        let q = DQuat::from_rotation_y(0.5);
+        approx::assert_abs_diff_ne!(q, -q);
+        approx::assert_relative_ne!(q, -q);
+    }
 }
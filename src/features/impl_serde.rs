@@ -952,6 +952,15 @@ mod f32 {
     impl_serde_float_types!(f32, Affine2, Affine3A, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
     impl_serde_mat3!(f32, Mat3A, test_mat3a_serde);
     impl_serde_vec3!(f32, Vec3A, test_vec3a_serde);
+
+    #[test]
+    fn test_mat3a_serde_matches_mat3() {
+        let a = Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let serialized = serde_json::to_string(&a).unwrap();
+        assert_eq!(serialized, serde_json::to_string(&Mat3A::from(a)).unwrap());
+        let deserialized: Mat3A = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(a, Mat3::from(deserialized));
+    }
 }
 
 mod f64 {
@@ -14,8 +14,17 @@ pub const fn ivec2(x: i32, y: i32) -> IVec2 {
 }
 
 /// A 2-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "cuda", repr(align(8)))]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
@@ -34,6 +43,12 @@ impl IVec2 {
     /// All negative ones.
     pub const NEG_ONE: Self = Self::splat(-1);
 
+    /// All `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// All `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0);
 
@@ -61,6 +76,15 @@ impl IVec2 {
         Self { x: v, y: v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(i32) -> i32,
+    {
+        Self::new(f(self.x), f(self.y))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -80,6 +104,17 @@ impl IVec2 {
         Self::new(a[0], a[1])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> i32,
+    {
+        Self::new(f(0), f(1))
+    }
+
     /// `[x, y]`
     #[inline]
     pub const fn to_array(&self) -> [i32; 2] {
@@ -176,6 +211,52 @@ impl IVec2 {
         self.x.max(self.y)
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> i32 {
+        self.x.wrapping_add(self.y)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> i32 {
+        self.x.wrapping_mul(self.y)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -259,9 +340,12 @@ impl IVec2 {
     }
 
     /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    ///
+    /// A zero element in `rhs` is treated as positive.
     #[inline]
     pub fn copysign(self, rhs: Self) -> Self {
-        Self::select(rhs.cmpge(Self::ZERO), self, -self)
+        let abs = self.abs();
+        Self::select(rhs.cmpge(Self::ZERO), abs, -abs)
     }
 
     /// Returns a bitmask with the lowest 2 bits set to the sign bits from the elements of `self`.
@@ -286,7 +370,133 @@ impl IVec2 {
         (self - rhs).length_squared()
     }
 
-    /// Returns a vector that is equal to `self` rotated by 90 degrees.
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
+
+    /// Returns a vector that is equal to `self` rotated by 90 degrees counter-clockwise.
+    ///
+    /// Counter-clockwise assumes the positive y axis is 90 degrees counter-clockwise from the
+    /// positive x axis, so `X.perp() == Y` and `Y.perp() == -X`. In a coordinate system where y
+    /// points down, such as screen space, the rotation will appear clockwise.
     #[inline]
     pub fn perp(self) -> Self {
         Self {
@@ -297,6 +507,13 @@ impl IVec2 {
 
     /// The perpendicular dot product of `self` and `rhs`.
     /// Also known as the wedge product, 2D cross product, and determinant.
+    ///
+    /// This is equal to `self.perp().dot(rhs)`. The result is positive if `rhs` is
+    /// counter-clockwise from `self`, negative if it is clockwise and zero if they are parallel,
+    /// using the same convention as [`Self::perp()`].
+    ///
+    /// The result is also the signed area of the parallelogram spanned by `self` and `rhs`, half
+    /// of it is the signed area of the triangle with edges `self` and `rhs`.
     #[doc(alias = "wedge")]
     #[doc(alias = "cross")]
     #[doc(alias = "determinant")]
@@ -306,8 +523,11 @@ impl IVec2 {
     }
 
     /// Returns `rhs` rotated by the angle of `self`. If `self` is normalized,
-    /// then this just rotation. This is what you usually want. Otherwise,
+    /// then this is just a rotation. This is what you usually want. Otherwise,
     /// it will be like a rotation with a multiplication by `self`'s length.
+    ///
+    /// This treats both vectors as complex numbers, so rotations can be composed, e.g.
+    /// `a.rotate(b.rotate(v))` is equal to `a.rotate(b).rotate(v)`.
     #[must_use]
     #[inline]
     pub fn rotate(self, rhs: Self) -> Self {
@@ -346,6 +566,36 @@ impl IVec2 {
     pub fn as_u64vec2(&self) -> crate::U64Vec2 {
         crate::U64Vec2::new(self.x as u64, self.y as u64)
     }
+
+    /// Casts all elements of `self` to `u32`, saturating elements that are out of
+    /// range to `u32::MIN` or `u32::MAX`.
+    ///
+    /// Unlike [`Self::as_uvec2()`] this never wraps around. To check that all
+    /// elements are in range use [`UVec2::try_from()`] instead.
+    ///
+    /// [`UVec2::try_from()`]: crate::UVec2::try_from
+    #[inline]
+    pub fn saturating_as_uvec2(&self) -> crate::UVec2 {
+        crate::UVec2::new(
+            u32::try_from(self.x).unwrap_or(if self.x < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.y).unwrap_or(if self.y < 0 { u32::MIN } else { u32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `u64`, saturating elements that are out of
+    /// range to `u64::MIN` or `u64::MAX`.
+    ///
+    /// Unlike [`Self::as_u64vec2()`] this never wraps around. To check that all
+    /// elements are in range use [`U64Vec2::try_from()`] instead.
+    ///
+    /// [`U64Vec2::try_from()`]: crate::U64Vec2::try_from
+    #[inline]
+    pub fn saturating_as_u64vec2(&self) -> crate::U64Vec2 {
+        crate::U64Vec2::new(
+            u64::try_from(self.x).unwrap_or(if self.x < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.y).unwrap_or(if self.y < 0 { u64::MIN } else { u64::MAX }),
+        )
+    }
 }
 
 impl Default for IVec2 {
@@ -689,6 +939,14 @@ impl BitAnd for IVec2 {
     }
 }
 
+impl BitAndAssign for IVec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+    }
+}
+
 impl BitOr for IVec2 {
     type Output = Self;
     #[inline]
@@ -700,6 +958,14 @@ impl BitOr for IVec2 {
     }
 }
 
+impl BitOrAssign for IVec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+    }
+}
+
 impl BitXor for IVec2 {
     type Output = Self;
     #[inline]
@@ -711,6 +977,14 @@ impl BitXor for IVec2 {
     }
 }
 
+impl BitXorAssign for IVec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+    }
+}
+
 impl BitAnd<i32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -722,6 +996,14 @@ impl BitAnd<i32> for IVec2 {
     }
 }
 
+impl BitAndAssign<i32> for IVec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: i32) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<i32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -733,6 +1015,14 @@ impl BitOr<i32> for IVec2 {
     }
 }
 
+impl BitOrAssign<i32> for IVec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: i32) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<i32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -744,6 +1034,14 @@ impl BitXor<i32> for IVec2 {
     }
 }
 
+impl BitXorAssign<i32> for IVec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: i32) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for IVec2 {
     type Output = Self;
     #[inline]
@@ -755,6 +1053,14 @@ impl Shl<i8> for IVec2 {
     }
 }
 
+impl ShlAssign<i8> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for IVec2 {
     type Output = Self;
     #[inline]
@@ -766,6 +1072,14 @@ impl Shr<i8> for IVec2 {
     }
 }
 
+impl ShrAssign<i8> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for IVec2 {
     type Output = Self;
     #[inline]
@@ -777,6 +1091,14 @@ impl Shl<i16> for IVec2 {
     }
 }
 
+impl ShlAssign<i16> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for IVec2 {
     type Output = Self;
     #[inline]
@@ -788,6 +1110,14 @@ impl Shr<i16> for IVec2 {
     }
 }
 
+impl ShrAssign<i16> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -799,6 +1129,14 @@ impl Shl<i32> for IVec2 {
     }
 }
 
+impl ShlAssign<i32> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -810,6 +1148,14 @@ impl Shr<i32> for IVec2 {
     }
 }
 
+impl ShrAssign<i32> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for IVec2 {
     type Output = Self;
     #[inline]
@@ -821,6 +1167,14 @@ impl Shl<i64> for IVec2 {
     }
 }
 
+impl ShlAssign<i64> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for IVec2 {
     type Output = Self;
     #[inline]
@@ -832,6 +1186,14 @@ impl Shr<i64> for IVec2 {
     }
 }
 
+impl ShrAssign<i64> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for IVec2 {
     type Output = Self;
     #[inline]
@@ -843,6 +1205,14 @@ impl Shl<u8> for IVec2 {
     }
 }
 
+impl ShlAssign<u8> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for IVec2 {
     type Output = Self;
     #[inline]
@@ -854,6 +1224,14 @@ impl Shr<u8> for IVec2 {
     }
 }
 
+impl ShrAssign<u8> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for IVec2 {
     type Output = Self;
     #[inline]
@@ -865,6 +1243,14 @@ impl Shl<u16> for IVec2 {
     }
 }
 
+impl ShlAssign<u16> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for IVec2 {
     type Output = Self;
     #[inline]
@@ -876,6 +1262,14 @@ impl Shr<u16> for IVec2 {
     }
 }
 
+impl ShrAssign<u16> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -887,6 +1281,14 @@ impl Shl<u32> for IVec2 {
     }
 }
 
+impl ShlAssign<u32> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for IVec2 {
     type Output = Self;
     #[inline]
@@ -898,6 +1300,14 @@ impl Shr<u32> for IVec2 {
     }
 }
 
+impl ShrAssign<u32> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for IVec2 {
     type Output = Self;
     #[inline]
@@ -909,6 +1319,14 @@ impl Shl<u64> for IVec2 {
     }
 }
 
+impl ShlAssign<u64> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for IVec2 {
     type Output = Self;
     #[inline]
@@ -920,6 +1338,14 @@ impl Shr<u64> for IVec2 {
     }
 }
 
+impl ShrAssign<u64> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec2> for IVec2 {
     type Output = Self;
     #[inline]
@@ -931,6 +1357,14 @@ impl Shl<crate::IVec2> for IVec2 {
     }
 }
 
+impl ShlAssign<crate::IVec2> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::IVec2> for IVec2 {
     type Output = Self;
     #[inline]
@@ -942,6 +1376,14 @@ impl Shr<crate::IVec2> for IVec2 {
     }
 }
 
+impl ShrAssign<crate::IVec2> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Shl<crate::UVec2> for IVec2 {
     type Output = Self;
     #[inline]
@@ -953,6 +1395,14 @@ impl Shl<crate::UVec2> for IVec2 {
     }
 }
 
+impl ShlAssign<crate::UVec2> for IVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::UVec2> for IVec2 {
     type Output = Self;
     #[inline]
@@ -964,6 +1414,14 @@ impl Shr<crate::UVec2> for IVec2 {
     }
 }
 
+impl ShrAssign<crate::UVec2> for IVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Index<usize> for IVec2 {
     type Output = i32;
     #[inline]
@@ -990,7 +1448,22 @@ impl IndexMut<usize> for IVec2 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for IVec2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}]", self.x, self.y)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for IVec2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -1031,3 +1504,45 @@ impl From<IVec2> for (i32, i32) {
         (v.x, v.y)
     }
 }
+
+impl TryFrom<crate::UVec2> for IVec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::UVec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
+
+impl TryFrom<crate::I64Vec2> for IVec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::I64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
+
+impl TryFrom<crate::U64Vec2> for IVec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::U64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
@@ -14,8 +14,17 @@ pub const fn ivec3(x: i32, y: i32, z: i32) -> IVec3 {
 }
 
 /// A 3-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`, then `z`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
 pub struct IVec3 {
@@ -34,6 +43,12 @@ impl IVec3 {
     /// All negative ones.
     pub const NEG_ONE: Self = Self::splat(-1);
 
+    /// All `i32::MIN`.
+    pub const MIN: Self = Self::splat(i32::MIN);
+
+    /// All `i32::MAX`.
+    pub const MAX: Self = Self::splat(i32::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0, 0);
 
@@ -67,6 +82,15 @@ impl IVec3 {
         Self { x: v, y: v, z: v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(i32) -> i32,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -87,6 +111,17 @@ impl IVec3 {
         Self::new(a[0], a[1], a[2])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> i32,
+    {
+        Self::new(f(0), f(1), f(2))
+    }
+
     /// `[x, y, z]`
     #[inline]
     pub const fn to_array(&self) -> [i32; 3] {
@@ -216,6 +251,60 @@ impl IVec3 {
         self.x.max(self.y.max(self.z))
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+        if self.z < min {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+        if self.z > max {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> i32 {
+        self.x.wrapping_add(self.y).wrapping_add(self.z)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> i32 {
+        self.x.wrapping_mul(self.y).wrapping_mul(self.z)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -301,9 +390,12 @@ impl IVec3 {
     }
 
     /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    ///
+    /// A zero element in `rhs` is treated as positive.
     #[inline]
     pub fn copysign(self, rhs: Self) -> Self {
-        Self::select(rhs.cmpge(Self::ZERO), self, -self)
+        let abs = self.abs();
+        Self::select(rhs.cmpge(Self::ZERO), abs, -abs)
     }
 
     /// Returns a bitmask with the lowest 3 bits set to the sign bits from the elements of `self`.
@@ -330,6 +422,137 @@ impl IVec3 {
         (self - rhs).length_squared()
     }
 
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+            z: (self.z & rhs.z) + ((self.z ^ rhs.z) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+            z: self.z.wrapping_add(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+            z: self.z.wrapping_sub(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+            z: self.z.saturating_add(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+            z: self.z.saturating_sub(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+            z: self.z.checked_sub(rhs.z)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+            z: self.z.div_euclid(rhs.z),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i32::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+            z: self.z.rem_euclid(rhs.z),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     pub fn as_vec3(&self) -> crate::Vec3 {
@@ -365,6 +588,38 @@ impl IVec3 {
     pub fn as_u64vec3(&self) -> crate::U64Vec3 {
         crate::U64Vec3::new(self.x as u64, self.y as u64, self.z as u64)
     }
+
+    /// Casts all elements of `self` to `u32`, saturating elements that are out of
+    /// range to `u32::MIN` or `u32::MAX`.
+    ///
+    /// Unlike [`Self::as_uvec3()`] this never wraps around. To check that all
+    /// elements are in range use [`UVec3::try_from()`] instead.
+    ///
+    /// [`UVec3::try_from()`]: crate::UVec3::try_from
+    #[inline]
+    pub fn saturating_as_uvec3(&self) -> crate::UVec3 {
+        crate::UVec3::new(
+            u32::try_from(self.x).unwrap_or(if self.x < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.y).unwrap_or(if self.y < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.z).unwrap_or(if self.z < 0 { u32::MIN } else { u32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `u64`, saturating elements that are out of
+    /// range to `u64::MIN` or `u64::MAX`.
+    ///
+    /// Unlike [`Self::as_u64vec3()`] this never wraps around. To check that all
+    /// elements are in range use [`U64Vec3::try_from()`] instead.
+    ///
+    /// [`U64Vec3::try_from()`]: crate::U64Vec3::try_from
+    #[inline]
+    pub fn saturating_as_u64vec3(&self) -> crate::U64Vec3 {
+        crate::U64Vec3::new(
+            u64::try_from(self.x).unwrap_or(if self.x < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.y).unwrap_or(if self.y < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.z).unwrap_or(if self.z < 0 { u64::MIN } else { u64::MAX }),
+        )
+    }
 }
 
 impl Default for IVec3 {
@@ -736,6 +991,15 @@ impl BitAnd for IVec3 {
     }
 }
 
+impl BitAndAssign for IVec3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+        self.z.bitand_assign(rhs.z);
+    }
+}
+
 impl BitOr for IVec3 {
     type Output = Self;
     #[inline]
@@ -748,6 +1012,15 @@ impl BitOr for IVec3 {
     }
 }
 
+impl BitOrAssign for IVec3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+        self.z.bitor_assign(rhs.z);
+    }
+}
+
 impl BitXor for IVec3 {
     type Output = Self;
     #[inline]
@@ -760,6 +1033,15 @@ impl BitXor for IVec3 {
     }
 }
 
+impl BitXorAssign for IVec3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+        self.z.bitxor_assign(rhs.z);
+    }
+}
+
 impl BitAnd<i32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -772,6 +1054,15 @@ impl BitAnd<i32> for IVec3 {
     }
 }
 
+impl BitAndAssign<i32> for IVec3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: i32) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+        self.z.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<i32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -784,6 +1075,15 @@ impl BitOr<i32> for IVec3 {
     }
 }
 
+impl BitOrAssign<i32> for IVec3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: i32) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+        self.z.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<i32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -796,6 +1096,15 @@ impl BitXor<i32> for IVec3 {
     }
 }
 
+impl BitXorAssign<i32> for IVec3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: i32) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+        self.z.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for IVec3 {
     type Output = Self;
     #[inline]
@@ -808,6 +1117,15 @@ impl Shl<i8> for IVec3 {
     }
 }
 
+impl ShlAssign<i8> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for IVec3 {
     type Output = Self;
     #[inline]
@@ -820,6 +1138,15 @@ impl Shr<i8> for IVec3 {
     }
 }
 
+impl ShrAssign<i8> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for IVec3 {
     type Output = Self;
     #[inline]
@@ -832,6 +1159,15 @@ impl Shl<i16> for IVec3 {
     }
 }
 
+impl ShlAssign<i16> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for IVec3 {
     type Output = Self;
     #[inline]
@@ -844,6 +1180,15 @@ impl Shr<i16> for IVec3 {
     }
 }
 
+impl ShrAssign<i16> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -856,6 +1201,15 @@ impl Shl<i32> for IVec3 {
     }
 }
 
+impl ShlAssign<i32> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -868,6 +1222,15 @@ impl Shr<i32> for IVec3 {
     }
 }
 
+impl ShrAssign<i32> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for IVec3 {
     type Output = Self;
     #[inline]
@@ -880,6 +1243,15 @@ impl Shl<i64> for IVec3 {
     }
 }
 
+impl ShlAssign<i64> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for IVec3 {
     type Output = Self;
     #[inline]
@@ -892,6 +1264,15 @@ impl Shr<i64> for IVec3 {
     }
 }
 
+impl ShrAssign<i64> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for IVec3 {
     type Output = Self;
     #[inline]
@@ -904,6 +1285,15 @@ impl Shl<u8> for IVec3 {
     }
 }
 
+impl ShlAssign<u8> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for IVec3 {
     type Output = Self;
     #[inline]
@@ -916,6 +1306,15 @@ impl Shr<u8> for IVec3 {
     }
 }
 
+impl ShrAssign<u8> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for IVec3 {
     type Output = Self;
     #[inline]
@@ -928,6 +1327,15 @@ impl Shl<u16> for IVec3 {
     }
 }
 
+impl ShlAssign<u16> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for IVec3 {
     type Output = Self;
     #[inline]
@@ -940,6 +1348,15 @@ impl Shr<u16> for IVec3 {
     }
 }
 
+impl ShrAssign<u16> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -952,6 +1369,15 @@ impl Shl<u32> for IVec3 {
     }
 }
 
+impl ShlAssign<u32> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for IVec3 {
     type Output = Self;
     #[inline]
@@ -964,6 +1390,15 @@ impl Shr<u32> for IVec3 {
     }
 }
 
+impl ShrAssign<u32> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for IVec3 {
     type Output = Self;
     #[inline]
@@ -976,6 +1411,15 @@ impl Shl<u64> for IVec3 {
     }
 }
 
+impl ShlAssign<u64> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for IVec3 {
     type Output = Self;
     #[inline]
@@ -988,6 +1432,15 @@ impl Shr<u64> for IVec3 {
     }
 }
 
+impl ShrAssign<u64> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec3> for IVec3 {
     type Output = Self;
     #[inline]
@@ -1000,6 +1453,15 @@ impl Shl<crate::IVec3> for IVec3 {
     }
 }
 
+impl ShlAssign<crate::IVec3> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec3) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+        self.z.shl_assign(rhs.z);
+    }
+}
+
 impl Shr<crate::IVec3> for IVec3 {
     type Output = Self;
     #[inline]
@@ -1012,6 +1474,15 @@ impl Shr<crate::IVec3> for IVec3 {
     }
 }
 
+impl ShrAssign<crate::IVec3> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec3) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+        self.z.shr_assign(rhs.z);
+    }
+}
+
 impl Shl<crate::UVec3> for IVec3 {
     type Output = Self;
     #[inline]
@@ -1024,6 +1495,15 @@ impl Shl<crate::UVec3> for IVec3 {
     }
 }
 
+impl ShlAssign<crate::UVec3> for IVec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec3) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+        self.z.shl_assign(rhs.z);
+    }
+}
+
 impl Shr<crate::UVec3> for IVec3 {
     type Output = Self;
     #[inline]
@@ -1036,6 +1516,15 @@ impl Shr<crate::UVec3> for IVec3 {
     }
 }
 
+impl ShrAssign<crate::UVec3> for IVec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec3) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+        self.z.shr_assign(rhs.z);
+    }
+}
+
 impl Index<usize> for IVec3 {
     type Output = i32;
     #[inline]
@@ -1064,7 +1553,24 @@ impl IndexMut<usize> for IVec3 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for IVec3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for IVec3 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -1113,3 +1619,48 @@ impl From<(IVec2, i32)> for IVec3 {
         Self::new(v.x, v.y, z)
     }
 }
+
+impl TryFrom<crate::UVec3> for IVec3 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::UVec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+            i32::try_from(v.z).map_err(|_| crate::TryFromIntVecError::new(2))?,
+        ))
+    }
+}
+
+impl TryFrom<crate::I64Vec3> for IVec3 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::I64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+            i32::try_from(v.z).map_err(|_| crate::TryFromIntVecError::new(2))?,
+        ))
+    }
+}
+
+impl TryFrom<crate::U64Vec3> for IVec3 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::U64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+            i32::try_from(v.z).map_err(|_| crate::TryFromIntVecError::new(2))?,
+        ))
+    }
+}
@@ -14,8 +14,17 @@ pub const fn i64vec2(x: i64, y: i64) -> I64Vec2 {
 }
 
 /// A 2-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "cuda", repr(align(16)))]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
@@ -34,6 +43,12 @@ impl I64Vec2 {
     /// All negative ones.
     pub const NEG_ONE: Self = Self::splat(-1);
 
+    /// All `i64::MIN`.
+    pub const MIN: Self = Self::splat(i64::MIN);
+
+    /// All `i64::MAX`.
+    pub const MAX: Self = Self::splat(i64::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0);
 
@@ -61,6 +76,15 @@ impl I64Vec2 {
         Self { x: v, y: v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(i64) -> i64,
+    {
+        Self::new(f(self.x), f(self.y))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -80,6 +104,17 @@ impl I64Vec2 {
         Self::new(a[0], a[1])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> i64,
+    {
+        Self::new(f(0), f(1))
+    }
+
     /// `[x, y]`
     #[inline]
     pub const fn to_array(&self) -> [i64; 2] {
@@ -176,6 +211,52 @@ impl I64Vec2 {
         self.x.max(self.y)
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> i64 {
+        self.x.wrapping_add(self.y)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> i64 {
+        self.x.wrapping_mul(self.y)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -259,9 +340,12 @@ impl I64Vec2 {
     }
 
     /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    ///
+    /// A zero element in `rhs` is treated as positive.
     #[inline]
     pub fn copysign(self, rhs: Self) -> Self {
-        Self::select(rhs.cmpge(Self::ZERO), self, -self)
+        let abs = self.abs();
+        Self::select(rhs.cmpge(Self::ZERO), abs, -abs)
     }
 
     /// Returns a bitmask with the lowest 2 bits set to the sign bits from the elements of `self`.
@@ -286,7 +370,133 @@ impl I64Vec2 {
         (self - rhs).length_squared()
     }
 
-    /// Returns a vector that is equal to `self` rotated by 90 degrees.
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i64::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i64::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
+
+    /// Returns a vector that is equal to `self` rotated by 90 degrees counter-clockwise.
+    ///
+    /// Counter-clockwise assumes the positive y axis is 90 degrees counter-clockwise from the
+    /// positive x axis, so `X.perp() == Y` and `Y.perp() == -X`. In a coordinate system where y
+    /// points down, such as screen space, the rotation will appear clockwise.
     #[inline]
     pub fn perp(self) -> Self {
         Self {
@@ -297,6 +507,13 @@ impl I64Vec2 {
 
     /// The perpendicular dot product of `self` and `rhs`.
     /// Also known as the wedge product, 2D cross product, and determinant.
+    ///
+    /// This is equal to `self.perp().dot(rhs)`. The result is positive if `rhs` is
+    /// counter-clockwise from `self`, negative if it is clockwise and zero if they are parallel,
+    /// using the same convention as [`Self::perp()`].
+    ///
+    /// The result is also the signed area of the parallelogram spanned by `self` and `rhs`, half
+    /// of it is the signed area of the triangle with edges `self` and `rhs`.
     #[doc(alias = "wedge")]
     #[doc(alias = "cross")]
     #[doc(alias = "determinant")]
@@ -306,8 +523,11 @@ impl I64Vec2 {
     }
 
     /// Returns `rhs` rotated by the angle of `self`. If `self` is normalized,
-    /// then this just rotation. This is what you usually want. Otherwise,
+    /// then this is just a rotation. This is what you usually want. Otherwise,
     /// it will be like a rotation with a multiplication by `self`'s length.
+    ///
+    /// This treats both vectors as complex numbers, so rotations can be composed, e.g.
+    /// `a.rotate(b.rotate(v))` is equal to `a.rotate(b).rotate(v)`.
     #[must_use]
     #[inline]
     pub fn rotate(self, rhs: Self) -> Self {
@@ -346,6 +566,51 @@ impl I64Vec2 {
     pub fn as_u64vec2(&self) -> crate::U64Vec2 {
         crate::U64Vec2::new(self.x as u64, self.y as u64)
     }
+
+    /// Casts all elements of `self` to `i32`, saturating elements that are out of
+    /// range to `i32::MIN` or `i32::MAX`.
+    ///
+    /// Unlike [`Self::as_ivec2()`] this never wraps around. To check that all
+    /// elements are in range use [`IVec2::try_from()`] instead.
+    ///
+    /// [`IVec2::try_from()`]: crate::IVec2::try_from
+    #[inline]
+    pub fn saturating_as_ivec2(&self) -> crate::IVec2 {
+        crate::IVec2::new(
+            i32::try_from(self.x).unwrap_or(if self.x < 0 { i32::MIN } else { i32::MAX }),
+            i32::try_from(self.y).unwrap_or(if self.y < 0 { i32::MIN } else { i32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `u32`, saturating elements that are out of
+    /// range to `u32::MIN` or `u32::MAX`.
+    ///
+    /// Unlike [`Self::as_uvec2()`] this never wraps around. To check that all
+    /// elements are in range use [`UVec2::try_from()`] instead.
+    ///
+    /// [`UVec2::try_from()`]: crate::UVec2::try_from
+    #[inline]
+    pub fn saturating_as_uvec2(&self) -> crate::UVec2 {
+        crate::UVec2::new(
+            u32::try_from(self.x).unwrap_or(if self.x < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.y).unwrap_or(if self.y < 0 { u32::MIN } else { u32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `u64`, saturating elements that are out of
+    /// range to `u64::MIN` or `u64::MAX`.
+    ///
+    /// Unlike [`Self::as_u64vec2()`] this never wraps around. To check that all
+    /// elements are in range use [`U64Vec2::try_from()`] instead.
+    ///
+    /// [`U64Vec2::try_from()`]: crate::U64Vec2::try_from
+    #[inline]
+    pub fn saturating_as_u64vec2(&self) -> crate::U64Vec2 {
+        crate::U64Vec2::new(
+            u64::try_from(self.x).unwrap_or(if self.x < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.y).unwrap_or(if self.y < 0 { u64::MIN } else { u64::MAX }),
+        )
+    }
 }
 
 impl Default for I64Vec2 {
@@ -689,6 +954,14 @@ impl BitAnd for I64Vec2 {
     }
 }
 
+impl BitAndAssign for I64Vec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+    }
+}
+
 impl BitOr for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -700,6 +973,14 @@ impl BitOr for I64Vec2 {
     }
 }
 
+impl BitOrAssign for I64Vec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+    }
+}
+
 impl BitXor for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -711,6 +992,14 @@ impl BitXor for I64Vec2 {
     }
 }
 
+impl BitXorAssign for I64Vec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+    }
+}
+
 impl BitAnd<i64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -722,6 +1011,14 @@ impl BitAnd<i64> for I64Vec2 {
     }
 }
 
+impl BitAndAssign<i64> for I64Vec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: i64) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<i64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -733,6 +1030,14 @@ impl BitOr<i64> for I64Vec2 {
     }
 }
 
+impl BitOrAssign<i64> for I64Vec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: i64) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<i64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -744,6 +1049,14 @@ impl BitXor<i64> for I64Vec2 {
     }
 }
 
+impl BitXorAssign<i64> for I64Vec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: i64) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -755,6 +1068,14 @@ impl Shl<i8> for I64Vec2 {
     }
 }
 
+impl ShlAssign<i8> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -766,6 +1087,14 @@ impl Shr<i8> for I64Vec2 {
     }
 }
 
+impl ShrAssign<i8> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -777,6 +1106,14 @@ impl Shl<i16> for I64Vec2 {
     }
 }
 
+impl ShlAssign<i16> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -788,6 +1125,14 @@ impl Shr<i16> for I64Vec2 {
     }
 }
 
+impl ShrAssign<i16> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -799,6 +1144,14 @@ impl Shl<i32> for I64Vec2 {
     }
 }
 
+impl ShlAssign<i32> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -810,6 +1163,14 @@ impl Shr<i32> for I64Vec2 {
     }
 }
 
+impl ShrAssign<i32> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -821,6 +1182,14 @@ impl Shl<i64> for I64Vec2 {
     }
 }
 
+impl ShlAssign<i64> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -832,6 +1201,14 @@ impl Shr<i64> for I64Vec2 {
     }
 }
 
+impl ShrAssign<i64> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -843,6 +1220,14 @@ impl Shl<u8> for I64Vec2 {
     }
 }
 
+impl ShlAssign<u8> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -854,6 +1239,14 @@ impl Shr<u8> for I64Vec2 {
     }
 }
 
+impl ShrAssign<u8> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -865,6 +1258,14 @@ impl Shl<u16> for I64Vec2 {
     }
 }
 
+impl ShlAssign<u16> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -876,6 +1277,14 @@ impl Shr<u16> for I64Vec2 {
     }
 }
 
+impl ShrAssign<u16> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -887,6 +1296,14 @@ impl Shl<u32> for I64Vec2 {
     }
 }
 
+impl ShlAssign<u32> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -898,6 +1315,14 @@ impl Shr<u32> for I64Vec2 {
     }
 }
 
+impl ShrAssign<u32> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -909,6 +1334,14 @@ impl Shl<u64> for I64Vec2 {
     }
 }
 
+impl ShlAssign<u64> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -920,6 +1353,14 @@ impl Shr<u64> for I64Vec2 {
     }
 }
 
+impl ShrAssign<u64> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec2> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -931,6 +1372,14 @@ impl Shl<crate::IVec2> for I64Vec2 {
     }
 }
 
+impl ShlAssign<crate::IVec2> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::IVec2> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -942,6 +1391,14 @@ impl Shr<crate::IVec2> for I64Vec2 {
     }
 }
 
+impl ShrAssign<crate::IVec2> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Shl<crate::UVec2> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -953,6 +1410,14 @@ impl Shl<crate::UVec2> for I64Vec2 {
     }
 }
 
+impl ShlAssign<crate::UVec2> for I64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::UVec2> for I64Vec2 {
     type Output = Self;
     #[inline]
@@ -964,6 +1429,14 @@ impl Shr<crate::UVec2> for I64Vec2 {
     }
 }
 
+impl ShrAssign<crate::UVec2> for I64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Index<usize> for I64Vec2 {
     type Output = i64;
     #[inline]
@@ -990,7 +1463,22 @@ impl IndexMut<usize> for I64Vec2 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for I64Vec2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}]", self.x, self.y)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for I64Vec2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -1031,3 +1519,31 @@ impl From<I64Vec2> for (i64, i64) {
         (v.x, v.y)
     }
 }
+
+impl From<crate::IVec2> for I64Vec2 {
+    #[inline]
+    fn from(v: crate::IVec2) -> Self {
+        Self::new(i64::from(v.x), i64::from(v.y))
+    }
+}
+
+impl From<crate::UVec2> for I64Vec2 {
+    #[inline]
+    fn from(v: crate::UVec2) -> Self {
+        Self::new(i64::from(v.x), i64::from(v.y))
+    }
+}
+
+impl TryFrom<crate::U64Vec2> for I64Vec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i64`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::U64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i64::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i64::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
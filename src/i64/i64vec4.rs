@@ -14,8 +14,17 @@ pub const fn i64vec4(x: i64, y: i64, z: i64, w: i64) -> I64Vec4 {
 }
 
 /// A 4-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`, then `z`, then `w`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "cuda", repr(align(16)))]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
@@ -36,6 +45,12 @@ impl I64Vec4 {
     /// All negative ones.
     pub const NEG_ONE: Self = Self::splat(-1);
 
+    /// All `i64::MIN`.
+    pub const MIN: Self = Self::splat(i64::MIN);
+
+    /// All `i64::MAX`.
+    pub const MAX: Self = Self::splat(i64::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0, 0, 0);
 
@@ -83,6 +98,15 @@ impl I64Vec4 {
         }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(i64) -> i64,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z), f(self.w))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -104,6 +128,17 @@ impl I64Vec4 {
         Self::new(a[0], a[1], a[2], a[3])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> i64,
+    {
+        Self::new(f(0), f(1), f(2), f(3))
+    }
+
     /// `[x, y, z, w]`
     #[inline]
     pub const fn to_array(&self) -> [i64; 4] {
@@ -133,7 +168,7 @@ impl I64Vec4 {
         slice[3] = self.w;
     }
 
-    /// Creates a 2D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
     ///
     /// Truncation to [`I64Vec3`] may also be performed by using [`self.xyz()`][crate::swizzles::Vec4Swizzles::xyz()].
     #[inline]
@@ -209,6 +244,74 @@ impl I64Vec4 {
         self.x.max(self.y.max(self.z.max(self.w)))
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+        if self.z < min {
+            min = self.z;
+            index = 2;
+        }
+        if self.w < min {
+            index = 3;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+        if self.z > max {
+            max = self.z;
+            index = 2;
+        }
+        if self.w > max {
+            index = 3;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> i64 {
+        self.x
+            .wrapping_add(self.y)
+            .wrapping_add(self.z)
+            .wrapping_add(self.w)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> i64 {
+        self.x
+            .wrapping_mul(self.y)
+            .wrapping_mul(self.z)
+            .wrapping_mul(self.w)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -326,9 +429,12 @@ impl I64Vec4 {
     }
 
     /// Returns a vector with signs of `rhs` and the magnitudes of `self`.
+    ///
+    /// A zero element in `rhs` is treated as positive.
     #[inline]
     pub fn copysign(self, rhs: Self) -> Self {
-        Self::select(rhs.cmpge(Self::ZERO), self, -self)
+        let abs = self.abs();
+        Self::select(rhs.cmpge(Self::ZERO), abs, -abs)
     }
 
     /// Returns a bitmask with the lowest 4 bits set to the sign bits from the elements of `self`.
@@ -356,6 +462,146 @@ impl I64Vec4 {
         (self - rhs).length_squared()
     }
 
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+            z: (self.z & rhs.z) + ((self.z ^ rhs.z) >> 1),
+            w: (self.w & rhs.w) + ((self.w ^ rhs.w) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+            z: self.z.wrapping_add(rhs.z),
+            w: self.w.wrapping_add(rhs.w),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+            z: self.z.wrapping_sub(rhs.z),
+            w: self.w.wrapping_sub(rhs.w),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+            z: self.z.saturating_add(rhs.z),
+            w: self.w.saturating_add(rhs.w),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+            z: self.z.saturating_sub(rhs.z),
+            w: self.w.saturating_sub(rhs.w),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+            w: self.w.checked_add(rhs.w)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+            z: self.z.checked_sub(rhs.z)?,
+            w: self.w.checked_sub(rhs.w)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i64::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+            z: self.z.div_euclid(rhs.z),
+            w: self.w.div_euclid(rhs.w),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: i64::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+            z: self.z.rem_euclid(rhs.z),
+            w: self.w.rem_euclid(rhs.w),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     pub fn as_vec4(&self) -> crate::Vec4 {
@@ -385,6 +631,57 @@ impl I64Vec4 {
     pub fn as_u64vec4(&self) -> crate::U64Vec4 {
         crate::U64Vec4::new(self.x as u64, self.y as u64, self.z as u64, self.w as u64)
     }
+
+    /// Casts all elements of `self` to `i32`, saturating elements that are out of
+    /// range to `i32::MIN` or `i32::MAX`.
+    ///
+    /// Unlike [`Self::as_ivec4()`] this never wraps around. To check that all
+    /// elements are in range use [`IVec4::try_from()`] instead.
+    ///
+    /// [`IVec4::try_from()`]: crate::IVec4::try_from
+    #[inline]
+    pub fn saturating_as_ivec4(&self) -> crate::IVec4 {
+        crate::IVec4::new(
+            i32::try_from(self.x).unwrap_or(if self.x < 0 { i32::MIN } else { i32::MAX }),
+            i32::try_from(self.y).unwrap_or(if self.y < 0 { i32::MIN } else { i32::MAX }),
+            i32::try_from(self.z).unwrap_or(if self.z < 0 { i32::MIN } else { i32::MAX }),
+            i32::try_from(self.w).unwrap_or(if self.w < 0 { i32::MIN } else { i32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `u32`, saturating elements that are out of
+    /// range to `u32::MIN` or `u32::MAX`.
+    ///
+    /// Unlike [`Self::as_uvec4()`] this never wraps around. To check that all
+    /// elements are in range use [`UVec4::try_from()`] instead.
+    ///
+    /// [`UVec4::try_from()`]: crate::UVec4::try_from
+    #[inline]
+    pub fn saturating_as_uvec4(&self) -> crate::UVec4 {
+        crate::UVec4::new(
+            u32::try_from(self.x).unwrap_or(if self.x < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.y).unwrap_or(if self.y < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.z).unwrap_or(if self.z < 0 { u32::MIN } else { u32::MAX }),
+            u32::try_from(self.w).unwrap_or(if self.w < 0 { u32::MIN } else { u32::MAX }),
+        )
+    }
+
+    /// Casts all elements of `self` to `u64`, saturating elements that are out of
+    /// range to `u64::MIN` or `u64::MAX`.
+    ///
+    /// Unlike [`Self::as_u64vec4()`] this never wraps around. To check that all
+    /// elements are in range use [`U64Vec4::try_from()`] instead.
+    ///
+    /// [`U64Vec4::try_from()`]: crate::U64Vec4::try_from
+    #[inline]
+    pub fn saturating_as_u64vec4(&self) -> crate::U64Vec4 {
+        crate::U64Vec4::new(
+            u64::try_from(self.x).unwrap_or(if self.x < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.y).unwrap_or(if self.y < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.z).unwrap_or(if self.z < 0 { u64::MIN } else { u64::MAX }),
+            u64::try_from(self.w).unwrap_or(if self.w < 0 { u64::MIN } else { u64::MAX }),
+        )
+    }
 }
 
 impl Default for I64Vec4 {
@@ -784,6 +1081,16 @@ impl BitAnd for I64Vec4 {
     }
 }
 
+impl BitAndAssign for I64Vec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+        self.z.bitand_assign(rhs.z);
+        self.w.bitand_assign(rhs.w);
+    }
+}
+
 impl BitOr for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -797,6 +1104,16 @@ impl BitOr for I64Vec4 {
     }
 }
 
+impl BitOrAssign for I64Vec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+        self.z.bitor_assign(rhs.z);
+        self.w.bitor_assign(rhs.w);
+    }
+}
+
 impl BitXor for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -810,6 +1127,16 @@ impl BitXor for I64Vec4 {
     }
 }
 
+impl BitXorAssign for I64Vec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+        self.z.bitxor_assign(rhs.z);
+        self.w.bitxor_assign(rhs.w);
+    }
+}
+
 impl BitAnd<i64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -823,6 +1150,16 @@ impl BitAnd<i64> for I64Vec4 {
     }
 }
 
+impl BitAndAssign<i64> for I64Vec4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: i64) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+        self.z.bitand_assign(rhs);
+        self.w.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<i64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -836,6 +1173,16 @@ impl BitOr<i64> for I64Vec4 {
     }
 }
 
+impl BitOrAssign<i64> for I64Vec4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: i64) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+        self.z.bitor_assign(rhs);
+        self.w.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<i64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -849,6 +1196,16 @@ impl BitXor<i64> for I64Vec4 {
     }
 }
 
+impl BitXorAssign<i64> for I64Vec4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: i64) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+        self.z.bitxor_assign(rhs);
+        self.w.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -862,6 +1219,16 @@ impl Shl<i8> for I64Vec4 {
     }
 }
 
+impl ShlAssign<i8> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -875,6 +1242,16 @@ impl Shr<i8> for I64Vec4 {
     }
 }
 
+impl ShrAssign<i8> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -888,6 +1265,16 @@ impl Shl<i16> for I64Vec4 {
     }
 }
 
+impl ShlAssign<i16> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -901,6 +1288,16 @@ impl Shr<i16> for I64Vec4 {
     }
 }
 
+impl ShrAssign<i16> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -914,6 +1311,16 @@ impl Shl<i32> for I64Vec4 {
     }
 }
 
+impl ShlAssign<i32> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -927,6 +1334,16 @@ impl Shr<i32> for I64Vec4 {
     }
 }
 
+impl ShrAssign<i32> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -940,6 +1357,16 @@ impl Shl<i64> for I64Vec4 {
     }
 }
 
+impl ShlAssign<i64> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -953,6 +1380,16 @@ impl Shr<i64> for I64Vec4 {
     }
 }
 
+impl ShrAssign<i64> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -966,6 +1403,16 @@ impl Shl<u8> for I64Vec4 {
     }
 }
 
+impl ShlAssign<u8> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -979,6 +1426,16 @@ impl Shr<u8> for I64Vec4 {
     }
 }
 
+impl ShrAssign<u8> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -992,6 +1449,16 @@ impl Shl<u16> for I64Vec4 {
     }
 }
 
+impl ShlAssign<u16> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1005,6 +1472,16 @@ impl Shr<u16> for I64Vec4 {
     }
 }
 
+impl ShrAssign<u16> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1018,6 +1495,16 @@ impl Shl<u32> for I64Vec4 {
     }
 }
 
+impl ShlAssign<u32> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1031,6 +1518,16 @@ impl Shr<u32> for I64Vec4 {
     }
 }
 
+impl ShrAssign<u32> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1044,6 +1541,16 @@ impl Shl<u64> for I64Vec4 {
     }
 }
 
+impl ShlAssign<u64> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+        self.w.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1057,6 +1564,16 @@ impl Shr<u64> for I64Vec4 {
     }
 }
 
+impl ShrAssign<u64> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+        self.w.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec4> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1070,6 +1587,16 @@ impl Shl<crate::IVec4> for I64Vec4 {
     }
 }
 
+impl ShlAssign<crate::IVec4> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec4) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+        self.z.shl_assign(rhs.z);
+        self.w.shl_assign(rhs.w);
+    }
+}
+
 impl Shr<crate::IVec4> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1083,6 +1610,16 @@ impl Shr<crate::IVec4> for I64Vec4 {
     }
 }
 
+impl ShrAssign<crate::IVec4> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec4) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+        self.z.shr_assign(rhs.z);
+        self.w.shr_assign(rhs.w);
+    }
+}
+
 impl Shl<crate::UVec4> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1096,6 +1633,16 @@ impl Shl<crate::UVec4> for I64Vec4 {
     }
 }
 
+impl ShlAssign<crate::UVec4> for I64Vec4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec4) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+        self.z.shl_assign(rhs.z);
+        self.w.shl_assign(rhs.w);
+    }
+}
+
 impl Shr<crate::UVec4> for I64Vec4 {
     type Output = Self;
     #[inline]
@@ -1109,6 +1656,16 @@ impl Shr<crate::UVec4> for I64Vec4 {
     }
 }
 
+impl ShrAssign<crate::UVec4> for I64Vec4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec4) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+        self.z.shr_assign(rhs.z);
+        self.w.shr_assign(rhs.w);
+    }
+}
+
 impl Index<usize> for I64Vec4 {
     type Output = i64;
     #[inline]
@@ -1139,7 +1696,26 @@ impl IndexMut<usize> for I64Vec4 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for I64Vec4 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.w, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for I64Vec4 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -1210,3 +1786,43 @@ impl From<(I64Vec2, I64Vec2)> for I64Vec4 {
         Self::new(v.x, v.y, u.x, u.y)
     }
 }
+
+impl From<crate::IVec4> for I64Vec4 {
+    #[inline]
+    fn from(v: crate::IVec4) -> Self {
+        Self::new(
+            i64::from(v.x),
+            i64::from(v.y),
+            i64::from(v.z),
+            i64::from(v.w),
+        )
+    }
+}
+
+impl From<crate::UVec4> for I64Vec4 {
+    #[inline]
+    fn from(v: crate::UVec4) -> Self {
+        Self::new(
+            i64::from(v.x),
+            i64::from(v.y),
+            i64::from(v.z),
+            i64::from(v.w),
+        )
+    }
+}
+
+impl TryFrom<crate::U64Vec4> for I64Vec4 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `i64`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::U64Vec4) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            i64::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            i64::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+            i64::try_from(v.z).map_err(|_| crate::TryFromIntVecError::new(2))?,
+            i64::try_from(v.w).map_err(|_| crate::TryFromIntVecError::new(3))?,
+        ))
+    }
+}
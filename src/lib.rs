@@ -165,7 +165,10 @@ functions in documentation and so on. The traits are [`Vec2Swizzles`], [`Vec3Swi
 [`Vec4Swizzles`].
 
 Note that the [`Vec3Swizzles`] implementation for [`Vec3A`] will return a [`Vec3A`] for 3 element
-swizzles, all other implementations will return [`Vec3`].
+swizzles, all other implementations will return [`Vec3`]. 2 and 4 element swizzles of a [`Vec3A`]
+return a [`Vec2`] and [`Vec4`] respectively, so there is no need to convert to [`Vec3`] first. A [`Vec4`]
+can be swizzled straight into a [`Vec3A`] using the `_a` suffixed methods of
+[`Vec4ToVec3ASwizzles`].
 
 ```
 use glam::{swizzles::*, Vec2, Vec3, Vec3A, Vec4};
@@ -180,10 +183,9 @@ assert_eq!(Vec4::new(4.0, 3.0, 2.0, 1.0), wzyx);
 let yzw = v.yzw();
 assert_eq!(Vec3::new(2.0, 3.0, 4.0), yzw);
 
-// To swizzle a `Vec4` into a `Vec3A` swizzle the `Vec4` first then convert to
-// `Vec3A`. If SIMD is supported this will use a vector shuffle. The last
-// element of the shuffled `Vec4` is ignored by the `Vec3A`.
-let yzw = Vec3A::from(v.yzwx());
+// Swizzle the yzw elements of `v` into a `Vec3A`. If SIMD is supported this
+// will use a single vector shuffle.
+let yzw = v.yzw_a();
 assert_eq!(Vec3A::new(2.0, 3.0, 4.0), yzw);
 
 // You can swizzle from a `Vec4` to a `Vec2`
@@ -193,6 +195,38 @@ assert_eq!(Vec2::new(1.0, 2.0), xy);
 // And back again
 let yyxx = xy.yyxx();
 assert_eq!(Vec4::new(2.0, 2.0, 1.0, 1.0), yyxx);
+
+// A `Vec3A` can be swizzled into a `Vec2`, `Vec3A` or `Vec4`
+let v = Vec3A::new(1.0, 2.0, 3.0);
+assert_eq!(Vec2::new(3.0, 1.0), v.zx());
+assert_eq!(Vec3A::new(3.0, 2.0, 1.0), v.zyx());
+assert_eq!(Vec4::new(1.0, 2.0, 3.0, 3.0), v.xyzz());
+```
+
+## Constant construction
+
+Vector, quaternion and matrix constructors such as `new`, `splat`, `from_array`, `from_xyzw`
+and `from_cols` are `const fn` for both scalar and SIMD storage, so `glam` types can be
+used in `const` and `static` items. On SSE2 the SIMD types are built through a `union` cast,
+reading `union` fields in a `const fn` has been stable since Rust 1.56 which is within the MSRV.
+
+```
+use glam::{Mat4, Quat, Vec3, Vec4};
+
+static DIRECTIONS: [Vec3; 6] = [
+    Vec3::X,
+    Vec3::NEG_X,
+    Vec3::new(0.0, 1.0, 0.0),
+    Vec3::new(0.0, -1.0, 0.0),
+    Vec3::Z,
+    Vec3::NEG_Z,
+];
+
+const FLIP_Z: Mat4 = Mat4::from_cols(Vec4::X, Vec4::Y, Vec4::NEG_Z, Vec4::W);
+const HALF_TURN: Quat = Quat::from_xyzw(0.0, 1.0, 0.0, 0.0);
+
+assert_eq!(DIRECTIONS[5], FLIP_Z.transform_vector3(DIRECTIONS[4]));
+assert!(HALF_TURN.mul_vec3(DIRECTIONS[0]).abs_diff_eq(DIRECTIONS[1], 1e-6));
 ```
 
 ## SIMD and scalar consistency
@@ -267,10 +301,16 @@ The minimum supported Rust version is `1.58.1`.
 mod macros;
 
 mod align16;
+mod convert;
+#[cfg(not(target_arch = "spirv"))]
+mod debug;
 mod deref;
 mod euler;
 mod features;
 
+#[cfg(not(target_arch = "spirv"))]
+mod parse;
+
 #[cfg(target_arch = "spirv")]
 mod spirv;
 
@@ -325,7 +365,14 @@ pub use self::u64::*;
 
 /** Traits adding swizzle methods to all vector types. */
 pub mod swizzles;
-pub use self::swizzles::{Vec2Swizzles, Vec3Swizzles, Vec4Swizzles};
+pub use self::swizzles::{Vec2Swizzles, Vec3Swizzles, Vec4Swizzles, Vec4ToVec3ASwizzles};
 
 /** Rotation Helper */
 pub use euler::EulerRot;
+
+/** Error type for checked conversions between integer vector types. */
+pub use convert::TryFromIntVecError;
+
+/** Error type for parsing glam types from strings. */
+#[cfg(not(target_arch = "spirv"))]
+pub use parse::ParseError;
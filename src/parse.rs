@@ -0,0 +1,92 @@
+/*
+Parsing of the `Display` format shared by vector, quaternion and matrix types.
+*/
+
+use core::fmt;
+use core::num::{ParseFloatError, ParseIntError};
+use core::str::FromStr;
+
+/// An error returned when parsing a glam type from a string fails.
+///
+/// Types are parsed from the format produced by their `Display` implementation, e.g.
+/// `"[1, 2, 3]"` for a `Vec3` or `"[[1, 0], [0, 1]]"` for a `Mat2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The input was not enclosed in `[` and `]`.
+    MissingBrackets,
+    /// The input did not contain the expected number of elements.
+    WrongLength,
+    /// An element could not be parsed as the element type.
+    InvalidElement,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingBrackets => "input is not enclosed in brackets",
+            Self::WrongLength => "input has the wrong number of elements",
+            Self::InvalidElement => "input contains an invalid element",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl From<ParseFloatError> for ParseError {
+    fn from(_: ParseFloatError) -> Self {
+        Self::InvalidElement
+    }
+}
+
+impl From<ParseIntError> for ParseError {
+    fn from(_: ParseIntError) -> Self {
+        Self::InvalidElement
+    }
+}
+
+/// Splits `"[a, b, ...]"` into exactly `N` comma separated elements, ignoring commas
+/// inside nested brackets.
+fn split_elements<const N: usize>(s: &str) -> Result<[&str; N], ParseError> {
+    let inner = s
+        .trim()
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or(ParseError::MissingBrackets)?;
+    let mut elements = [""; N];
+    let mut count = 0;
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                *elements.get_mut(count).ok_or(ParseError::WrongLength)? = &inner[start..i];
+                count += 1;
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    *elements.get_mut(count).ok_or(ParseError::WrongLength)? = &inner[start..];
+    if count + 1 != N {
+        return Err(ParseError::WrongLength);
+    }
+    Ok(elements)
+}
+
+/// Parses `"[a, b, ...]"` into an array of `N` elements, each of which is parsed with
+/// its own `FromStr` implementation.
+pub(crate) fn parse_array<T, const N: usize>(s: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr + Copy + Default,
+    ParseError: From<T::Err>,
+{
+    let elements = split_elements::<N>(s)?;
+    let mut out = [T::default(); N];
+    for (o, e) in out.iter_mut().zip(elements.iter()) {
+        *o = e.trim().parse()?;
+    }
+    Ok(out)
+}
@@ -32,6 +32,8 @@ const PS_SIN_COEFFICIENTS1: __m128 = m128_from_f32x4([
     -0.000_185_246_7, /*Est3*/
 ]);
 const PS_ONE: __m128 = m128_from_f32x4([1.0; 4]);
+// the largest `f32` less than 0.5
+const PS_HALF_PRED: __m128 = m128_from_f32x4([0.49999997; 4]);
 const PS_TWO_PI: __m128 = m128_from_f32x4([core::f32::consts::TAU; 4]);
 const PS_RECIPROCAL_TWO_PI: __m128 = m128_from_f32x4([0.159_154_94; 4]);
 
@@ -117,6 +119,22 @@ pub(crate) unsafe fn m128_ceil(v: __m128) -> __m128 {
     _mm_or_ps(result, _mm_castsi128_ps(test))
 }
 
+#[inline]
+pub(crate) unsafe fn m128_trunc(v: __m128) -> __m128 {
+    // Based on https://github.com/microsoft/DirectXMath `XMVectorTruncate`
+    // To handle NAN, INF and numbers greater than 8388608, use masking
+    let test = _mm_and_si128(_mm_castps_si128(v), _mm_castps_si128(PS_INV_SIGN_MASK));
+    let test = _mm_cmplt_epi32(test, _mm_castps_si128(PS_NO_FRACTION));
+    // Truncate, keeping the sign so that small negative numbers truncate to `-0.0`
+    let vint = _mm_cvttps_epi32(v);
+    let result = _mm_or_ps(_mm_cvtepi32_ps(vint), _mm_and_ps(v, PS_SIGN_MASK));
+    // All numbers less than 8388608 will use the round to int
+    let result = _mm_and_ps(result, _mm_castsi128_ps(test));
+    // All others, use the ORIGINAL value
+    let test = _mm_andnot_si128(test, _mm_castps_si128(v));
+    _mm_or_ps(result, _mm_castsi128_ps(test))
+}
+
 #[inline]
 pub(crate) unsafe fn m128_abs(v: __m128) -> __m128 {
     _mm_and_ps(v, _mm_castsi128_ps(_mm_set1_epi32(0x7f_ff_ff_ff)))
@@ -144,16 +162,11 @@ pub(crate) unsafe fn m128_neg_mul_sub(a: __m128, b: __m128, c: __m128) -> __m128
 
 #[inline]
 pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
-    // Based on https://github.com/microsoft/DirectXMath `XMVectorRound`
-    let sign = _mm_and_ps(v, PS_SIGN_MASK);
-    let s_magic = _mm_or_ps(PS_NO_FRACTION, sign);
-    let r1 = _mm_add_ps(v, s_magic);
-    let r1 = _mm_sub_ps(r1, s_magic);
-    let r2 = _mm_and_ps(v, PS_INV_SIGN_MASK);
-    let mask = _mm_cmple_ps(r2, PS_NO_FRACTION);
-    let r2 = _mm_andnot_ps(mask, v);
-    let r1 = _mm_and_ps(r1, mask);
-    _mm_xor_ps(r1, r2)
+    // Adding the largest float below 0.5 with the sign of `v` and truncating rounds half-way
+    // cases away from zero like `f32::round`, the `XMVectorRound` magic number approach rounds
+    // them to even instead.
+    let half = _mm_or_ps(PS_HALF_PRED, _mm_and_ps(v, PS_SIGN_MASK));
+    m128_trunc(_mm_add_ps(v, half))
 }
 
 /// Returns a vector whose components are the corresponding components of Angles modulo 2PI.
@@ -2,7 +2,7 @@
 
 #![allow(clippy::useless_conversion)]
 
-use crate::{Vec2, Vec3, Vec4, Vec4Swizzles};
+use crate::{Vec2, Vec3, Vec3A, Vec4, Vec4Swizzles, Vec4ToVec3ASwizzles};
 
 use core::simd::*;
 
@@ -1995,3 +1995,325 @@ impl Vec4Swizzles for Vec4 {
         Vec4(simd_swizzle!(self.0, [3, 3, 3, 3]))
     }
 }
+
+impl Vec4ToVec3ASwizzles for Vec4 {
+    #[inline]
+    fn xxx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 0, 0, 0]))
+    }
+
+    #[inline]
+    fn xxy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 0, 1, 0]))
+    }
+
+    #[inline]
+    fn xxz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 0, 2, 0]))
+    }
+
+    #[inline]
+    fn xxw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 0, 3, 0]))
+    }
+
+    #[inline]
+    fn xyx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 1, 0, 0]))
+    }
+
+    #[inline]
+    fn xyy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 1, 1, 0]))
+    }
+
+    #[inline]
+    fn xyz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 1, 2, 0]))
+    }
+
+    #[inline]
+    fn xyw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 1, 3, 0]))
+    }
+
+    #[inline]
+    fn xzx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 2, 0, 0]))
+    }
+
+    #[inline]
+    fn xzy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 2, 1, 0]))
+    }
+
+    #[inline]
+    fn xzz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 2, 2, 0]))
+    }
+
+    #[inline]
+    fn xzw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 2, 3, 0]))
+    }
+
+    #[inline]
+    fn xwx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 3, 0, 0]))
+    }
+
+    #[inline]
+    fn xwy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 3, 1, 0]))
+    }
+
+    #[inline]
+    fn xwz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 3, 2, 0]))
+    }
+
+    #[inline]
+    fn xww_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [0, 3, 3, 0]))
+    }
+
+    #[inline]
+    fn yxx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 0, 0, 0]))
+    }
+
+    #[inline]
+    fn yxy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 0, 1, 0]))
+    }
+
+    #[inline]
+    fn yxz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 0, 2, 0]))
+    }
+
+    #[inline]
+    fn yxw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 0, 3, 0]))
+    }
+
+    #[inline]
+    fn yyx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 1, 0, 0]))
+    }
+
+    #[inline]
+    fn yyy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 1, 1, 0]))
+    }
+
+    #[inline]
+    fn yyz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 1, 2, 0]))
+    }
+
+    #[inline]
+    fn yyw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 1, 3, 0]))
+    }
+
+    #[inline]
+    fn yzx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 2, 0, 0]))
+    }
+
+    #[inline]
+    fn yzy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 2, 1, 0]))
+    }
+
+    #[inline]
+    fn yzz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 2, 2, 0]))
+    }
+
+    #[inline]
+    fn yzw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 2, 3, 0]))
+    }
+
+    #[inline]
+    fn ywx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 3, 0, 0]))
+    }
+
+    #[inline]
+    fn ywy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 3, 1, 0]))
+    }
+
+    #[inline]
+    fn ywz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 3, 2, 0]))
+    }
+
+    #[inline]
+    fn yww_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [1, 3, 3, 0]))
+    }
+
+    #[inline]
+    fn zxx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 0, 0, 0]))
+    }
+
+    #[inline]
+    fn zxy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 0, 1, 0]))
+    }
+
+    #[inline]
+    fn zxz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 0, 2, 0]))
+    }
+
+    #[inline]
+    fn zxw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 0, 3, 0]))
+    }
+
+    #[inline]
+    fn zyx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 1, 0, 0]))
+    }
+
+    #[inline]
+    fn zyy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 1, 1, 0]))
+    }
+
+    #[inline]
+    fn zyz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 1, 2, 0]))
+    }
+
+    #[inline]
+    fn zyw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 1, 3, 0]))
+    }
+
+    #[inline]
+    fn zzx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 2, 0, 0]))
+    }
+
+    #[inline]
+    fn zzy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 2, 1, 0]))
+    }
+
+    #[inline]
+    fn zzz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 2, 2, 0]))
+    }
+
+    #[inline]
+    fn zzw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 2, 3, 0]))
+    }
+
+    #[inline]
+    fn zwx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 3, 0, 0]))
+    }
+
+    #[inline]
+    fn zwy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 3, 1, 0]))
+    }
+
+    #[inline]
+    fn zwz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 3, 2, 0]))
+    }
+
+    #[inline]
+    fn zww_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [2, 3, 3, 0]))
+    }
+
+    #[inline]
+    fn wxx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 0, 0, 0]))
+    }
+
+    #[inline]
+    fn wxy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 0, 1, 0]))
+    }
+
+    #[inline]
+    fn wxz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 0, 2, 0]))
+    }
+
+    #[inline]
+    fn wxw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 0, 3, 0]))
+    }
+
+    #[inline]
+    fn wyx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 1, 0, 0]))
+    }
+
+    #[inline]
+    fn wyy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 1, 1, 0]))
+    }
+
+    #[inline]
+    fn wyz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 1, 2, 0]))
+    }
+
+    #[inline]
+    fn wyw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 1, 3, 0]))
+    }
+
+    #[inline]
+    fn wzx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 2, 0, 0]))
+    }
+
+    #[inline]
+    fn wzy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 2, 1, 0]))
+    }
+
+    #[inline]
+    fn wzz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 2, 2, 0]))
+    }
+
+    #[inline]
+    fn wzw_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 2, 3, 0]))
+    }
+
+    #[inline]
+    fn wwx_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 3, 0, 0]))
+    }
+
+    #[inline]
+    fn wwy_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 3, 1, 0]))
+    }
+
+    #[inline]
+    fn wwz_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 3, 2, 0]))
+    }
+
+    #[inline]
+    fn www_a(self) -> Vec3A {
+        Vec3A(simd_swizzle!(self.0, [3, 3, 3, 0]))
+    }
+}
@@ -1,6 +1,6 @@
 // Generated from swizzle_impl.rs.tera template. Edit the template, not the generated file.
 
-use crate::{Vec2, Vec3, Vec4, Vec4Swizzles};
+use crate::{Vec2, Vec3, Vec3A, Vec4, Vec4Swizzles, Vec4ToVec3ASwizzles};
 
 impl Vec4Swizzles for Vec4 {
     type Vec2 = Vec2;
@@ -1991,3 +1991,325 @@ impl Vec4Swizzles for Vec4 {
         Vec4::new(self.w, self.w, self.w, self.w)
     }
 }
+
+impl Vec4ToVec3ASwizzles for Vec4 {
+    #[inline]
+    fn xxx_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.x, self.x)
+    }
+
+    #[inline]
+    fn xxy_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.x, self.y)
+    }
+
+    #[inline]
+    fn xxz_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.x, self.z)
+    }
+
+    #[inline]
+    fn xxw_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.x, self.w)
+    }
+
+    #[inline]
+    fn xyx_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.y, self.x)
+    }
+
+    #[inline]
+    fn xyy_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.y, self.y)
+    }
+
+    #[inline]
+    fn xyz_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn xyw_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.y, self.w)
+    }
+
+    #[inline]
+    fn xzx_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.z, self.x)
+    }
+
+    #[inline]
+    fn xzy_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.z, self.y)
+    }
+
+    #[inline]
+    fn xzz_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.z, self.z)
+    }
+
+    #[inline]
+    fn xzw_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.z, self.w)
+    }
+
+    #[inline]
+    fn xwx_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.w, self.x)
+    }
+
+    #[inline]
+    fn xwy_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.w, self.y)
+    }
+
+    #[inline]
+    fn xwz_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.w, self.z)
+    }
+
+    #[inline]
+    fn xww_a(self) -> Vec3A {
+        Vec3A::new(self.x, self.w, self.w)
+    }
+
+    #[inline]
+    fn yxx_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.x, self.x)
+    }
+
+    #[inline]
+    fn yxy_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.x, self.y)
+    }
+
+    #[inline]
+    fn yxz_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.x, self.z)
+    }
+
+    #[inline]
+    fn yxw_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.x, self.w)
+    }
+
+    #[inline]
+    fn yyx_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.y, self.x)
+    }
+
+    #[inline]
+    fn yyy_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.y, self.y)
+    }
+
+    #[inline]
+    fn yyz_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.y, self.z)
+    }
+
+    #[inline]
+    fn yyw_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.y, self.w)
+    }
+
+    #[inline]
+    fn yzx_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.z, self.x)
+    }
+
+    #[inline]
+    fn yzy_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.z, self.y)
+    }
+
+    #[inline]
+    fn yzz_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.z, self.z)
+    }
+
+    #[inline]
+    fn yzw_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.z, self.w)
+    }
+
+    #[inline]
+    fn ywx_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.w, self.x)
+    }
+
+    #[inline]
+    fn ywy_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.w, self.y)
+    }
+
+    #[inline]
+    fn ywz_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.w, self.z)
+    }
+
+    #[inline]
+    fn yww_a(self) -> Vec3A {
+        Vec3A::new(self.y, self.w, self.w)
+    }
+
+    #[inline]
+    fn zxx_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.x, self.x)
+    }
+
+    #[inline]
+    fn zxy_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.x, self.y)
+    }
+
+    #[inline]
+    fn zxz_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.x, self.z)
+    }
+
+    #[inline]
+    fn zxw_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.x, self.w)
+    }
+
+    #[inline]
+    fn zyx_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.y, self.x)
+    }
+
+    #[inline]
+    fn zyy_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.y, self.y)
+    }
+
+    #[inline]
+    fn zyz_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.y, self.z)
+    }
+
+    #[inline]
+    fn zyw_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.y, self.w)
+    }
+
+    #[inline]
+    fn zzx_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.z, self.x)
+    }
+
+    #[inline]
+    fn zzy_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.z, self.y)
+    }
+
+    #[inline]
+    fn zzz_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.z, self.z)
+    }
+
+    #[inline]
+    fn zzw_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.z, self.w)
+    }
+
+    #[inline]
+    fn zwx_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.w, self.x)
+    }
+
+    #[inline]
+    fn zwy_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.w, self.y)
+    }
+
+    #[inline]
+    fn zwz_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.w, self.z)
+    }
+
+    #[inline]
+    fn zww_a(self) -> Vec3A {
+        Vec3A::new(self.z, self.w, self.w)
+    }
+
+    #[inline]
+    fn wxx_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.x, self.x)
+    }
+
+    #[inline]
+    fn wxy_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.x, self.y)
+    }
+
+    #[inline]
+    fn wxz_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.x, self.z)
+    }
+
+    #[inline]
+    fn wxw_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.x, self.w)
+    }
+
+    #[inline]
+    fn wyx_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.y, self.x)
+    }
+
+    #[inline]
+    fn wyy_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.y, self.y)
+    }
+
+    #[inline]
+    fn wyz_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.y, self.z)
+    }
+
+    #[inline]
+    fn wyw_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.y, self.w)
+    }
+
+    #[inline]
+    fn wzx_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.z, self.x)
+    }
+
+    #[inline]
+    fn wzy_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.z, self.y)
+    }
+
+    #[inline]
+    fn wzz_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.z, self.z)
+    }
+
+    #[inline]
+    fn wzw_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.z, self.w)
+    }
+
+    #[inline]
+    fn wwx_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.w, self.x)
+    }
+
+    #[inline]
+    fn wwy_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.w, self.y)
+    }
+
+    #[inline]
+    fn wwz_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.w, self.z)
+    }
+
+    #[inline]
+    fn www_a(self) -> Vec3A {
+        Vec3A::new(self.w, self.w, self.w)
+    }
+}
@@ -2,7 +2,7 @@
 
 #![allow(clippy::useless_conversion)]
 
-use crate::{Vec2, Vec3, Vec4, Vec4Swizzles};
+use crate::{Vec2, Vec3, Vec3A, Vec4, Vec4Swizzles, Vec4ToVec3ASwizzles};
 
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
@@ -1998,3 +1998,325 @@ impl Vec4Swizzles for Vec4 {
         Vec4(unsafe { _mm_shuffle_ps(self.0, self.0, 0b11_11_11_11) })
     }
 }
+
+impl Vec4ToVec3ASwizzles for Vec4 {
+    #[inline]
+    fn xxx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_00_00) })
+    }
+
+    #[inline]
+    fn xxy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_00_00) })
+    }
+
+    #[inline]
+    fn xxz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_00_00) })
+    }
+
+    #[inline]
+    fn xxw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_00_00) })
+    }
+
+    #[inline]
+    fn xyx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_01_00) })
+    }
+
+    #[inline]
+    fn xyy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_01_00) })
+    }
+
+    #[inline]
+    fn xyz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_01_00) })
+    }
+
+    #[inline]
+    fn xyw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_01_00) })
+    }
+
+    #[inline]
+    fn xzx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_10_00) })
+    }
+
+    #[inline]
+    fn xzy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_10_00) })
+    }
+
+    #[inline]
+    fn xzz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_10_00) })
+    }
+
+    #[inline]
+    fn xzw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_10_00) })
+    }
+
+    #[inline]
+    fn xwx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_11_00) })
+    }
+
+    #[inline]
+    fn xwy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_11_00) })
+    }
+
+    #[inline]
+    fn xwz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_11_00) })
+    }
+
+    #[inline]
+    fn xww_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_11_00) })
+    }
+
+    #[inline]
+    fn yxx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_00_01) })
+    }
+
+    #[inline]
+    fn yxy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_00_01) })
+    }
+
+    #[inline]
+    fn yxz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_00_01) })
+    }
+
+    #[inline]
+    fn yxw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_00_01) })
+    }
+
+    #[inline]
+    fn yyx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_01_01) })
+    }
+
+    #[inline]
+    fn yyy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_01_01) })
+    }
+
+    #[inline]
+    fn yyz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_01_01) })
+    }
+
+    #[inline]
+    fn yyw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_01_01) })
+    }
+
+    #[inline]
+    fn yzx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_10_01) })
+    }
+
+    #[inline]
+    fn yzy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_10_01) })
+    }
+
+    #[inline]
+    fn yzz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_10_01) })
+    }
+
+    #[inline]
+    fn yzw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_10_01) })
+    }
+
+    #[inline]
+    fn ywx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_11_01) })
+    }
+
+    #[inline]
+    fn ywy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_11_01) })
+    }
+
+    #[inline]
+    fn ywz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_11_01) })
+    }
+
+    #[inline]
+    fn yww_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_11_01) })
+    }
+
+    #[inline]
+    fn zxx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_00_10) })
+    }
+
+    #[inline]
+    fn zxy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_00_10) })
+    }
+
+    #[inline]
+    fn zxz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_00_10) })
+    }
+
+    #[inline]
+    fn zxw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_00_10) })
+    }
+
+    #[inline]
+    fn zyx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_01_10) })
+    }
+
+    #[inline]
+    fn zyy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_01_10) })
+    }
+
+    #[inline]
+    fn zyz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_01_10) })
+    }
+
+    #[inline]
+    fn zyw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_01_10) })
+    }
+
+    #[inline]
+    fn zzx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_10_10) })
+    }
+
+    #[inline]
+    fn zzy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_10_10) })
+    }
+
+    #[inline]
+    fn zzz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_10_10) })
+    }
+
+    #[inline]
+    fn zzw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_10_10) })
+    }
+
+    #[inline]
+    fn zwx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_11_10) })
+    }
+
+    #[inline]
+    fn zwy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_11_10) })
+    }
+
+    #[inline]
+    fn zwz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_11_10) })
+    }
+
+    #[inline]
+    fn zww_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_11_10) })
+    }
+
+    #[inline]
+    fn wxx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_00_11) })
+    }
+
+    #[inline]
+    fn wxy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_00_11) })
+    }
+
+    #[inline]
+    fn wxz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_00_11) })
+    }
+
+    #[inline]
+    fn wxw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_00_11) })
+    }
+
+    #[inline]
+    fn wyx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_01_11) })
+    }
+
+    #[inline]
+    fn wyy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_01_11) })
+    }
+
+    #[inline]
+    fn wyz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_01_11) })
+    }
+
+    #[inline]
+    fn wyw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_01_11) })
+    }
+
+    #[inline]
+    fn wzx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_10_11) })
+    }
+
+    #[inline]
+    fn wzy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_10_11) })
+    }
+
+    #[inline]
+    fn wzz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_10_11) })
+    }
+
+    #[inline]
+    fn wzw_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_10_11) })
+    }
+
+    #[inline]
+    fn wwx_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_11_11) })
+    }
+
+    #[inline]
+    fn wwy_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_11_11) })
+    }
+
+    #[inline]
+    fn wwz_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_10_11_11) })
+    }
+
+    #[inline]
+    fn www_a(self) -> Vec3A {
+        Vec3A(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_11_11_11) })
+    }
+}
@@ -988,3 +988,138 @@ pub trait Vec4Swizzles: Sized + Copy + Clone {
 
     fn wwww(self) -> Self;
 }
+
+/// 3 element swizzles of a [`Vec4`](crate::Vec4) that return a [`Vec3A`](crate::Vec3A)
+/// directly.
+///
+/// The `_a` suffix distinguishes these from the [`Vec4Swizzles`] methods which return a
+/// [`Vec3`](crate::Vec3). When SIMD is supported these are a single vector shuffle.
+pub trait Vec4ToVec3ASwizzles: Sized + Copy + Clone {
+    fn xxx_a(self) -> crate::Vec3A;
+
+    fn xxy_a(self) -> crate::Vec3A;
+
+    fn xxz_a(self) -> crate::Vec3A;
+
+    fn xxw_a(self) -> crate::Vec3A;
+
+    fn xyx_a(self) -> crate::Vec3A;
+
+    fn xyy_a(self) -> crate::Vec3A;
+
+    fn xyz_a(self) -> crate::Vec3A;
+
+    fn xyw_a(self) -> crate::Vec3A;
+
+    fn xzx_a(self) -> crate::Vec3A;
+
+    fn xzy_a(self) -> crate::Vec3A;
+
+    fn xzz_a(self) -> crate::Vec3A;
+
+    fn xzw_a(self) -> crate::Vec3A;
+
+    fn xwx_a(self) -> crate::Vec3A;
+
+    fn xwy_a(self) -> crate::Vec3A;
+
+    fn xwz_a(self) -> crate::Vec3A;
+
+    fn xww_a(self) -> crate::Vec3A;
+
+    fn yxx_a(self) -> crate::Vec3A;
+
+    fn yxy_a(self) -> crate::Vec3A;
+
+    fn yxz_a(self) -> crate::Vec3A;
+
+    fn yxw_a(self) -> crate::Vec3A;
+
+    fn yyx_a(self) -> crate::Vec3A;
+
+    fn yyy_a(self) -> crate::Vec3A;
+
+    fn yyz_a(self) -> crate::Vec3A;
+
+    fn yyw_a(self) -> crate::Vec3A;
+
+    fn yzx_a(self) -> crate::Vec3A;
+
+    fn yzy_a(self) -> crate::Vec3A;
+
+    fn yzz_a(self) -> crate::Vec3A;
+
+    fn yzw_a(self) -> crate::Vec3A;
+
+    fn ywx_a(self) -> crate::Vec3A;
+
+    fn ywy_a(self) -> crate::Vec3A;
+
+    fn ywz_a(self) -> crate::Vec3A;
+
+    fn yww_a(self) -> crate::Vec3A;
+
+    fn zxx_a(self) -> crate::Vec3A;
+
+    fn zxy_a(self) -> crate::Vec3A;
+
+    fn zxz_a(self) -> crate::Vec3A;
+
+    fn zxw_a(self) -> crate::Vec3A;
+
+    fn zyx_a(self) -> crate::Vec3A;
+
+    fn zyy_a(self) -> crate::Vec3A;
+
+    fn zyz_a(self) -> crate::Vec3A;
+
+    fn zyw_a(self) -> crate::Vec3A;
+
+    fn zzx_a(self) -> crate::Vec3A;
+
+    fn zzy_a(self) -> crate::Vec3A;
+
+    fn zzz_a(self) -> crate::Vec3A;
+
+    fn zzw_a(self) -> crate::Vec3A;
+
+    fn zwx_a(self) -> crate::Vec3A;
+
+    fn zwy_a(self) -> crate::Vec3A;
+
+    fn zwz_a(self) -> crate::Vec3A;
+
+    fn zww_a(self) -> crate::Vec3A;
+
+    fn wxx_a(self) -> crate::Vec3A;
+
+    fn wxy_a(self) -> crate::Vec3A;
+
+    fn wxz_a(self) -> crate::Vec3A;
+
+    fn wxw_a(self) -> crate::Vec3A;
+
+    fn wyx_a(self) -> crate::Vec3A;
+
+    fn wyy_a(self) -> crate::Vec3A;
+
+    fn wyz_a(self) -> crate::Vec3A;
+
+    fn wyw_a(self) -> crate::Vec3A;
+
+    fn wzx_a(self) -> crate::Vec3A;
+
+    fn wzy_a(self) -> crate::Vec3A;
+
+    fn wzz_a(self) -> crate::Vec3A;
+
+    fn wzw_a(self) -> crate::Vec3A;
+
+    fn wwx_a(self) -> crate::Vec3A;
+
+    fn wwy_a(self) -> crate::Vec3A;
+
+    fn wwz_a(self) -> crate::Vec3A;
+
+    fn www_a(self) -> crate::Vec3A;
+}
@@ -2,7 +2,7 @@
 
 #![allow(clippy::useless_conversion)]
 
-use crate::{Vec2, Vec3, Vec4, Vec4Swizzles};
+use crate::{Vec2, Vec3, Vec3A, Vec4, Vec4Swizzles, Vec4ToVec3ASwizzles};
 
 use core::arch::wasm32::*;
 
@@ -1995,3 +1995,325 @@ impl Vec4Swizzles for Vec4 {
         Vec4(i32x4_shuffle::<3, 3, 7, 7>(self.0, self.0))
     }
 }
+
+impl Vec4ToVec3ASwizzles for Vec4 {
+    #[inline]
+    fn xxx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 0, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xxy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 0, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xxz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 0, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xxw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 0, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xyx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 1, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xyy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 1, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xyz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 1, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xyw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 1, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xzx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 2, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xzy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 2, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xzz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 2, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xzw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 2, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xwx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 3, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xwy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 3, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xwz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 3, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn xww_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<0, 3, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yxx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 0, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yxy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 0, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yxz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 0, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yxw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 0, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yyx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 1, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yyy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 1, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yyz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 1, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yyw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 1, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yzx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 2, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yzy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 2, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yzz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 2, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yzw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 2, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn ywx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 3, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn ywy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 3, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn ywz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 3, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn yww_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<1, 3, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zxx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 0, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zxy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 0, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zxz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 0, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zxw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 0, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zyx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 1, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zyy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 1, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zyz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 1, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zyw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 1, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zzx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 2, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zzy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 2, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zzz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 2, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zzw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 2, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zwx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 3, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zwy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 3, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zwz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 3, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn zww_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<2, 3, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wxx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 0, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wxy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 0, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wxz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 0, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wxw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 0, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wyx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 1, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wyy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 1, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wyz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 1, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wyw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 1, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wzx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 2, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wzy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 2, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wzz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 2, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wzw_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 2, 7, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wwx_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 3, 4, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wwy_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 3, 5, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn wwz_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 3, 6, 4>(self.0, self.0))
+    }
+
+    #[inline]
+    fn www_a(self) -> Vec3A {
+        Vec3A(i32x4_shuffle::<3, 3, 7, 4>(self.0, self.0))
+    }
+}
@@ -14,8 +14,17 @@ pub const fn uvec2(x: u32, y: u32) -> UVec2 {
 }
 
 /// A 2-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "cuda", repr(align(8)))]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
@@ -31,6 +40,12 @@ impl UVec2 {
     /// All ones.
     pub const ONE: Self = Self::splat(1);
 
+    /// All `u32::MIN`.
+    pub const MIN: Self = Self::splat(u32::MIN);
+
+    /// All `u32::MAX`.
+    pub const MAX: Self = Self::splat(u32::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0);
 
@@ -52,6 +67,15 @@ impl UVec2 {
         Self { x: v, y: v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(u32) -> u32,
+    {
+        Self::new(f(self.x), f(self.y))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -71,6 +95,17 @@ impl UVec2 {
         Self::new(a[0], a[1])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> u32,
+    {
+        Self::new(f(0), f(1))
+    }
+
     /// `[x, y]`
     #[inline]
     pub const fn to_array(&self) -> [u32; 2] {
@@ -167,6 +202,52 @@ impl UVec2 {
         self.x.max(self.y)
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> u32 {
+        self.x.wrapping_add(self.y)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> u32 {
+        self.x.wrapping_mul(self.y)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -234,6 +315,128 @@ impl UVec2 {
         self.dot(self)
     }
 
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: u32::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: u32::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     pub fn as_vec2(&self) -> crate::Vec2 {
@@ -263,6 +466,21 @@ impl UVec2 {
     pub fn as_u64vec2(&self) -> crate::U64Vec2 {
         crate::U64Vec2::new(self.x as u64, self.y as u64)
     }
+
+    /// Casts all elements of `self` to `i32`, saturating elements that are out of
+    /// range to `i32::MIN` or `i32::MAX`.
+    ///
+    /// Unlike [`Self::as_ivec2()`] this never wraps around. To check that all
+    /// elements are in range use [`IVec2::try_from()`] instead.
+    ///
+    /// [`IVec2::try_from()`]: crate::IVec2::try_from
+    #[inline]
+    pub fn saturating_as_ivec2(&self) -> crate::IVec2 {
+        crate::IVec2::new(
+            i32::try_from(self.x).unwrap_or(i32::MAX),
+            i32::try_from(self.y).unwrap_or(i32::MAX),
+        )
+    }
 }
 
 impl Default for UVec2 {
@@ -595,6 +813,14 @@ impl BitAnd for UVec2 {
     }
 }
 
+impl BitAndAssign for UVec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+    }
+}
+
 impl BitOr for UVec2 {
     type Output = Self;
     #[inline]
@@ -606,6 +832,14 @@ impl BitOr for UVec2 {
     }
 }
 
+impl BitOrAssign for UVec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+    }
+}
+
 impl BitXor for UVec2 {
     type Output = Self;
     #[inline]
@@ -617,6 +851,14 @@ impl BitXor for UVec2 {
     }
 }
 
+impl BitXorAssign for UVec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+    }
+}
+
 impl BitAnd<u32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -628,6 +870,14 @@ impl BitAnd<u32> for UVec2 {
     }
 }
 
+impl BitAndAssign<u32> for UVec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: u32) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<u32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -639,6 +889,14 @@ impl BitOr<u32> for UVec2 {
     }
 }
 
+impl BitOrAssign<u32> for UVec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: u32) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<u32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -650,6 +908,14 @@ impl BitXor<u32> for UVec2 {
     }
 }
 
+impl BitXorAssign<u32> for UVec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: u32) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for UVec2 {
     type Output = Self;
     #[inline]
@@ -661,6 +927,14 @@ impl Shl<i8> for UVec2 {
     }
 }
 
+impl ShlAssign<i8> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for UVec2 {
     type Output = Self;
     #[inline]
@@ -672,6 +946,14 @@ impl Shr<i8> for UVec2 {
     }
 }
 
+impl ShrAssign<i8> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for UVec2 {
     type Output = Self;
     #[inline]
@@ -683,6 +965,14 @@ impl Shl<i16> for UVec2 {
     }
 }
 
+impl ShlAssign<i16> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for UVec2 {
     type Output = Self;
     #[inline]
@@ -694,6 +984,14 @@ impl Shr<i16> for UVec2 {
     }
 }
 
+impl ShrAssign<i16> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -705,6 +1003,14 @@ impl Shl<i32> for UVec2 {
     }
 }
 
+impl ShlAssign<i32> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -716,6 +1022,14 @@ impl Shr<i32> for UVec2 {
     }
 }
 
+impl ShrAssign<i32> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for UVec2 {
     type Output = Self;
     #[inline]
@@ -727,6 +1041,14 @@ impl Shl<i64> for UVec2 {
     }
 }
 
+impl ShlAssign<i64> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for UVec2 {
     type Output = Self;
     #[inline]
@@ -738,6 +1060,14 @@ impl Shr<i64> for UVec2 {
     }
 }
 
+impl ShrAssign<i64> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for UVec2 {
     type Output = Self;
     #[inline]
@@ -749,6 +1079,14 @@ impl Shl<u8> for UVec2 {
     }
 }
 
+impl ShlAssign<u8> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for UVec2 {
     type Output = Self;
     #[inline]
@@ -760,6 +1098,14 @@ impl Shr<u8> for UVec2 {
     }
 }
 
+impl ShrAssign<u8> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for UVec2 {
     type Output = Self;
     #[inline]
@@ -771,6 +1117,14 @@ impl Shl<u16> for UVec2 {
     }
 }
 
+impl ShlAssign<u16> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for UVec2 {
     type Output = Self;
     #[inline]
@@ -782,6 +1136,14 @@ impl Shr<u16> for UVec2 {
     }
 }
 
+impl ShrAssign<u16> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -793,6 +1155,14 @@ impl Shl<u32> for UVec2 {
     }
 }
 
+impl ShlAssign<u32> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for UVec2 {
     type Output = Self;
     #[inline]
@@ -804,6 +1174,14 @@ impl Shr<u32> for UVec2 {
     }
 }
 
+impl ShrAssign<u32> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for UVec2 {
     type Output = Self;
     #[inline]
@@ -815,6 +1193,14 @@ impl Shl<u64> for UVec2 {
     }
 }
 
+impl ShlAssign<u64> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for UVec2 {
     type Output = Self;
     #[inline]
@@ -826,6 +1212,14 @@ impl Shr<u64> for UVec2 {
     }
 }
 
+impl ShrAssign<u64> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec2> for UVec2 {
     type Output = Self;
     #[inline]
@@ -837,6 +1231,14 @@ impl Shl<crate::IVec2> for UVec2 {
     }
 }
 
+impl ShlAssign<crate::IVec2> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::IVec2> for UVec2 {
     type Output = Self;
     #[inline]
@@ -848,6 +1250,14 @@ impl Shr<crate::IVec2> for UVec2 {
     }
 }
 
+impl ShrAssign<crate::IVec2> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Shl<crate::UVec2> for UVec2 {
     type Output = Self;
     #[inline]
@@ -859,6 +1269,14 @@ impl Shl<crate::UVec2> for UVec2 {
     }
 }
 
+impl ShlAssign<crate::UVec2> for UVec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::UVec2> for UVec2 {
     type Output = Self;
     #[inline]
@@ -870,6 +1288,14 @@ impl Shr<crate::UVec2> for UVec2 {
     }
 }
 
+impl ShrAssign<crate::UVec2> for UVec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Index<usize> for UVec2 {
     type Output = u32;
     #[inline]
@@ -896,7 +1322,22 @@ impl IndexMut<usize> for UVec2 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for UVec2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}]", self.x, self.y)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for UVec2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -937,3 +1378,45 @@ impl From<UVec2> for (u32, u32) {
         (v.x, v.y)
     }
 }
+
+impl TryFrom<crate::IVec2> for UVec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::IVec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
+
+impl TryFrom<crate::I64Vec2> for UVec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::I64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
+
+impl TryFrom<crate::U64Vec2> for UVec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u32`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::U64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u32::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u32::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
@@ -14,8 +14,17 @@ pub const fn u64vec2(x: u64, y: u64) -> U64Vec2 {
 }
 
 /// A 2-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "cuda", repr(align(16)))]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
@@ -31,6 +40,12 @@ impl U64Vec2 {
     /// All ones.
     pub const ONE: Self = Self::splat(1);
 
+    /// All `u64::MIN`.
+    pub const MIN: Self = Self::splat(u64::MIN);
+
+    /// All `u64::MAX`.
+    pub const MAX: Self = Self::splat(u64::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0);
 
@@ -52,6 +67,15 @@ impl U64Vec2 {
         Self { x: v, y: v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(u64) -> u64,
+    {
+        Self::new(f(self.x), f(self.y))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -71,6 +95,17 @@ impl U64Vec2 {
         Self::new(a[0], a[1])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> u64,
+    {
+        Self::new(f(0), f(1))
+    }
+
     /// `[x, y]`
     #[inline]
     pub const fn to_array(&self) -> [u64; 2] {
@@ -167,6 +202,52 @@ impl U64Vec2 {
         self.x.max(self.y)
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            index = 1;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> u64 {
+        self.x.wrapping_add(self.y)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> u64 {
+        self.x.wrapping_mul(self.y)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -234,6 +315,128 @@ impl U64Vec2 {
         self.dot(self)
     }
 
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: u64::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: u64::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     pub fn as_vec2(&self) -> crate::Vec2 {
@@ -263,6 +466,51 @@ impl U64Vec2 {
     pub fn as_i64vec2(&self) -> crate::I64Vec2 {
         crate::I64Vec2::new(self.x as i64, self.y as i64)
     }
+
+    /// Casts all elements of `self` to `i32`, saturating elements that are out of
+    /// range to `i32::MIN` or `i32::MAX`.
+    ///
+    /// Unlike [`Self::as_ivec2()`] this never wraps around. To check that all
+    /// elements are in range use [`IVec2::try_from()`] instead.
+    ///
+    /// [`IVec2::try_from()`]: crate::IVec2::try_from
+    #[inline]
+    pub fn saturating_as_ivec2(&self) -> crate::IVec2 {
+        crate::IVec2::new(
+            i32::try_from(self.x).unwrap_or(i32::MAX),
+            i32::try_from(self.y).unwrap_or(i32::MAX),
+        )
+    }
+
+    /// Casts all elements of `self` to `u32`, saturating elements that are out of
+    /// range to `u32::MIN` or `u32::MAX`.
+    ///
+    /// Unlike [`Self::as_uvec2()`] this never wraps around. To check that all
+    /// elements are in range use [`UVec2::try_from()`] instead.
+    ///
+    /// [`UVec2::try_from()`]: crate::UVec2::try_from
+    #[inline]
+    pub fn saturating_as_uvec2(&self) -> crate::UVec2 {
+        crate::UVec2::new(
+            u32::try_from(self.x).unwrap_or(u32::MAX),
+            u32::try_from(self.y).unwrap_or(u32::MAX),
+        )
+    }
+
+    /// Casts all elements of `self` to `i64`, saturating elements that are out of
+    /// range to `i64::MIN` or `i64::MAX`.
+    ///
+    /// Unlike [`Self::as_i64vec2()`] this never wraps around. To check that all
+    /// elements are in range use [`I64Vec2::try_from()`] instead.
+    ///
+    /// [`I64Vec2::try_from()`]: crate::I64Vec2::try_from
+    #[inline]
+    pub fn saturating_as_i64vec2(&self) -> crate::I64Vec2 {
+        crate::I64Vec2::new(
+            i64::try_from(self.x).unwrap_or(i64::MAX),
+            i64::try_from(self.y).unwrap_or(i64::MAX),
+        )
+    }
 }
 
 impl Default for U64Vec2 {
@@ -595,6 +843,14 @@ impl BitAnd for U64Vec2 {
     }
 }
 
+impl BitAndAssign for U64Vec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+    }
+}
+
 impl BitOr for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -606,6 +862,14 @@ impl BitOr for U64Vec2 {
     }
 }
 
+impl BitOrAssign for U64Vec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+    }
+}
+
 impl BitXor for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -617,6 +881,14 @@ impl BitXor for U64Vec2 {
     }
 }
 
+impl BitXorAssign for U64Vec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+    }
+}
+
 impl BitAnd<u64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -628,6 +900,14 @@ impl BitAnd<u64> for U64Vec2 {
     }
 }
 
+impl BitAndAssign<u64> for U64Vec2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: u64) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<u64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -639,6 +919,14 @@ impl BitOr<u64> for U64Vec2 {
     }
 }
 
+impl BitOrAssign<u64> for U64Vec2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: u64) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<u64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -650,6 +938,14 @@ impl BitXor<u64> for U64Vec2 {
     }
 }
 
+impl BitXorAssign<u64> for U64Vec2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: u64) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -661,6 +957,14 @@ impl Shl<i8> for U64Vec2 {
     }
 }
 
+impl ShlAssign<i8> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -672,6 +976,14 @@ impl Shr<i8> for U64Vec2 {
     }
 }
 
+impl ShrAssign<i8> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -683,6 +995,14 @@ impl Shl<i16> for U64Vec2 {
     }
 }
 
+impl ShlAssign<i16> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -694,6 +1014,14 @@ impl Shr<i16> for U64Vec2 {
     }
 }
 
+impl ShrAssign<i16> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -705,6 +1033,14 @@ impl Shl<i32> for U64Vec2 {
     }
 }
 
+impl ShlAssign<i32> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -716,6 +1052,14 @@ impl Shr<i32> for U64Vec2 {
     }
 }
 
+impl ShrAssign<i32> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -727,6 +1071,14 @@ impl Shl<i64> for U64Vec2 {
     }
 }
 
+impl ShlAssign<i64> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -738,6 +1090,14 @@ impl Shr<i64> for U64Vec2 {
     }
 }
 
+impl ShrAssign<i64> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -749,6 +1109,14 @@ impl Shl<u8> for U64Vec2 {
     }
 }
 
+impl ShlAssign<u8> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -760,6 +1128,14 @@ impl Shr<u8> for U64Vec2 {
     }
 }
 
+impl ShrAssign<u8> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -771,6 +1147,14 @@ impl Shl<u16> for U64Vec2 {
     }
 }
 
+impl ShlAssign<u16> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -782,6 +1166,14 @@ impl Shr<u16> for U64Vec2 {
     }
 }
 
+impl ShrAssign<u16> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -793,6 +1185,14 @@ impl Shl<u32> for U64Vec2 {
     }
 }
 
+impl ShlAssign<u32> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -804,6 +1204,14 @@ impl Shr<u32> for U64Vec2 {
     }
 }
 
+impl ShrAssign<u32> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -815,6 +1223,14 @@ impl Shl<u64> for U64Vec2 {
     }
 }
 
+impl ShlAssign<u64> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -826,6 +1242,14 @@ impl Shr<u64> for U64Vec2 {
     }
 }
 
+impl ShrAssign<u64> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec2> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -837,6 +1261,14 @@ impl Shl<crate::IVec2> for U64Vec2 {
     }
 }
 
+impl ShlAssign<crate::IVec2> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::IVec2> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -848,6 +1280,14 @@ impl Shr<crate::IVec2> for U64Vec2 {
     }
 }
 
+impl ShrAssign<crate::IVec2> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Shl<crate::UVec2> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -859,6 +1299,14 @@ impl Shl<crate::UVec2> for U64Vec2 {
     }
 }
 
+impl ShlAssign<crate::UVec2> for U64Vec2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+    }
+}
+
 impl Shr<crate::UVec2> for U64Vec2 {
     type Output = Self;
     #[inline]
@@ -870,6 +1318,14 @@ impl Shr<crate::UVec2> for U64Vec2 {
     }
 }
 
+impl ShrAssign<crate::UVec2> for U64Vec2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec2) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+    }
+}
+
 impl Index<usize> for U64Vec2 {
     type Output = u64;
     #[inline]
@@ -896,7 +1352,22 @@ impl IndexMut<usize> for U64Vec2 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for U64Vec2 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}]", self.x, self.y)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for U64Vec2 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -937,3 +1408,38 @@ impl From<U64Vec2> for (u64, u64) {
         (v.x, v.y)
     }
 }
+
+impl TryFrom<crate::IVec2> for U64Vec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u64`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::IVec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u64::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u64::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
+
+impl From<crate::UVec2> for U64Vec2 {
+    #[inline]
+    fn from(v: crate::UVec2) -> Self {
+        Self::new(u64::from(v.x), u64::from(v.y))
+    }
+}
+
+impl TryFrom<crate::I64Vec2> for U64Vec2 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u64`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::I64Vec2) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u64::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u64::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+        ))
+    }
+}
@@ -14,8 +14,17 @@ pub const fn u64vec3(x: u64, y: u64, z: u64) -> U64Vec3 {
 }
 
 /// A 3-dimensional vector.
+///
+/// `PartialOrd` and `Ord` compare lexicographically, `x` first, then `y`, then `z`.
+/// This gives a total order suitable for sorting and ordered collections, use
+/// [`Self::cmplt()`] and friends for component-wise comparison.
+///
+/// The inherent [`Self::min()`], [`Self::max()`] and [`Self::clamp()`] methods are
+/// component-wise, while the `Ord` methods and functions built on them, such as
+/// `core::cmp::max`, `core::cmp::min` and `Iterator::max`, are lexicographic. For example
+/// `core::cmp::max(a, b)` returns either `a` or `b`, while `a.max(b)` may mix their elements.
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 #[cfg_attr(target_arch = "spirv", repr(simd))]
 pub struct U64Vec3 {
@@ -31,6 +40,12 @@ impl U64Vec3 {
     /// All ones.
     pub const ONE: Self = Self::splat(1);
 
+    /// All `u64::MIN`.
+    pub const MIN: Self = Self::splat(u64::MIN);
+
+    /// All `u64::MAX`.
+    pub const MAX: Self = Self::splat(u64::MAX);
+
     /// A unit-length vector pointing along the positive X axis.
     pub const X: Self = Self::new(1, 0, 0);
 
@@ -55,6 +70,15 @@ impl U64Vec3 {
         Self { x: v, y: v, z: v }
     }
 
+    /// Returns a vector containing each element of `self` modified by a mapping function `f`.
+    #[inline]
+    pub fn map<F>(self, f: F) -> Self
+    where
+        F: Fn(u64) -> u64,
+    {
+        Self::new(f(self.x), f(self.y), f(self.z))
+    }
+
     /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
     /// for each element of `self`.
     ///
@@ -75,6 +99,17 @@ impl U64Vec3 {
         Self::new(a[0], a[1], a[2])
     }
 
+    /// Creates a new vector where each element is the result of calling `f` with its index.
+    ///
+    /// In other words, this computes `[f(0), f(1), ..]`.
+    #[inline]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(usize) -> u64,
+    {
+        Self::new(f(0), f(1), f(2))
+    }
+
     /// `[x, y, z]`
     #[inline]
     pub const fn to_array(&self) -> [u64; 3] {
@@ -204,6 +239,60 @@ impl U64Vec3 {
         self.x.max(self.y.max(self.z))
     }
 
+    /// Returns the index of the minimum element of `self`.
+    ///
+    /// If several elements are equal to the minimum the lowest index is returned.
+    #[inline]
+    pub fn min_position(self) -> usize {
+        let mut min = self.x;
+        let mut index = 0;
+        if self.y < min {
+            min = self.y;
+            index = 1;
+        }
+        if self.z < min {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the index of the maximum element of `self`.
+    ///
+    /// If several elements are equal to the maximum the lowest index is returned.
+    #[inline]
+    pub fn max_position(self) -> usize {
+        let mut max = self.x;
+        let mut index = 0;
+        if self.y > max {
+            max = self.y;
+            index = 1;
+        }
+        if self.z > max {
+            index = 2;
+        }
+        index
+    }
+
+    /// Returns the sum of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x + self.y + ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_sum(self) -> u64 {
+        self.x.wrapping_add(self.y).wrapping_add(self.z)
+    }
+
+    /// Returns the product of all elements of `self`.
+    ///
+    /// In other words, this computes `self.x * self.y * ..`.
+    ///
+    /// This wraps on overflow.
+    #[inline]
+    pub fn element_product(self) -> u64 {
+        self.x.wrapping_mul(self.y).wrapping_mul(self.z)
+    }
+
     /// Returns a vector mask containing the result of a `==` comparison for each element of
     /// `self` and `rhs`.
     ///
@@ -271,6 +360,137 @@ impl U64Vec3 {
         self.dot(self)
     }
 
+    /// Calculates the midpoint between `self` and `rhs`, rounding each element towards negative
+    /// infinity.
+    ///
+    /// Unlike `(self + rhs) / 2` this will never overflow.
+    #[must_use]
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        // the shared bits plus half of the differing bits, the shift rounds towards negative
+        // infinity for both signed and unsigned types
+        Self {
+            x: (self.x & rhs.x) + ((self.x ^ rhs.x) >> 1),
+            y: (self.y & rhs.y) + ((self.y ^ rhs.y) >> 1),
+            z: (self.z & rhs.z) + ((self.z ^ rhs.z) >> 1),
+        }
+    }
+
+    /// Returns a vector containing the wrapping addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_add(rhs.x),
+            y: self.y.wrapping_add(rhs.y),
+            z: self.z.wrapping_add(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the wrapping subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.wrapping_sub(rhs.x),
+            y: self.y.wrapping_sub(rhs.y),
+            z: self.z.wrapping_sub(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating addition of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_add(rhs.x),
+            y: self.y.saturating_add(rhs.y),
+            z: self.z.saturating_add(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the saturating subtraction of `self` and `rhs`.
+    ///
+    /// In other words this computes `[self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y), ..]`.
+    #[must_use]
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.saturating_sub(rhs.x),
+            y: self.y.saturating_sub(rhs.y),
+            z: self.z.saturating_sub(rhs.z),
+        }
+    }
+
+    /// Returns a vector containing the checked addition of `self` and `rhs`.
+    ///
+    /// Returns `None` if the addition of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+            z: self.z.checked_add(rhs.z)?,
+        })
+    }
+
+    /// Returns a vector containing the checked subtraction of `self` and `rhs`.
+    ///
+    /// Returns `None` if the subtraction of any element overflows.
+    #[must_use]
+    #[inline]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+            z: self.z.checked_sub(rhs.z)?,
+        })
+    }
+
+    /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: u64::div_euclid
+    #[must_use]
+    #[inline]
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.div_euclid(rhs.x),
+            y: self.y.div_euclid(rhs.y),
+            z: self.z.div_euclid(rhs.z),
+        }
+    }
+
+    /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+    ///
+    /// The result is in the range `[0, rhs.abs())` for each element. This differs from the `%`
+    /// operator, which truncates the quotient so the result has the same sign as `self`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `rhs` element is 0 or the division results in overflow.
+    ///
+    /// [Euclidean division]: u64::rem_euclid
+    #[must_use]
+    #[inline]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(rhs.x),
+            y: self.y.rem_euclid(rhs.y),
+            z: self.z.rem_euclid(rhs.z),
+        }
+    }
+
     /// Casts all elements of `self` to `f32`.
     #[inline]
     pub fn as_vec3(&self) -> crate::Vec3 {
@@ -306,6 +526,54 @@ impl U64Vec3 {
     pub fn as_i64vec3(&self) -> crate::I64Vec3 {
         crate::I64Vec3::new(self.x as i64, self.y as i64, self.z as i64)
     }
+
+    /// Casts all elements of `self` to `i32`, saturating elements that are out of
+    /// range to `i32::MIN` or `i32::MAX`.
+    ///
+    /// Unlike [`Self::as_ivec3()`] this never wraps around. To check that all
+    /// elements are in range use [`IVec3::try_from()`] instead.
+    ///
+    /// [`IVec3::try_from()`]: crate::IVec3::try_from
+    #[inline]
+    pub fn saturating_as_ivec3(&self) -> crate::IVec3 {
+        crate::IVec3::new(
+            i32::try_from(self.x).unwrap_or(i32::MAX),
+            i32::try_from(self.y).unwrap_or(i32::MAX),
+            i32::try_from(self.z).unwrap_or(i32::MAX),
+        )
+    }
+
+    /// Casts all elements of `self` to `u32`, saturating elements that are out of
+    /// range to `u32::MIN` or `u32::MAX`.
+    ///
+    /// Unlike [`Self::as_uvec3()`] this never wraps around. To check that all
+    /// elements are in range use [`UVec3::try_from()`] instead.
+    ///
+    /// [`UVec3::try_from()`]: crate::UVec3::try_from
+    #[inline]
+    pub fn saturating_as_uvec3(&self) -> crate::UVec3 {
+        crate::UVec3::new(
+            u32::try_from(self.x).unwrap_or(u32::MAX),
+            u32::try_from(self.y).unwrap_or(u32::MAX),
+            u32::try_from(self.z).unwrap_or(u32::MAX),
+        )
+    }
+
+    /// Casts all elements of `self` to `i64`, saturating elements that are out of
+    /// range to `i64::MIN` or `i64::MAX`.
+    ///
+    /// Unlike [`Self::as_i64vec3()`] this never wraps around. To check that all
+    /// elements are in range use [`I64Vec3::try_from()`] instead.
+    ///
+    /// [`I64Vec3::try_from()`]: crate::I64Vec3::try_from
+    #[inline]
+    pub fn saturating_as_i64vec3(&self) -> crate::I64Vec3 {
+        crate::I64Vec3::new(
+            i64::try_from(self.x).unwrap_or(i64::MAX),
+            i64::try_from(self.y).unwrap_or(i64::MAX),
+            i64::try_from(self.z).unwrap_or(i64::MAX),
+        )
+    }
 }
 
 impl Default for U64Vec3 {
@@ -665,6 +933,15 @@ impl BitAnd for U64Vec3 {
     }
 }
 
+impl BitAndAssign for U64Vec3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.x.bitand_assign(rhs.x);
+        self.y.bitand_assign(rhs.y);
+        self.z.bitand_assign(rhs.z);
+    }
+}
+
 impl BitOr for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -677,6 +954,15 @@ impl BitOr for U64Vec3 {
     }
 }
 
+impl BitOrAssign for U64Vec3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.x.bitor_assign(rhs.x);
+        self.y.bitor_assign(rhs.y);
+        self.z.bitor_assign(rhs.z);
+    }
+}
+
 impl BitXor for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -689,6 +975,15 @@ impl BitXor for U64Vec3 {
     }
 }
 
+impl BitXorAssign for U64Vec3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.x.bitxor_assign(rhs.x);
+        self.y.bitxor_assign(rhs.y);
+        self.z.bitxor_assign(rhs.z);
+    }
+}
+
 impl BitAnd<u64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -701,6 +996,15 @@ impl BitAnd<u64> for U64Vec3 {
     }
 }
 
+impl BitAndAssign<u64> for U64Vec3 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: u64) {
+        self.x.bitand_assign(rhs);
+        self.y.bitand_assign(rhs);
+        self.z.bitand_assign(rhs);
+    }
+}
+
 impl BitOr<u64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -713,6 +1017,15 @@ impl BitOr<u64> for U64Vec3 {
     }
 }
 
+impl BitOrAssign<u64> for U64Vec3 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: u64) {
+        self.x.bitor_assign(rhs);
+        self.y.bitor_assign(rhs);
+        self.z.bitor_assign(rhs);
+    }
+}
+
 impl BitXor<u64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -725,6 +1038,15 @@ impl BitXor<u64> for U64Vec3 {
     }
 }
 
+impl BitXorAssign<u64> for U64Vec3 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: u64) {
+        self.x.bitxor_assign(rhs);
+        self.y.bitxor_assign(rhs);
+        self.z.bitxor_assign(rhs);
+    }
+}
+
 impl Shl<i8> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -737,6 +1059,15 @@ impl Shl<i8> for U64Vec3 {
     }
 }
 
+impl ShlAssign<i8> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i8> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -749,6 +1080,15 @@ impl Shr<i8> for U64Vec3 {
     }
 }
 
+impl ShrAssign<i8> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<i16> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -761,6 +1101,15 @@ impl Shl<i16> for U64Vec3 {
     }
 }
 
+impl ShlAssign<i16> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i16> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -773,6 +1122,15 @@ impl Shr<i16> for U64Vec3 {
     }
 }
 
+impl ShrAssign<i16> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<i32> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -785,6 +1143,15 @@ impl Shl<i32> for U64Vec3 {
     }
 }
 
+impl ShlAssign<i32> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i32> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -797,6 +1164,15 @@ impl Shr<i32> for U64Vec3 {
     }
 }
 
+impl ShrAssign<i32> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<i64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -809,6 +1185,15 @@ impl Shl<i64> for U64Vec3 {
     }
 }
 
+impl ShlAssign<i64> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<i64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -821,6 +1206,15 @@ impl Shr<i64> for U64Vec3 {
     }
 }
 
+impl ShrAssign<i64> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u8> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -833,6 +1227,15 @@ impl Shl<u8> for U64Vec3 {
     }
 }
 
+impl ShlAssign<u8> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u8) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u8> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -845,6 +1248,15 @@ impl Shr<u8> for U64Vec3 {
     }
 }
 
+impl ShrAssign<u8> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u8) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u16> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -857,6 +1269,15 @@ impl Shl<u16> for U64Vec3 {
     }
 }
 
+impl ShlAssign<u16> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u16) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u16> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -869,6 +1290,15 @@ impl Shr<u16> for U64Vec3 {
     }
 }
 
+impl ShrAssign<u16> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u16) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u32> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -881,6 +1311,15 @@ impl Shl<u32> for U64Vec3 {
     }
 }
 
+impl ShlAssign<u32> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u32> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -893,6 +1332,15 @@ impl Shr<u32> for U64Vec3 {
     }
 }
 
+impl ShrAssign<u32> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<u64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -905,6 +1353,15 @@ impl Shl<u64> for U64Vec3 {
     }
 }
 
+impl ShlAssign<u64> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64) {
+        self.x.shl_assign(rhs);
+        self.y.shl_assign(rhs);
+        self.z.shl_assign(rhs);
+    }
+}
+
 impl Shr<u64> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -917,6 +1374,15 @@ impl Shr<u64> for U64Vec3 {
     }
 }
 
+impl ShrAssign<u64> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64) {
+        self.x.shr_assign(rhs);
+        self.y.shr_assign(rhs);
+        self.z.shr_assign(rhs);
+    }
+}
+
 impl Shl<crate::IVec3> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -929,6 +1395,15 @@ impl Shl<crate::IVec3> for U64Vec3 {
     }
 }
 
+impl ShlAssign<crate::IVec3> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::IVec3) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+        self.z.shl_assign(rhs.z);
+    }
+}
+
 impl Shr<crate::IVec3> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -941,6 +1416,15 @@ impl Shr<crate::IVec3> for U64Vec3 {
     }
 }
 
+impl ShrAssign<crate::IVec3> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::IVec3) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+        self.z.shr_assign(rhs.z);
+    }
+}
+
 impl Shl<crate::UVec3> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -953,6 +1437,15 @@ impl Shl<crate::UVec3> for U64Vec3 {
     }
 }
 
+impl ShlAssign<crate::UVec3> for U64Vec3 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: crate::UVec3) {
+        self.x.shl_assign(rhs.x);
+        self.y.shl_assign(rhs.y);
+        self.z.shl_assign(rhs.z);
+    }
+}
+
 impl Shr<crate::UVec3> for U64Vec3 {
     type Output = Self;
     #[inline]
@@ -965,6 +1458,15 @@ impl Shr<crate::UVec3> for U64Vec3 {
     }
 }
 
+impl ShrAssign<crate::UVec3> for U64Vec3 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: crate::UVec3) {
+        self.x.shr_assign(rhs.x);
+        self.y.shr_assign(rhs.y);
+        self.z.shr_assign(rhs.z);
+    }
+}
+
 impl Index<usize> for U64Vec3 {
     type Output = u64;
     #[inline]
@@ -993,7 +1495,24 @@ impl IndexMut<usize> for U64Vec3 {
 #[cfg(not(target_arch = "spirv"))]
 impl fmt::Display for U64Vec3 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+        // pass the formatter on to each element so flags such as precision and width apply to
+        // every element
+        f.write_str("[")?;
+        fmt::Display::fmt(&self.x, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.y, f)?;
+        f.write_str(", ")?;
+        fmt::Display::fmt(&self.z, f)?;
+        f.write_str("]")
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::str::FromStr for U64Vec3 {
+    type Err = crate::ParseError;
+    /// Parses the format produced by `Display`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse::parse_array(s).map(Self::from_array)
     }
 }
 
@@ -1042,3 +1561,40 @@ impl From<(U64Vec2, u64)> for U64Vec3 {
         Self::new(v.x, v.y, z)
     }
 }
+
+impl TryFrom<crate::IVec3> for U64Vec3 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u64`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::IVec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u64::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u64::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+            u64::try_from(v.z).map_err(|_| crate::TryFromIntVecError::new(2))?,
+        ))
+    }
+}
+
+impl From<crate::UVec3> for U64Vec3 {
+    #[inline]
+    fn from(v: crate::UVec3) -> Self {
+        Self::new(u64::from(v.x), u64::from(v.y), u64::from(v.z))
+    }
+}
+
+impl TryFrom<crate::I64Vec3> for U64Vec3 {
+    type Error = crate::TryFromIntVecError;
+
+    /// Converts each element of `v` to `u64`, failing with the index of the first
+    /// element that is out of range.
+    #[inline]
+    fn try_from(v: crate::I64Vec3) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            u64::try_from(v.x).map_err(|_| crate::TryFromIntVecError::new(0))?,
+            u64::try_from(v.y).map_err(|_| crate::TryFromIntVecError::new(1))?,
+            u64::try_from(v.z).map_err(|_| crate::TryFromIntVecError::new(2))?,
+        ))
+    }
+}
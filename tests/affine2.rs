@@ -41,6 +41,13 @@ macro_rules! impl_affine2_tests {
             assert_eq!(MATRIX2D, a.to_cols_array_2d());
         });
 
+        glam_test!(test_affine2_const, {
+            const A1: $affine2 = $affine2::from_cols_array(&MATRIX1D);
+            const A2: $affine2 = $affine2::from_cols_array_2d(&MATRIX2D);
+            assert_eq!(MATRIX1D, A1.to_cols_array());
+            assert_eq!(MATRIX2D, A2.to_cols_array_2d());
+        });
+
         glam_test!(test_affine2_deref, {
             let a = $affine2::from_cols_array_2d(&MATRIX2D);
             assert_eq!(MATRIX2D[0], a.x_axis.to_array());
@@ -71,6 +78,29 @@ macro_rules! impl_affine2_tests {
             assert_eq!(MATRIX2D, a.to_cols_array_2d());
 
             assert_eq!(m, $mat3::from(a));
+            assert_eq!(a, $affine2::from(m));
+        });
+
+        glam_test!(test_affine2_mat3_round_trip, {
+            let a = $affine2::from_scale_angle_translation(
+                $vec2::new(0.5, 1.5),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+            assert_eq!(a, $affine2::from($mat3::from(a)));
+
+            let b = $affine2::from_scale_angle_translation(
+                $vec2::new(2.0, -3.0),
+                deg(-45.0),
+                $vec2::new(-4.0, 5.0),
+            );
+            let m = $mat3::from(a) * $mat3::from(b);
+            assert_approx_eq!(a * b, $affine2::from(m), 1.0e-6);
+            assert_approx_eq!($mat3::from(a.inverse()), $mat3::from(a).inverse(), 1.0e-6);
+
+            let p = $vec2::new(3.0, -7.0);
+            assert_approx_eq!(m.transform_point2(p), (a * b).transform_point2(p), 1.0e-5);
+            assert_approx_eq!(m.transform_vector2(p), (a * b).transform_vector2(p), 1.0e-5);
         });
 
         glam_test!(test_affine2_translation, {
@@ -130,6 +160,15 @@ macro_rules! impl_affine2_tests {
             assert_approx_eq!($affine2::IDENTITY, scale * scale_inv);
             assert_approx_eq!($affine2::IDENTITY, scale_inv * scale);
 
+            let m = $affine2::from_scale_angle_translation(
+                $vec2::new(0.5, 1.5),
+                deg(30.0),
+                $vec2::new(1.0, 2.0),
+            );
+            let m_inv = m.inverse();
+            assert_approx_eq!($affine2::IDENTITY, m * m_inv, 1.0e-6);
+            assert_approx_eq!($affine2::IDENTITY, m_inv * m, 1.0e-6);
+
             let m = scale * rot * trans;
             let m_inv = m.inverse();
             assert_approx_eq!($affine2::IDENTITY, m * m_inv, 1.0e-5);
@@ -160,6 +199,16 @@ macro_rules! impl_affine2_tests {
         glam_test!(test_affine2_fmt, {
             let a = $affine2::from_cols_array_2d(&MATRIX2D);
             assert_eq!(format!("{}", a), "[[1, 2], [3, 4], [5, 6]]");
+            assert_eq!(format!("{:.1}", a), "[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]");
+        });
+
+        glam_test!(test_affine2_from_str, {
+            let a = $affine2::from_cols_array_2d(&MATRIX2D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                format!("{}", a.matrix2).parse::<$affine2>()
+            );
         });
 
         glam_test!(test_affine2_to_from_slice, {
@@ -235,6 +284,7 @@ mod affine2 {
         assert_eq!(MATRIX2D, a.to_cols_array_2d());
 
         assert_eq!(m, Mat3A::from(a));
+        assert_eq!(a, Affine2::from(m));
     });
 
     impl_affine2_tests!(f32, Affine2, Vec2, Mat2, Mat3);
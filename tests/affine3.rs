@@ -49,6 +49,13 @@ macro_rules! impl_affine3_tests {
             assert_eq!(MATRIX2D, a.to_cols_array_2d());
         });
 
+        glam_test!(test_affine3_const, {
+            const A1: $affine3 = $affine3::from_cols_array(&MATRIX1D);
+            const A2: $affine3 = $affine3::from_cols_array_2d(&MATRIX2D);
+            assert_eq!(MATRIX1D, A1.to_cols_array());
+            assert_eq!(MATRIX2D, A2.to_cols_array_2d());
+        });
+
         glam_test!(test_affine3_deref, {
             let a = $affine3::from_cols_array_2d(&MATRIX2D);
             assert_eq!(MATRIX2D[0], a.x_axis.to_array());
@@ -86,6 +93,7 @@ macro_rules! impl_affine3_tests {
             assert_eq!(MATRIX2D, a.to_cols_array_2d());
 
             assert_eq!(m, $mat4::from(a));
+            assert_eq!(a, $affine3::from(m));
         });
 
         glam_test!(test_affine3_translation, {
@@ -147,6 +155,38 @@ macro_rules! impl_affine3_tests {
             assert_approx_eq!($vec3::new(1.0, 2.0, 4.5), result3, 1.0e-6);
         });
 
+        glam_test!(test_affine3_mul_matches_mat4, {
+            let a = $affine3::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_x(deg(90.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let b = $affine3::from_scale_rotation_translation(
+                $vec3::new(3.0, 1.0, 0.25),
+                $quat::from_rotation_y(deg(30.0)),
+                $vec3::new(-4.0, 5.0, -6.0),
+            );
+            let ma = $mat4::from(a);
+            let mb = $mat4::from(b);
+
+            assert_approx_eq!(ma * mb, $mat4::from(a * b), 1.0e-5);
+            assert_approx_eq!(mb * ma, $mat4::from(b * a), 1.0e-5);
+            assert_approx_eq!(a * b, $affine3::from(ma * mb), 1.0e-5);
+            assert_approx_eq!(a.inverse(), $affine3::from(ma.inverse()), 1.0e-5);
+
+            let p = $vec3::new(1.0, -2.0, 3.0);
+            assert_approx_eq!(
+                (ma * mb).transform_point3(p),
+                (a * b).transform_point3(p),
+                1.0e-5
+            );
+            assert_approx_eq!(
+                (ma * mb).transform_vector3(p),
+                (a * b).transform_vector3(p),
+                1.0e-5
+            );
+        });
+
         glam_test!(test_from_scale, {
             let m = $affine3::from_scale($vec3::new(2.0, 4.0, 8.0));
             assert_approx_eq!(
@@ -305,6 +345,19 @@ macro_rules! impl_affine3_tests {
                 format!("{}", a),
                 "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
             );
+            assert_eq!(
+                format!("{:+.1}", a),
+                "[[+1.0, +2.0, +3.0], [+4.0, +5.0, +6.0], [+7.0, +8.0, +9.0], [+10.0, +11.0, +12.0]]"
+            );
+        });
+
+        glam_test!(test_affine3_from_str, {
+            let a = $affine3::from_cols_array_2d(&MATRIX2D);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                format!("{}", a.matrix3).parse::<$affine3>()
+            );
         });
 
         glam_test!(test_affine3_to_from_slice, {
@@ -83,6 +83,40 @@ macro_rules! impl_3axis_test {
     };
 }
 
+macro_rules! impl_3axis_lock_test {
+    ($name:ident, $t:ty, $quat:ident, $euler:path, $U:path, $V:path, $W:path, $vec:ident) => {
+        glam_test!($name, {
+            let euler = $euler;
+            for u in (-176..=176).step_by(44) {
+                for v in [-90, -89, 89, 90] {
+                    for w in (-176..=176).step_by(44) {
+                        let u1 = (u as $t).to_radians();
+                        let v1 = (v as $t).to_radians();
+                        let w1 = (w as $t).to_radians();
+
+                        let q1: $quat = ($quat::from_axis_angle($U, u1)
+                            * $quat::from_axis_angle($V, v1)
+                            * $quat::from_axis_angle($W, w1))
+                        .normalize();
+
+                        // At gimbal lock the individual angles can't be recovered but they must
+                        // still describe the same rotation. The tolerance is larger than above
+                        // as the second angle loses precision close to +/-90 degrees.
+                        let (u2, v2, w2) = q1.to_euler(euler);
+                        assert!(u2.is_finite() && v2.is_finite() && w2.is_finite());
+                        assert_approx_angle!(v1, v2, 1e-3 as $t);
+                        let q3 = $quat::from_euler(euler, u2, v2, w2).normalize();
+
+                        assert_approx_eq!(q1 * $vec::X, q3 * $vec::X, 1e-3);
+                        assert_approx_eq!(q1 * $vec::Y, q3 * $vec::Y, 1e-3);
+                        assert_approx_eq!(q1 * $vec::Z, q3 * $vec::Z, 1e-3);
+                    }
+                }
+            }
+        });
+    };
+}
+
 macro_rules! impl_all_quat_tests_three_axis {
     ($t:ty, $q:ident, $v:ident) => {
         impl_3axis_test!(test_euler_zyx, $t, $q, ER::ZYX, $v::Z, $v::Y, $v::X, $v);
@@ -91,6 +125,13 @@ macro_rules! impl_all_quat_tests_three_axis {
         impl_3axis_test!(test_euler_yzx, $t, $q, ER::YZX, $v::Y, $v::Z, $v::X, $v);
         impl_3axis_test!(test_euler_xyz, $t, $q, ER::XYZ, $v::X, $v::Y, $v::Z, $v);
         impl_3axis_test!(test_euler_xzy, $t, $q, ER::XZY, $v::X, $v::Z, $v::Y, $v);
+
+        impl_3axis_lock_test!(test_lock_zyx, $t, $q, ER::ZYX, $v::Z, $v::Y, $v::X, $v);
+        impl_3axis_lock_test!(test_lock_zxy, $t, $q, ER::ZXY, $v::Z, $v::X, $v::Y, $v);
+        impl_3axis_lock_test!(test_lock_yxz, $t, $q, ER::YXZ, $v::Y, $v::X, $v::Z, $v);
+        impl_3axis_lock_test!(test_lock_yzx, $t, $q, ER::YZX, $v::Y, $v::Z, $v::X, $v);
+        impl_3axis_lock_test!(test_lock_xyz, $t, $q, ER::XYZ, $v::X, $v::Y, $v::Z, $v);
+        impl_3axis_lock_test!(test_lock_xzy, $t, $q, ER::XZY, $v::X, $v::Z, $v::Y, $v);
     };
 }
 
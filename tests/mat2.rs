@@ -17,6 +17,81 @@ macro_rules! impl_mat2_tests {
             assert_eq!(MATRIX1D, M0.to_cols_array());
             assert_eq!(MATRIX1D, M1.to_cols_array());
             assert_eq!(MATRIX1D, M2.to_cols_array());
+
+            const IDENTITY: $mat2 = $mat2::IDENTITY;
+            const ZERO: $mat2 = $mat2::ZERO;
+            assert_eq!($mat2::IDENTITY, IDENTITY);
+            assert_eq!([0.0; 4], ZERO.to_cols_array());
+        });
+
+        glam_test!(test_mat2_row_col, {
+            let m = $mat2::from_cols_array(&MATRIX1D);
+            let a = m.to_cols_array_2d();
+            for i in 0..2 {
+                assert_eq!([a[0][i], a[1][i]], m.row(i).to_array());
+                assert_eq!(a[i], m.col(i).to_array());
+                assert_eq!(m.transpose().col(i), m.row(i));
+            }
+        });
+
+        glam_test!(test_mat2_index, {
+            let m = $mat2::from_cols_array(&MATRIX1D);
+            for i in 0..2 {
+                assert_eq!(m.col(i), m[i]);
+                assert_eq!(MATRIX[i], m[i].to_array());
+            }
+            // indexing is column-major, matching `from_cols`
+            let r = $mat2::from_cols(m.y_axis, m.x_axis);
+            for i in 0..2 {
+                assert_eq!(m[1 - i], r[i]);
+            }
+
+            let mut n = m;
+            n[0] = m[1];
+            n[1][0] = 42.0;
+            assert_eq!(m.y_axis, n.x_axis);
+            assert_eq!(42.0, n.y_axis.x);
+            assert_eq!(n.col(1), n[1]);
+
+            should_panic!({ m[2] });
+            should_panic!({
+                let mut m = m;
+                m[2] = m[0];
+            });
+        });
+
+        glam_test!(test_mat2_cols_array_layout, {
+            // the layout is column-major: element `i * 2 + j` is row `j` of column `i`
+            let m = $mat2::from_cols_array(&MATRIX1D);
+            for i in 0..2 {
+                for j in 0..2 {
+                    assert_eq!(MATRIX1D[i * 2 + j], m.col(i)[j]);
+                    assert_eq!(MATRIX1D[i * 2 + j], m.row(j)[i]);
+                    assert_eq!(MATRIX[i][j], m.col(i)[j]);
+                }
+            }
+            assert_eq!(m, $mat2::from_cols_array_2d(&MATRIX));
+
+            // round tripping preserves the exact bits, including signed zero and NaN payloads
+            let mut a = MATRIX1D;
+            a[0] = -0.0;
+            a[1] = $t::from_bits(<$t>::NAN.to_bits() | 1);
+            a[2] = $t::MIN_POSITIVE / 2.0;
+            let bits = a.map(|x| x.to_bits());
+            let m = $mat2::from_cols_array(&a);
+            assert_eq!(bits, m.to_cols_array().map(|x| x.to_bits()));
+            let a2 = m.to_cols_array_2d();
+            assert_eq!(
+                bits,
+                $mat2::from_cols_array_2d(&a2)
+                    .to_cols_array()
+                    .map(|x| x.to_bits())
+            );
+            for i in 0..2 {
+                for j in 0..2 {
+                    assert_eq!(bits[i * 2 + j], a2[i][j].to_bits());
+                }
+            }
         });
 
         glam_test!(test_mat2_identity, {
@@ -184,9 +259,70 @@ macro_rules! impl_mat2_tests {
             assert_approx_eq!(m0, m1);
         });
 
+        glam_test!(test_mat2_mul_add, {
+            let m0 = $mat2::from_cols_array_2d(&MATRIX);
+            let m1 = m0.transpose();
+            let m2 = m0 * 2.0;
+            assert_eq!(m0 * m1 + m2, m0.mul_add(&m1, &m2));
+            assert_eq!(m2, $mat2::ZERO.mul_add(&m1, &m2));
+            assert_eq!(m0 * m1, m0.mul_add(&m1, &$mat2::ZERO));
+
+            // the multiply-add is fused so the small product is not lost to rounding
+            let e = $t::EPSILON;
+            let m = ($mat2::IDENTITY * (1.0 + e))
+                .mul_add(&($mat2::IDENTITY * (1.0 - e)), &($mat2::IDENTITY * -1.0));
+            assert_eq!($mat2::IDENTITY * (-e * e), m);
+        });
+
         glam_test!(test_mat2_fmt, {
             let a = $mat2::from_cols_array_2d(&MATRIX);
             assert_eq!(format!("{}", a), "[[1, 2], [3, 4]]");
+            assert_eq!(format!("{:.1}", a), "[[1.0, 2.0], [3.0, 4.0]]");
+            assert_eq!(format!("{:+}", -a), "[[-1, -2], [-3, -4]]");
+        });
+
+        glam_test!(test_mat2_debug, {
+            let a = $mat2::from_cols_array_2d(&[[1.0, -2.0], [3.5, 40.0]]);
+            let name = stringify!($mat2);
+            let col = format!("{:?}", a.x_axis);
+            let col = &col[..col.find('(').unwrap()];
+            assert_eq!(
+                format!("{:?}", a),
+                format!("{name} {{ x_axis: {col}(1.0, -2.0), y_axis: {col}(3.5, 40.0) }}")
+            );
+
+            // the alternate form prints one column per line with the elements aligned
+            let expected = [
+                format!("{name} {{"),
+                format!("    x_axis: {col}( 1.0, -2.0),"),
+                format!("    y_axis: {col}( 3.5, 40.0),"),
+                "}".to_string(),
+            ]
+            .join("\n");
+            assert_eq!(format!("{:#?}", a), expected);
+
+            let expected = [
+                format!("{name} {{"),
+                format!("    x_axis: {col}( 1.00, -2.00),"),
+                format!("    y_axis: {col}( 3.50, 40.00),"),
+                "}".to_string(),
+            ]
+            .join("\n");
+            assert_eq!(format!("{:#.2?}", a), expected);
+        });
+
+        glam_test!(test_mat2_from_str, {
+            let a = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(-a), format!("{}", -a).parse());
+            assert_eq!(
+                Ok($mat2::IDENTITY * 1e-20),
+                format!("{}", $mat2::IDENTITY * 1e-20).parse()
+            );
+            assert_eq!(
+                Err(glam::ParseError::MissingBrackets),
+                format!("{}", a.col(0)).parse::<$mat2>()
+            );
         });
 
         glam_test!(test_mat2_to_from_slice, {
@@ -198,18 +334,49 @@ macro_rules! impl_mat2_tests {
 
             should_panic!({ $mat2::from_cols_slice(&[0.0; 3]) });
             should_panic!({ $mat2::IDENTITY.write_cols_to_slice(&mut [0.0; 3]) });
+
+            // longer slices only use the first 4 elements
+            let mut out = [-1.0; 6];
+            m.write_cols_to_slice(&mut out[1..]);
+            assert_eq!(-1.0, out[0]);
+            assert_eq!(MATRIX1D, out[1..5]);
+            assert_eq!(-1.0, out[5]);
+            assert_eq!(m, $mat2::from_cols_slice(&out[1..]));
+        });
+
+        glam_test!(test_mat2_from_into_array_2d, {
+            let m: $mat2 = MATRIX.into();
+            assert_eq!($mat2::from_cols_array_2d(&MATRIX), m);
+            // the inner arrays are columns
+            assert_eq!(MATRIX[1], m.col(1).to_array());
+            assert_eq!(MATRIX[0][1], m.col(0).y);
+            let a: [[$t; 2]; 2] = m.into();
+            assert_eq!(MATRIX, a);
+            assert_eq!(MATRIX1D, m.to_cols_array());
         });
 
         glam_test!(test_sum, {
             let id = $mat2::IDENTITY;
             assert_eq!(vec![id, id].iter().sum::<$mat2>(), id + id);
             assert_eq!(vec![id, id].into_iter().sum::<$mat2>(), id + id);
+
+            assert_eq!(Vec::<$mat2>::new().iter().sum::<$mat2>(), $mat2::ZERO);
+            assert_eq!(Vec::<$mat2>::new().into_iter().sum::<$mat2>(), $mat2::ZERO);
         });
 
         glam_test!(test_product, {
             let two = $mat2::IDENTITY + $mat2::IDENTITY;
             assert_eq!(vec![two, two].iter().product::<$mat2>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$mat2>(), two * two);
+
+            assert_eq!(
+                Vec::<$mat2>::new().iter().product::<$mat2>(),
+                $mat2::IDENTITY
+            );
+            assert_eq!(
+                Vec::<$mat2>::new().into_iter().product::<$mat2>(),
+                $mat2::IDENTITY
+            );
         });
 
         glam_test!(test_mat2_is_finite, {
@@ -220,6 +387,19 @@ macro_rules! impl_mat2_tests {
             assert!(!($mat2::IDENTITY * INFINITY).is_finite());
             assert!(!($mat2::IDENTITY * NEG_INFINITY).is_finite());
             assert!(!($mat2::IDENTITY * NAN).is_finite());
+
+            // a single infinite element
+            let mut a = $mat2::IDENTITY.to_cols_array();
+            a[1] = INFINITY;
+            let m = $mat2::from_cols_array(&a);
+            assert!(!m.is_finite());
+            assert!(!m.is_nan());
+
+            // a single NaN element, alongside the infinite one
+            a[2] = NAN;
+            let m = $mat2::from_cols_array(&a);
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
         });
     };
 }
@@ -269,6 +449,12 @@ mod mat2 {
             Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
             DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]).as_mat2()
         );
+
+        // every f32 survives the round trip through f64
+        let m = Mat2::from_diagonal(Vec2::splat(0.1)) * 1e-3;
+        assert_eq!(m, m.as_dmat2().as_mat2());
+        let m = DMat2::IDENTITY * -0.25;
+        assert_eq!(m, m.as_mat2().as_dmat2());
     });
 
     impl_mat2_tests!(f32, mat2, Mat2, Mat3, vec2, Vec2);
@@ -25,6 +25,81 @@ macro_rules! impl_mat3_tests {
             assert_eq!(MATRIX1D, M0.to_cols_array());
             assert_eq!(MATRIX1D, M1.to_cols_array());
             assert_eq!(MATRIX1D, M2.to_cols_array());
+
+            const IDENTITY: $mat3 = $mat3::IDENTITY;
+            const ZERO: $mat3 = $mat3::ZERO;
+            assert_eq!($mat3::IDENTITY, IDENTITY);
+            assert_eq!([0.0; 9], ZERO.to_cols_array());
+        });
+
+        glam_test!(test_mat3_row_col, {
+            let m = $mat3::from_cols_array(&MATRIX1D);
+            let a = m.to_cols_array_2d();
+            for i in 0..3 {
+                assert_eq!([a[0][i], a[1][i], a[2][i]], m.row(i).to_array());
+                assert_eq!(a[i], m.col(i).to_array());
+                assert_eq!(m.transpose().col(i), m.row(i));
+            }
+        });
+
+        glam_test!(test_mat3_index, {
+            let m = $mat3::from_cols_array(&MATRIX1D);
+            for i in 0..3 {
+                assert_eq!(m.col(i), m[i]);
+                assert_eq!(MATRIX[i], m[i].to_array());
+            }
+            // indexing is column-major, matching `from_cols`
+            let r = $mat3::from_cols(m.z_axis, m.y_axis, m.x_axis);
+            for i in 0..3 {
+                assert_eq!(m[2 - i], r[i]);
+            }
+
+            let mut n = m;
+            n[0] = m[2];
+            n[2][0] = 42.0;
+            assert_eq!(m.z_axis, n.x_axis);
+            assert_eq!(42.0, n.z_axis.x);
+            assert_eq!(n.col(2), n[2]);
+
+            should_panic!({ m[3] });
+            should_panic!({
+                let mut m = m;
+                m[3] = m[0];
+            });
+        });
+
+        glam_test!(test_mat3_cols_array_layout, {
+            // the layout is column-major: element `i * 3 + j` is row `j` of column `i`
+            let m = $mat3::from_cols_array(&MATRIX1D);
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_eq!(MATRIX1D[i * 3 + j], m.col(i)[j]);
+                    assert_eq!(MATRIX1D[i * 3 + j], m.row(j)[i]);
+                    assert_eq!(MATRIX[i][j], m.col(i)[j]);
+                }
+            }
+            assert_eq!(m, $mat3::from_cols_array_2d(&MATRIX));
+
+            // round tripping preserves the exact bits, including signed zero and NaN payloads
+            let mut a = MATRIX1D;
+            a[0] = -0.0;
+            a[1] = $t::from_bits(<$t>::NAN.to_bits() | 1);
+            a[3] = $t::MIN_POSITIVE / 2.0;
+            let bits = a.map(|x| x.to_bits());
+            let m = $mat3::from_cols_array(&a);
+            assert_eq!(bits, m.to_cols_array().map(|x| x.to_bits()));
+            let a2 = m.to_cols_array_2d();
+            assert_eq!(
+                bits,
+                $mat3::from_cols_array_2d(&a2)
+                    .to_cols_array()
+                    .map(|x| x.to_bits())
+            );
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_eq!(bits[i * 3 + j], a2[i][j].to_bits());
+                }
+            }
         });
 
         glam_test!(test_mat3_identity, {
@@ -173,6 +248,31 @@ macro_rules! impl_mat3_tests {
             assert_approx_eq!(result2, (m * $vec2::Y.extend(1.0)).truncate());
         });
 
+        glam_test!(test_mat3_transform2d_compose, {
+            let scale = $vec2::new(0.5, 1.5);
+            let angle = $t::to_radians(30.0);
+            let translation = $vec2::new(1.0, -2.0);
+            let m = $mat3::from_scale_angle_translation(scale, angle, translation);
+            let composed = $mat3::from_translation(translation)
+                * $mat3::from_angle(angle)
+                * $mat3::from_scale(scale);
+            assert_approx_eq!(composed, m, 1e-6);
+
+            for p in [$vec2::ZERO, $vec2::X, $vec2::new(-3.0, 4.0)] {
+                let expected = $mat3::from_translation(translation).transform_point2(
+                    $mat3::from_angle(angle)
+                        .transform_point2($mat3::from_scale(scale).transform_point2(p)),
+                );
+                assert_approx_eq!(expected, m.transform_point2(p), 1e-6);
+                assert_approx_eq!(expected, composed.transform_point2(p), 1e-6);
+                assert_approx_eq!(
+                    m.transform_point2(p) - translation,
+                    m.transform_vector2(p),
+                    1e-6
+                );
+            }
+        });
+
         glam_test!(test_from_ypr, {
             use glam::EulerRot;
             let zero = deg(0.0);
@@ -229,6 +329,11 @@ macro_rules! impl_mat3_tests {
                 $mat3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [5.0, 6.0, 7.0], [9.0, 10.0, 11.0]]),
                 m3
             );
+            assert_eq!(m3, $mat3::from(m4));
+
+            let rot = $mat3::from_rotation_x(deg(30.0)) * $mat3::from_rotation_z(deg(45.0));
+            assert_eq!(rot, $mat3::from_mat4($mat4::from_mat3(rot.into())));
+            assert_eq!(rot, $mat3::from($mat4::from(rot)));
         });
 
         glam_test!(test_mat3_transpose, {
@@ -323,9 +428,93 @@ macro_rules! impl_mat3_tests {
             assert_approx_eq!(m0, m1);
         });
 
+        glam_test!(test_mat3_mul_add, {
+            let m0 = $mat3::from_cols_array_2d(&MATRIX);
+            let m1 = m0.transpose();
+            let m2 = m0 * 2.0;
+            assert_eq!(m0 * m1 + m2, m0.mul_add(&m1, &m2));
+            assert_eq!(m2, $mat3::ZERO.mul_add(&m1, &m2));
+            assert_eq!(m0 * m1, m0.mul_add(&m1, &$mat3::ZERO));
+
+            // the multiply-add is fused so the small product is not lost to rounding
+            let e = $t::EPSILON;
+            let m = ($mat3::IDENTITY * (1.0 + e))
+                .mul_add(&($mat3::IDENTITY * (1.0 - e)), &($mat3::IDENTITY * -1.0));
+            assert_eq!($mat3::IDENTITY * (-e * e), m);
+        });
+
         glam_test!(test_mat3_fmt, {
             let a = $mat3::from_cols_array_2d(&MATRIX);
             assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+            assert_eq!(
+                format!("{:.1}", a),
+                "[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]"
+            );
+            assert_eq!(
+                format!("{:+2}", a),
+                "[[+1, +2, +3], [+4, +5, +6], [+7, +8, +9]]"
+            );
+            // the inner arrays are columns, so the translation is printed last
+            assert_eq!(
+                format!("{}", $mat3::from_translation($vec2::new(5.0, 6.0))),
+                "[[1, 0, 0], [0, 1, 0], [5, 6, 1]]"
+            );
+        });
+
+        glam_test!(test_mat3_debug, {
+            let a =
+                $mat3::from_cols_array_2d(&[[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 0.0, 10.5]]);
+            let name = stringify!($mat3);
+            let col = format!("{:?}", a.x_axis);
+            let col = &col[..col.find('(').unwrap()];
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    concat!(
+                        "{name} {{ ",
+                        "x_axis: {col}(1.0, 0.0, 0.0), ",
+                        "y_axis: {col}(0.0, -1.0, 0.0), ",
+                        "z_axis: {col}(0.0, 0.0, 10.5) }}",
+                    ),
+                    name = name,
+                    col = col
+                )
+            );
+
+            // the alternate form prints one column per line with the elements aligned
+            let expected = [
+                format!("{name} {{"),
+                format!("    x_axis: {col}( 1.0,  0.0,  0.0),"),
+                format!("    y_axis: {col}( 0.0, -1.0,  0.0),"),
+                format!("    z_axis: {col}( 0.0,  0.0, 10.5),"),
+                "}".to_string(),
+            ]
+            .join("\n");
+            assert_eq!(format!("{:#?}", a), expected);
+
+            let expected = [
+                format!("{name} {{"),
+                format!("    x_axis: {col}( 1.00,  0.00,  0.00),"),
+                format!("    y_axis: {col}( 0.00, -1.00,  0.00),"),
+                format!("    z_axis: {col}( 0.00,  0.00, 10.50),"),
+                "}".to_string(),
+            ]
+            .join("\n");
+            assert_eq!(format!("{:#.2?}", a), expected);
+        });
+
+        glam_test!(test_mat3_from_str, {
+            let a = $mat3::from_cols_array_2d(&MATRIX);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(-a), format!("{}", -a).parse());
+            assert_eq!(
+                Ok($mat3::IDENTITY * 1e-20),
+                format!("{}", $mat3::IDENTITY * 1e-20).parse()
+            );
+            assert_eq!(
+                Err(glam::ParseError::MissingBrackets),
+                format!("{}", a.col(0)).parse::<$mat3>()
+            );
         });
 
         glam_test!(test_mat3_to_from_slice, {
@@ -337,18 +526,49 @@ macro_rules! impl_mat3_tests {
 
             should_panic!({ $mat3::from_cols_slice(&[0.0; 8]) });
             should_panic!({ $mat3::IDENTITY.write_cols_to_slice(&mut [0.0; 8]) });
+
+            // longer slices only use the first 9 elements
+            let mut out = [-1.0; 11];
+            m.write_cols_to_slice(&mut out[1..]);
+            assert_eq!(-1.0, out[0]);
+            assert_eq!(MATRIX1D, out[1..10]);
+            assert_eq!(-1.0, out[10]);
+            assert_eq!(m, $mat3::from_cols_slice(&out[1..]));
+        });
+
+        glam_test!(test_mat3_from_into_array_2d, {
+            let m: $mat3 = MATRIX.into();
+            assert_eq!($mat3::from_cols_array_2d(&MATRIX), m);
+            // the inner arrays are columns
+            assert_eq!(MATRIX[1], m.col(1).to_array());
+            assert_eq!(MATRIX[0][1], m.col(0).y);
+            let a: [[$t; 3]; 3] = m.into();
+            assert_eq!(MATRIX, a);
+            assert_eq!(MATRIX1D, m.to_cols_array());
         });
 
         glam_test!(test_sum, {
             let id = $mat3::IDENTITY;
             assert_eq!(vec![id, id].iter().sum::<$mat3>(), id + id);
             assert_eq!(vec![id, id].into_iter().sum::<$mat3>(), id + id);
+
+            assert_eq!(Vec::<$mat3>::new().iter().sum::<$mat3>(), $mat3::ZERO);
+            assert_eq!(Vec::<$mat3>::new().into_iter().sum::<$mat3>(), $mat3::ZERO);
         });
 
         glam_test!(test_product, {
             let two = $mat3::IDENTITY + $mat3::IDENTITY;
             assert_eq!(vec![two, two].iter().product::<$mat3>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$mat3>(), two * two);
+
+            assert_eq!(
+                Vec::<$mat3>::new().iter().product::<$mat3>(),
+                $mat3::IDENTITY
+            );
+            assert_eq!(
+                Vec::<$mat3>::new().into_iter().product::<$mat3>(),
+                $mat3::IDENTITY
+            );
         });
 
         glam_test!(test_mat3_is_finite, {
@@ -356,6 +576,19 @@ macro_rules! impl_mat3_tests {
             assert!(!($mat3::IDENTITY * INFINITY).is_finite());
             assert!(!($mat3::IDENTITY * NEG_INFINITY).is_finite());
             assert!(!($mat3::IDENTITY * NAN).is_finite());
+
+            // a single infinite element
+            let mut a = $mat3::IDENTITY.to_cols_array();
+            a[4] = INFINITY;
+            let m = $mat3::from_cols_array(&a);
+            assert!(!m.is_finite());
+            assert!(!m.is_nan());
+
+            // a single NaN element, alongside the infinite one
+            a[7] = NAN;
+            let m = $mat3::from_cols_array(&a);
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
         });
     };
 }
@@ -400,6 +633,12 @@ mod mat3 {
             Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
             DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]).as_mat3()
         );
+
+        // every f32 survives the round trip through f64
+        let m = Mat3::from_diagonal(Vec3::splat(0.1)) * 1e-3;
+        assert_eq!(m, m.as_dmat3().as_mat3());
+        let m = DMat3::IDENTITY * -0.25;
+        assert_eq!(m, m.as_mat3().as_dmat3());
     });
 
     impl_mat3_tests!(f32, mat3, Mat3, Mat2, Mat4, Quat, vec3, Vec3, Vec2);
@@ -430,6 +669,24 @@ mod mat3a {
         );
     });
 
+    glam_test!(test_matches_mat3, {
+        use glam::Mat3;
+        let m0 = Mat3::from_cols_array(&[2.0, 1.0, -1.0, 0.5, 3.0, 0.25, -2.0, 1.5, 4.0]);
+        let m1 = Mat3::from_scale_angle_translation(Vec2::new(2.0, 0.5), deg(30.0), Vec2::ONE);
+        let a0 = Mat3A::from(m0);
+        let a1 = Mat3A::from(m1);
+        assert_eq!(m0, Mat3::from(a0));
+        assert_eq!(m0.to_cols_array(), a0.to_cols_array());
+
+        let v = Vec3::new(1.0, -2.0, 3.0);
+        assert_approx_eq!(m0 * v, a0 * v, 1e-6);
+        assert_approx_eq!(Vec3A::from(m0 * v), a0 * Vec3A::from(v), 1e-6);
+        assert_approx_eq!(Mat3A::from(m0 * m1), a0 * a1, 1e-6);
+        assert_approx_eq!(Mat3A::from(m0.transpose()), a0.transpose(), 1e-6);
+        assert_approx_eq!(m0.determinant(), a0.determinant(), 1e-5);
+        assert_approx_eq!(Mat3A::from(m0.inverse()), a0.inverse(), 1e-6);
+    });
+
     impl_mat3_tests!(f32, mat3a, Mat3A, Mat2, Mat4, Quat, vec3a, Vec3, Vec2);
 }
 
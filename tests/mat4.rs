@@ -37,6 +37,81 @@ macro_rules! impl_mat4_tests {
             assert_eq!(MATRIX1D, M0.to_cols_array());
             assert_eq!(MATRIX1D, M1.to_cols_array());
             assert_eq!(MATRIX1D, M2.to_cols_array());
+
+            const IDENTITY: $mat4 = $mat4::IDENTITY;
+            const ZERO: $mat4 = $mat4::ZERO;
+            assert_eq!($mat4::IDENTITY, IDENTITY);
+            assert_eq!([0.0; 16], ZERO.to_cols_array());
+        });
+
+        glam_test!(test_mat4_row_col, {
+            let m = $mat4::from_cols_array(&MATRIX1D);
+            let a = m.to_cols_array_2d();
+            for i in 0..4 {
+                assert_eq!([a[0][i], a[1][i], a[2][i], a[3][i]], m.row(i).to_array());
+                assert_eq!(a[i], m.col(i).to_array());
+                assert_eq!(m.transpose().col(i), m.row(i));
+            }
+        });
+
+        glam_test!(test_mat4_index, {
+            let m = $mat4::from_cols_array(&MATRIX1D);
+            for i in 0..4 {
+                assert_eq!(m.col(i), m[i]);
+                assert_eq!(MATRIX[i], m[i].to_array());
+            }
+            // indexing is column-major, matching `from_cols`
+            let r = $mat4::from_cols(m.w_axis, m.z_axis, m.y_axis, m.x_axis);
+            for i in 0..4 {
+                assert_eq!(m[3 - i], r[i]);
+            }
+
+            let mut n = m;
+            n[0] = m[3];
+            n[3][0] = 42.0;
+            assert_eq!(m.w_axis, n.x_axis);
+            assert_eq!(42.0, n.w_axis.x);
+            assert_eq!(n.col(3), n[3]);
+
+            should_panic!({ m[4] });
+            should_panic!({
+                let mut m = m;
+                m[4] = m[0];
+            });
+        });
+
+        glam_test!(test_mat4_cols_array_layout, {
+            // the layout is column-major: element `i * 4 + j` is row `j` of column `i`
+            let m = $mat4::from_cols_array(&MATRIX1D);
+            for i in 0..4 {
+                for j in 0..4 {
+                    assert_eq!(MATRIX1D[i * 4 + j], m.col(i)[j]);
+                    assert_eq!(MATRIX1D[i * 4 + j], m.row(j)[i]);
+                    assert_eq!(MATRIX[i][j], m.col(i)[j]);
+                }
+            }
+            assert_eq!(m, $mat4::from_cols_array_2d(&MATRIX));
+
+            // round tripping preserves the exact bits, including signed zero and NaN payloads
+            let mut a = MATRIX1D;
+            a[0] = -0.0;
+            a[1] = $t::from_bits(<$t>::NAN.to_bits() | 1);
+            a[4] = $t::MIN_POSITIVE / 2.0;
+            let bits = a.map(|x| x.to_bits());
+            let m = $mat4::from_cols_array(&a);
+            assert_eq!(bits, m.to_cols_array().map(|x| x.to_bits()));
+            let a2 = m.to_cols_array_2d();
+            assert_eq!(
+                bits,
+                $mat4::from_cols_array_2d(&a2)
+                    .to_cols_array()
+                    .map(|x| x.to_bits())
+            );
+            for i in 0..4 {
+                for j in 0..4 {
+                    assert_eq!(bits[i * 4 + j], a2[i][j].to_bits());
+                }
+            }
         });
 
         glam_test!(test_mat4_identity, {
@@ -183,6 +258,11 @@ macro_rules! impl_mat4_tests {
                 ]),
                 m4
             );
+            assert_eq!(m4, $mat4::from(m3));
+
+            let rot = $mat4::from_rotation_y(deg(30.0)) * $mat4::from_rotation_x(deg(45.0));
+            assert_eq!(rot, $mat4::from_mat3($mat3::from_mat4(rot)));
+            assert_eq!(rot, $mat4::from($mat3::from(rot)));
         });
 
         glam_test!(test_mat4_mul, {
@@ -348,6 +428,47 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::ZERO.inverse() });
         });
 
+        glam_test!(test_mat4_trs_round_trip, {
+            let in_translation = $vec3::new(-2.0, 4.0, -0.125);
+            for (in_scale, in_rotation) in [
+                ($vec3::ONE, $quat::from_rotation_y(deg(30.0))),
+                (
+                    $vec3::new(1.0, 2.0, 4.0),
+                    $quat::from_rotation_x(deg(-60.0)),
+                ),
+                (
+                    $vec3::new(0.5, 3.0, 1.5),
+                    $quat::from_axis_angle($vec3::new(1.0, -2.0, 3.0).normalize(), deg(200.0)),
+                ),
+            ] {
+                let m =
+                    $mat4::from_scale_rotation_translation(in_scale, in_rotation, in_translation);
+                let (out_scale, out_rotation, out_translation) = m.to_scale_rotation_translation();
+                assert_approx_eq!(in_scale, out_scale, 1e-5);
+                // `q` and `-q` represent the same rotation
+                let out_rotation = if in_rotation.dot(out_rotation) < 0.0 {
+                    -out_rotation
+                } else {
+                    out_rotation
+                };
+                assert_approx_eq!(in_rotation, out_rotation, 1e-5);
+                assert_approx_eq!(in_translation, out_translation, 1e-6);
+
+                // the rotation only constructors agree with the TRS one
+                assert_approx_eq!(
+                    $mat4::from_rotation_translation(in_rotation, in_translation),
+                    $mat4::from_scale_rotation_translation($vec3::ONE, in_rotation, in_translation),
+                    1e-6
+                );
+                assert_approx_eq!(
+                    $mat4::from_quat(in_rotation),
+                    $mat4::from_mat3($mat3::from_quat(in_rotation)),
+                    1e-6
+                );
+                assert_eq!($vec4::W, $mat4::from_quat(in_rotation).w_axis);
+            }
+        });
+
         glam_test!(test_mat4_decompose, {
             // identity
             let (out_scale, out_rotation, out_translation) =
@@ -456,8 +577,32 @@ macro_rules! impl_mat4_tests {
             assert_approx_eq!(lh.transform_point3(point), $vec3::new(0.0, 1.0, 5.0));
             assert_approx_eq!(rh.transform_point3(point), $vec3::new(0.0, 1.0, -5.0));
 
+            // look_to with `center - eye` matches look_at for arbitrary inputs
+            let eye = $vec3::new(1.0, 2.0, 3.0);
+            let center = $vec3::new(-4.0, 0.5, 2.0);
+            let up = $vec3::new(0.1, 1.0, -0.2).normalize();
+            assert_approx_eq!(
+                $mat4::look_to_lh(eye, center - eye, up),
+                $mat4::look_at_lh(eye, center, up),
+                1e-6
+            );
+            assert_approx_eq!(
+                $mat4::look_to_rh(eye, center - eye, up),
+                $mat4::look_at_rh(eye, center, up),
+                1e-6
+            );
+            // the direction does not need to be normalized
+            assert_approx_eq!(
+                $mat4::look_to_rh(eye, (center - eye).normalize(), up),
+                $mat4::look_to_rh(eye, (center - eye) * 3.0, up),
+                1e-6
+            );
+
             should_glam_assert!({ $mat4::look_at_lh($vec3::ONE, $vec3::ZERO, $vec3::ZERO) });
             should_glam_assert!({ $mat4::look_at_rh($vec3::ONE, $vec3::ZERO, $vec3::ZERO) });
+            should_glam_assert!({ $mat4::look_to_lh($vec3::ONE, $vec3::Y, $vec3::Y) });
+            should_glam_assert!({ $mat4::look_to_rh($vec3::ONE, $vec3::Y, $vec3::NEG_Y) });
+            should_glam_assert!({ $mat4::look_to_rh($vec3::ONE, $vec3::X, $vec3::ZERO) });
         });
 
         glam_test!(test_mat4_perspective_gl_rh, {
@@ -489,6 +634,7 @@ macro_rules! impl_mat4_tests {
 
         glam_test!(test_mat4_perspective_infinite_lh, {
             let projection = $mat4::perspective_infinite_lh($t::to_radians(90.0), 2.0, 5.0);
+            assert!(projection.is_finite());
 
             let original = $vec3::new(5.0, 5.0, 15.0);
             let projected = projection * original.extend(1.0);
@@ -503,6 +649,7 @@ macro_rules! impl_mat4_tests {
 
         glam_test!(test_mat4_perspective_infinite_reverse_lh, {
             let projection = $mat4::perspective_infinite_reverse_lh($t::to_radians(90.0), 2.0, 5.0);
+            assert!(projection.is_finite());
 
             let original = $vec3::new(5.0, 5.0, 15.0);
             let projected = projection * original.extend(1.0);
@@ -532,6 +679,7 @@ macro_rules! impl_mat4_tests {
 
         glam_test!(test_mat4_perspective_infinite_rh, {
             let projection = $mat4::perspective_infinite_rh($t::to_radians(90.0), 2.0, 5.0);
+            assert!(projection.is_finite());
 
             let original = $vec3::new(5.0, 5.0, 15.0);
             let projected = projection * original.extend(1.0);
@@ -546,6 +694,7 @@ macro_rules! impl_mat4_tests {
 
         glam_test!(test_mat4_perspective_infinite_reverse_rh, {
             let projection = $mat4::perspective_infinite_reverse_rh($t::to_radians(90.0), 2.0, 5.0);
+            assert!(projection.is_finite());
 
             let original = $vec3::new(5.0, 5.0, 15.0);
             let projected = projection * original.extend(1.0);
@@ -558,6 +707,35 @@ macro_rules! impl_mat4_tests {
             should_glam_assert!({ $mat4::perspective_infinite_reverse_rh(0.0, 1.0, 0.0) });
         });
 
+        glam_test!(test_mat4_project_point3_perspective, {
+            // points on the near and far planes map to the NDC depths 0 and 1
+            let projection = $mat4::perspective_lh($t::to_radians(90.0), 2.0, 5.0, 15.0);
+            let near = projection.project_point3($vec3::new(5.0, 5.0, 5.0));
+            assert_approx_eq!($vec3::new(0.5, 1.0, 0.0), near, 1e-6);
+            let far = projection.project_point3($vec3::new(5.0, 5.0, 15.0));
+            assert_approx_eq!($vec3::new(1.0 / 6.0, 1.0 / 3.0, 1.0), far, 1e-6);
+
+            // right handed projections look down -z
+            let projection = $mat4::perspective_rh($t::to_radians(90.0), 2.0, 5.0, 15.0);
+            let near = projection.project_point3($vec3::new(5.0, 5.0, -5.0));
+            assert_approx_eq!($vec3::new(0.5, 1.0, 0.0), near, 1e-6);
+            let far = projection.project_point3($vec3::new(5.0, 5.0, -15.0));
+            assert_approx_eq!($vec3::new(1.0 / 6.0, 1.0 / 3.0, 1.0), far, 1e-6);
+
+            // reverse z maps the near plane to 1
+            let projection = $mat4::perspective_infinite_reverse_rh($t::to_radians(90.0), 2.0, 5.0);
+            let near = projection.project_point3($vec3::new(5.0, 5.0, -5.0));
+            assert_approx_eq!($vec3::new(0.5, 1.0, 1.0), near, 1e-6);
+
+            // `transform_point3` assumes `w` is 1 and doesn't divide
+            should_glam_assert!({ projection.transform_point3($vec3::Z) });
+            let affine = $mat4::from_translation($vec3::new(1.0, 2.0, 3.0));
+            assert_eq!(
+                affine.transform_point3($vec3::ONE),
+                affine.project_point3($vec3::ONE)
+            );
+        });
+
         glam_test!(test_mat4_orthographic_gl_rh, {
             let projection = $mat4::orthographic_rh_gl(-10.0, 10.0, -5.0, 5.0, 0.0, -10.0);
             let original = $vec4::new(5.0, 5.0, -5.0, 1.0);
@@ -626,12 +804,99 @@ macro_rules! impl_mat4_tests {
             assert_approx_eq!(m0, m1);
         });
 
+        glam_test!(test_mat4_mul_add, {
+            let m0 = $mat4::from_cols_array_2d(&MATRIX);
+            let m1 = m0.transpose();
+            let m2 = m0 * 2.0;
+            assert_eq!(m0 * m1 + m2, m0.mul_add(&m1, &m2));
+            assert_eq!(m2, $mat4::ZERO.mul_add(&m1, &m2));
+            assert_eq!(m0 * m1, m0.mul_add(&m1, &$mat4::ZERO));
+
+            // the multiply-add is fused so the small product is not lost to rounding
+            let e = $t::EPSILON;
+            let m = ($mat4::IDENTITY * (1.0 + e))
+                .mul_add(&($mat4::IDENTITY * (1.0 - e)), &($mat4::IDENTITY * -1.0));
+            assert_eq!($mat4::IDENTITY * (-e * e), m);
+        });
+
         glam_test!(test_mat4_fmt, {
             let a = $mat4::from_cols_array_2d(&MATRIX);
             assert_eq!(
                 format!("{}", a),
                 "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]"
             );
+            assert_eq!(
+                format!("{:2}", a),
+                "[[ 1,  2,  3,  4], [ 5,  6,  7,  8], [ 9, 10, 11, 12], [13, 14, 15, 16]]"
+            );
+            // the inner arrays are columns, so the translation is printed last
+            assert_eq!(
+                format!("{}", $mat4::from_translation($newvec3(5.0, 6.0, 7.0))),
+                "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [5, 6, 7, 1]]"
+            );
+        });
+
+        glam_test!(test_mat4_debug, {
+            let a = $mat4::from_cols_array_2d(&[
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [100.0, -2.5, 0.0, 1.0],
+            ]);
+            let name = stringify!($mat4);
+            let col = format!("{:?}", a.x_axis);
+            let col = &col[..col.find('(').unwrap()];
+            assert_eq!(
+                format!("{:?}", a),
+                format!(
+                    concat!(
+                        "{name} {{ ",
+                        "x_axis: {col}(1.0, 0.0, 0.0, 0.0), ",
+                        "y_axis: {col}(0.0, 1.0, 0.0, 0.0), ",
+                        "z_axis: {col}(0.0, 0.0, 1.0, 0.0), ",
+                        "w_axis: {col}(100.0, -2.5, 0.0, 1.0) }}",
+                    ),
+                    name = name,
+                    col = col
+                )
+            );
+
+            // the alternate form prints one column per line with the elements aligned
+            let expected = [
+                format!("{name} {{"),
+                format!("    x_axis: {col}(  1.0,   0.0,   0.0,   0.0),"),
+                format!("    y_axis: {col}(  0.0,   1.0,   0.0,   0.0),"),
+                format!("    z_axis: {col}(  0.0,   0.0,   1.0,   0.0),"),
+                format!("    w_axis: {col}(100.0,  -2.5,   0.0,   1.0),"),
+                "}".to_string(),
+            ]
+            .join("\n");
+            assert_eq!(format!("{:#?}", a), expected);
+
+            let expected = [
+                format!("{name} {{"),
+                format!("    x_axis: {col}(  1.00,   0.00,   0.00,   0.00),"),
+                format!("    y_axis: {col}(  0.00,   1.00,   0.00,   0.00),"),
+                format!("    z_axis: {col}(  0.00,   0.00,   1.00,   0.00),"),
+                format!("    w_axis: {col}(100.00,  -2.50,   0.00,   1.00),"),
+                "}".to_string(),
+            ]
+            .join("\n");
+            assert_eq!(format!("{:#.2?}", a), expected);
+        });
+
+        glam_test!(test_mat4_from_str, {
+            let a = $mat4::from_cols_array_2d(&MATRIX);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(-a), format!("{}", -a).parse());
+            assert_eq!(
+                Ok($mat4::IDENTITY * 1e-20),
+                format!("{}", $mat4::IDENTITY * 1e-20).parse()
+            );
+            assert_eq!(
+                Err(glam::ParseError::MissingBrackets),
+                format!("{}", a.col(0)).parse::<$mat4>()
+            );
         });
 
         glam_test!(test_mat4_to_from_slice, {
@@ -643,18 +908,49 @@ macro_rules! impl_mat4_tests {
 
             should_panic!({ $mat4::from_cols_slice(&[0.0; 15]) });
             should_panic!({ $mat4::IDENTITY.write_cols_to_slice(&mut [0.0; 15]) });
+
+            // longer slices only use the first 16 elements
+            let mut out = [-1.0; 18];
+            m.write_cols_to_slice(&mut out[1..]);
+            assert_eq!(-1.0, out[0]);
+            assert_eq!(MATRIX1D, out[1..17]);
+            assert_eq!(-1.0, out[17]);
+            assert_eq!(m, $mat4::from_cols_slice(&out[1..]));
+        });
+
+        glam_test!(test_mat4_from_into_array_2d, {
+            let m: $mat4 = MATRIX.into();
+            assert_eq!($mat4::from_cols_array_2d(&MATRIX), m);
+            // the inner arrays are columns
+            assert_eq!(MATRIX[1], m.col(1).to_array());
+            assert_eq!(MATRIX[0][1], m.col(0).y);
+            let a: [[$t; 4]; 4] = m.into();
+            assert_eq!(MATRIX, a);
+            assert_eq!(MATRIX1D, m.to_cols_array());
         });
 
         glam_test!(test_sum, {
             let id = $mat4::IDENTITY;
             assert_eq!(vec![id, id].iter().sum::<$mat4>(), id + id);
             assert_eq!(vec![id, id].into_iter().sum::<$mat4>(), id + id);
+
+            assert_eq!(Vec::<$mat4>::new().iter().sum::<$mat4>(), $mat4::ZERO);
+            assert_eq!(Vec::<$mat4>::new().into_iter().sum::<$mat4>(), $mat4::ZERO);
         });
 
         glam_test!(test_product, {
             let two = $mat4::IDENTITY + $mat4::IDENTITY;
             assert_eq!(vec![two, two].iter().product::<$mat4>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$mat4>(), two * two);
+
+            assert_eq!(
+                Vec::<$mat4>::new().iter().product::<$mat4>(),
+                $mat4::IDENTITY
+            );
+            assert_eq!(
+                Vec::<$mat4>::new().into_iter().product::<$mat4>(),
+                $mat4::IDENTITY
+            );
         });
 
         glam_test!(test_mat4_is_finite, {
@@ -662,6 +958,19 @@ macro_rules! impl_mat4_tests {
             assert!(!($mat4::IDENTITY * INFINITY).is_finite());
             assert!(!($mat4::IDENTITY * NEG_INFINITY).is_finite());
             assert!(!($mat4::IDENTITY * NAN).is_finite());
+
+            // a single infinite element
+            let mut a = $mat4::IDENTITY.to_cols_array();
+            a[6] = INFINITY;
+            let m = $mat4::from_cols_array(&a);
+            assert!(!m.is_finite());
+            assert!(!m.is_nan());
+
+            // a single NaN element, alongside the infinite one
+            a[15] = NAN;
+            let m = $mat4::from_cols_array(&a);
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
         });
     };
 }
@@ -703,6 +1012,42 @@ mod mat4 {
             ]),
             m4
         );
+        assert_eq!(m4, Mat4::from(m3));
+
+        let rot = Mat4::from_rotation_z(deg(30.0)) * Mat4::from_rotation_x(deg(45.0));
+        assert_eq!(rot, Mat4::from(Mat3A::from(rot)));
+    });
+
+    glam_test!(test_transform_vec3a, {
+        use glam::Vec3A;
+        let m = Mat4::from_scale_rotation_translation(
+            Vec3::new(0.5, 1.5, 2.0),
+            Quat::from_euler(glam::EulerRot::YXZ, deg(30.0), deg(45.0), deg(60.0)),
+            Vec3::new(1.0, -2.0, 3.0),
+        );
+        for v in [
+            Vec3::ZERO,
+            Vec3::X,
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, -1.5),
+        ] {
+            assert_approx_eq!(
+                Vec3A::from(m.transform_point3(v)),
+                m.transform_point3a(v.into()),
+                1e-6
+            );
+            assert_approx_eq!(
+                Vec3A::from(m.transform_vector3(v)),
+                m.transform_vector3a(v.into()),
+                1e-6
+            );
+        }
+        // points are translated, vectors are not
+        assert_approx_eq!(m.transform_point3a(Vec3A::ZERO), Vec3A::new(1.0, -2.0, 3.0));
+        assert_eq!(m.transform_vector3a(Vec3A::ZERO), Vec3A::ZERO);
+
+        should_glam_assert!({ Mat4::ZERO.transform_point3a(Vec3A::X) });
+        should_glam_assert!({ Mat4::ZERO.transform_vector3a(Vec3A::X) });
     });
 
     glam_test!(test_as, {
@@ -737,6 +1082,12 @@ mod mat4 {
             ])
             .as_mat4()
         );
+
+        // every f32 survives the round trip through f64
+        let m = Mat4::from_diagonal(Vec4::splat(0.1)) * 1e-3;
+        assert_eq!(m, m.as_dmat4().as_mat4());
+        let m = DMat4::IDENTITY * -0.25;
+        assert_eq!(m, m.as_mat4().as_dmat4());
     });
 
     impl_mat4_tests!(f32, mat4, vec4, vec3, Mat4, Mat3, Quat, Vec4, Vec3);
@@ -14,6 +14,9 @@ macro_rules! impl_quat_tests {
             const Q1: $quat = $quat::from_array([1.0, 2.0, 3.0, 4.0]);
             assert_eq!([1.0, 2.0, 3.0, 4.0], *Q0.as_ref());
             assert_eq!([1.0, 2.0, 3.0, 4.0], *Q1.as_ref());
+
+            const I: $quat = $quat::IDENTITY;
+            assert_eq!([0.0, 0.0, 0.0, 1.0], *I.as_ref());
         });
 
         glam_test!(test_nan, {
@@ -37,7 +40,17 @@ macro_rules! impl_quat_tests {
 
             assert_eq!(q1, $quat::from_array(a1));
 
+            let t1: ($t, $t, $t, $t) = q1.into();
+            assert_eq!((v1.x, v1.y, v1.z, v1.w), t1);
+            assert_eq!(q1, $quat::from_xyzw(t1.0, t1.1, t1.2, t1.3));
+
             assert_eq!(a1, *q0.as_ref());
+
+            let mut q2 = $quat::IDENTITY;
+            *q2.as_mut() = a1;
+            assert_eq!(q1, q2);
+            q2.as_mut()[3] = 0.0;
+            assert_eq!($new(v1.x, v1.y, v1.z, 0.0), q2);
         });
 
         glam_test!(test_funcs, {
@@ -62,6 +75,32 @@ macro_rules! impl_quat_tests {
             should_glam_assert!({ ($quat::IDENTITY * 0.0).normalize() });
         });
 
+        glam_test!(test_try_normalize, {
+            let q0 = $quat::from_euler(EulerRot::YXZ, deg(45.0), deg(180.0), deg(90.0));
+            // slightly off unit length, e.g. after accumulating rotations
+            let q1 = $quat::from_vec4($vec4::from(q0) * 1.001);
+            assert!(!q1.is_normalized());
+            let n = q1.normalize();
+            assert!(n.is_normalized());
+            assert_approx_eq!(q0, n);
+            // normalizing a normalized quaternion doesn't change it
+            assert_approx_eq!(n, n.normalize());
+            assert_eq!(Some(n), q1.try_normalize());
+            assert_eq!(n, q1.normalize_or_identity());
+
+            // degenerate zero length and non-finite quaternions
+            let zero = $quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+            assert!(!zero.is_normalized());
+            assert_eq!(None, zero.try_normalize());
+            assert_eq!($quat::IDENTITY, zero.normalize_or_identity());
+            assert_eq!(None, $quat::NAN.try_normalize());
+            assert_eq!($quat::IDENTITY, $quat::NAN.normalize_or_identity());
+            assert_eq!(
+                $quat::IDENTITY,
+                $quat::from_xyzw(INFINITY, 0.0, 0.0, 0.0).normalize_or_identity()
+            );
+        });
+
         glam_test!(test_rotation, {
             let zero = deg(0.0);
             let yaw = deg(30.0);
@@ -142,6 +181,35 @@ macro_rules! impl_quat_tests {
             should_glam_assert!({ $quat::from_axis_angle($vec3::ZERO, 0.0) });
         });
 
+        glam_test!(test_from_mat_round_trip, {
+            // cover each branch of the extraction, where x, y, z and w are the largest component
+            for (axis, angle) in [
+                ($vec3::new(1.0, 0.2, -0.3), 170.0),
+                ($vec3::new(-0.2, 1.0, 0.3), 160.0),
+                ($vec3::new(0.3, -0.2, 1.0), 175.0),
+                ($vec3::new(1.0, 2.0, 3.0), 20.0),
+                ($vec3::new(-3.0, 1.0, 2.0), -90.0),
+            ] {
+                let q0 = $quat::from_axis_angle(axis.normalize(), deg(angle));
+                let m3 = $mat3::from_quat(q0);
+                let q1 = $quat::from_mat3(&m3);
+                assert!(q1.is_normalized());
+                // `q` and `-q` represent the same rotation
+                let q1 = if q0.dot(q1) < 0.0 { -q1 } else { q1 };
+                assert_approx_eq!(q0, q1, 1.0e-6);
+                assert_approx_eq!(m3, $mat3::from_quat(q1), 1.0e-6);
+
+                // translation is ignored
+                let m4 = $mat4::from_rotation_translation(q0, $vec3::new(1.0, -2.0, 3.0));
+                let q2 = $quat::from_mat4(&m4);
+                let q2 = if q0.dot(q2) < 0.0 { -q2 } else { q2 };
+                assert_approx_eq!(q0, q2, 1.0e-6);
+            }
+
+            should_glam_assert!({ $quat::from_mat3(&$mat3::from_diagonal($vec3::splat(2.0))) });
+            should_glam_assert!({ $quat::from_mat4(&$mat4::from_scale($vec3::splat(2.0))) });
+        });
+
         glam_test!(test_from_scaled_axis, {
             assert_eq!($quat::from_scaled_axis($vec3::ZERO), $quat::IDENTITY);
             assert_eq!(
@@ -280,8 +348,53 @@ macro_rules! impl_quat_tests {
             assert_approx_eq!(q1, q0.slerp(q1, 1.0), 1.0e-3);
             assert_approx_eq!($quat::from_rotation_y(deg(45.0)), q0.slerp(q1, 0.5), 1.0e-3);
 
-            should_glam_assert!({ $quat::lerp($quat::IDENTITY * 2.0, $quat::IDENTITY, 1.0) });
-            should_glam_assert!({ $quat::lerp($quat::IDENTITY, $quat::IDENTITY * 0.5, 1.0) });
+            should_glam_assert!({ $quat::slerp($quat::IDENTITY * 2.0, $quat::IDENTITY, 1.0) });
+            should_glam_assert!({ $quat::slerp($quat::IDENTITY, $quat::IDENTITY * 0.5, 1.0) });
+        });
+
+        glam_test!(test_slerp_shortest_path, {
+            // rotations of 170 and -170 degrees are 20 degrees apart, the short path passes
+            // through 180 degrees rather than through the identity
+            let q0 = $quat::from_rotation_z(deg(170.0));
+            let q1 = $quat::from_rotation_z(deg(-170.0));
+            assert!(q0.dot(q1) < 0.0);
+            let expected = $quat::from_rotation_z(deg(180.0));
+            for q in [q0.slerp(q1, 0.5), q0.lerp(q1, 0.5)] {
+                assert!(q.is_normalized());
+                assert_approx_eq!(q.dot(expected).abs(), 1.0, 1e-5);
+            }
+            for s in [0.25, 0.75] {
+                let q = q0.slerp(q1, s);
+                assert!(q.is_normalized());
+                let angle = deg(170.0 + 20.0 * s);
+                assert_approx_eq!(q.dot($quat::from_rotation_z(angle)).abs(), 1.0, 1e-5);
+            }
+
+            // the same rotation with a negated quaternion is nearly antipodal, interpolating
+            // should give a rotation close to both rather than passing through the identity
+            let q0 = $quat::from_rotation_x(deg(60.0));
+            let q1 = -$quat::from_rotation_x(deg(60.5));
+            assert!(q0.dot(q1) < -0.99);
+            for s in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                for q in [q0.slerp(q1, s), q0.lerp(q1, s)] {
+                    assert!(q.is_finite());
+                    assert!(q.is_normalized());
+                    let angle = deg(60.0 + 0.5 * s);
+                    assert_approx_eq!(q.dot($quat::from_rotation_x(angle)).abs(), 1.0, 1e-5);
+                }
+            }
+
+            // tiny angles fall back to lerp instead of dividing by a near zero sine
+            let q0 = $quat::from_rotation_y(deg(30.0));
+            let q1 = $quat::from_rotation_y(deg(30.001));
+            let q = q0.slerp(q1, 0.5);
+            assert!(q.is_finite());
+            assert!(q.is_normalized());
+            assert_approx_eq!(q, $quat::from_rotation_y(deg(30.0005)), 1e-6);
+            let q = q0.slerp(-q1, 0.5);
+            assert!(q.is_finite());
+            assert!(q.is_normalized());
+            assert_approx_eq!(q, $quat::from_rotation_y(deg(30.0005)), 1e-6);
         });
 
         glam_test!(test_slerp_constant_speed, {
@@ -329,6 +442,52 @@ macro_rules! impl_quat_tests {
             assert!(s.is_normalized());
         });
 
+        glam_test!(test_rotate_towards, {
+            let q0 = $quat::from_rotation_y(deg(0.0));
+            let q1 = $quat::from_rotation_y(deg(90.0));
+
+            // steps are clamped to max_angle and follow the slerp path
+            let q = q0.rotate_towards(q1, deg(30.0));
+            assert!(q.is_normalized());
+            assert_approx_eq!($quat::from_rotation_y(deg(30.0)), q, 1.0e-4);
+            assert_approx_eq!(q0.angle_between(q), deg(30.0), 1.0e-3);
+
+            // the target is returned exactly once it is within range
+            assert_approx_eq!(q1, q0.rotate_towards(q1, deg(90.0)), 1.0e-6);
+            assert_eq!(q1, q0.rotate_towards(q1, deg(91.0)));
+            assert_eq!(q1, q0.rotate_towards(q1, deg(180.0)));
+            assert_approx_eq!(q1, q1.rotate_towards(q1, 0.0), 1.0e-6);
+
+            // non-positive steps leave the rotation unchanged
+            assert_approx_eq!(q0, q0.rotate_towards(q1, 0.0), 1.0e-6);
+            assert_approx_eq!(q0, q0.rotate_towards(q1, deg(-30.0)), 1.0e-6);
+            // a negative max angle with no rotation left must not divide by zero
+            let q = $quat::from_rotation_y(0.3);
+            assert!(!q.rotate_towards(q, -1.0).is_nan());
+            assert_approx_eq!(q, q.rotate_towards(q, -1.0), 1.0e-6);
+
+            // repeated steps never overshoot and converge on the target
+            let max_angle = deg(7.0);
+            let mut q = q0;
+            for _ in 0..13 {
+                let next = q.rotate_towards(q1, max_angle);
+                assert!(next.is_normalized());
+                assert!(q.angle_between(next) <= max_angle + 1.0e-3);
+                q = next;
+            }
+            assert_eq!(q1, q);
+
+            // takes the shortest path when the dot product is negative
+            let q0 = $quat::from_rotation_z(deg(170.0));
+            let q1 = -$quat::from_rotation_z(deg(-170.0));
+            assert!(q0.dot(q1) > 0.0);
+            let q = q0.rotate_towards(-q1, deg(10.0));
+            assert!(q.is_normalized());
+            assert_approx_eq!(q.dot($quat::from_rotation_z(deg(180.0))).abs(), 1.0, 1e-5);
+
+            should_glam_assert!({ $quat::IDENTITY.rotate_towards($quat::IDENTITY * 2.0, 1.0) });
+        });
+
         glam_test!(test_fmt, {
             let a = $quat::IDENTITY;
             assert_eq!(
@@ -340,6 +499,18 @@ macro_rules! impl_quat_tests {
             //     "$quat(\n    1.0,\n    2.0,\n    3.0,\n    4.0\n)"
             // );
             assert_eq!(format!("{}", a), "[0, 0, 0, 1]");
+            assert_eq!(format!("{:.2}", a), "[0.00, 0.00, 0.00, 1.00]");
+            assert_eq!(format!("{:+4}", -a), "[  -0,   -0,   -0,   -1]");
+        });
+
+        glam_test!(test_from_str, {
+            let a = $quat::from_rotation_y(deg(-30.0));
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok($quat::IDENTITY), " [0, 0.0, 0e0, 1] ".parse());
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                "[0, 0, 1]".parse::<$quat>()
+            );
         });
 
         glam_test!(test_identity, {
@@ -366,6 +537,12 @@ macro_rules! impl_quat_tests {
 
             should_panic!({ $quat::IDENTITY.write_to_slice(&mut [0 as $t; 3]) });
             should_panic!({ $quat::from_slice(&[0 as $t; 3]) });
+
+            // longer slices only use the first 4 elements
+            let mut e = [9.0; 6];
+            b.write_to_slice(&mut e[1..]);
+            assert_eq!([9.0, a[0], a[1], a[2], a[3], 9.0], e);
+            assert_eq!(b, $quat::from_slice(&e[1..]));
         });
 
         glam_test!(test_elements, {
@@ -395,9 +572,24 @@ macro_rules! impl_quat_tests {
             assert_eq!(a - b, $quat::from_xyzw(1.0, 2.0, 3.0, 4.0));
         });
 
+        glam_test!(test_abs_diff_eq, {
+            let a = $quat::from_rotation_y(deg(30.0));
+            let b = $quat::from_xyzw(a.x, a.y + 0.09, a.z, a.w);
+            let c = $quat::from_xyzw(a.x, a.y + 0.11, a.z, a.w);
+            assert!(a.abs_diff_eq(b, 0.1));
+            assert!(!a.abs_diff_eq(c, 0.1));
+            // `-a` is the same rotation but not the same elements
+            assert!(!a.abs_diff_eq(-a, 0.1));
+            assert!(a.rotation_abs_diff_eq(-a, 0.0));
+            assert!(a.rotation_abs_diff_eq(-b, 0.1));
+            assert!(!a.rotation_abs_diff_eq(-c, 0.1));
+            assert!(!a.rotation_abs_diff_eq($quat::IDENTITY, 0.1));
+        });
+
         glam_test!(test_scalar_multiplication, {
             let a = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
             assert_eq!(a * 2.0, $quat::from_xyzw(2.0, 4.0, 6.0, 8.0));
+            assert_eq!(2.0 * a, $quat::from_xyzw(2.0, 4.0, 6.0, 8.0));
         });
 
         glam_test!(test_scalar_division, {
@@ -409,12 +601,25 @@ macro_rules! impl_quat_tests {
             let two = $new(2.0, 2.0, 2.0, 2.0);
             assert_eq!(vec![two, two].iter().sum::<$quat>(), two + two);
             assert_eq!(vec![two, two].into_iter().sum::<$quat>(), two + two);
+
+            let zero = $quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+            assert_eq!(Vec::<$quat>::new().iter().sum::<$quat>(), zero);
+            assert_eq!(Vec::<$quat>::new().into_iter().sum::<$quat>(), zero);
         });
 
         glam_test!(test_product, {
             let two = $new(2.0, 2.0, 2.0, 2.0).normalize();
             assert_eq!(vec![two, two].iter().product::<$quat>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$quat>(), two * two);
+
+            assert_eq!(
+                Vec::<$quat>::new().iter().product::<$quat>(),
+                $quat::IDENTITY
+            );
+            assert_eq!(
+                Vec::<$quat>::new().into_iter().product::<$quat>(),
+                $quat::IDENTITY
+            );
         });
 
         glam_test!(test_is_finite, {
@@ -495,6 +700,16 @@ macro_rules! impl_quat_tests {
                 }
             }
 
+            {
+                // nearly antiparallel, dot is close to but not exactly -1
+                let from = $vec3::X;
+                let to = $vec3::new(-1.0, 1e-4, 0.0).normalize();
+                let q = $quat::from_rotation_arc(from, to);
+                assert!(q.is_finite());
+                assert!(q.is_normalized());
+                assert!((q * from - to).length() < eps);
+            }
+
             should_glam_assert!({ $quat::from_rotation_arc($vec3::ZERO, $vec3::X) });
             should_glam_assert!({ $quat::from_rotation_arc($vec3::X, $vec3::ZERO) });
             should_glam_assert!({ $quat::from_rotation_arc_colinear($vec3::ZERO, $vec3::X) });
@@ -508,6 +723,18 @@ macro_rules! impl_quat_tests {
             assert!($new(1.0, 2.0, 3.0, 4.0).to_array() == [1.0, 2.0, 3.0, 4.0]);
         });
 
+        glam_test!(test_xyzw_order, {
+            let q = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+            assert_eq!($vec4::from(q), $vec4::new(1.0, 2.0, 3.0, 4.0));
+            assert_eq!($quat::from_vec4($vec4::new(1.0, 2.0, 3.0, 4.0)).w, 4.0);
+            assert_eq!($quat::from_array([1.0, 2.0, 3.0, 4.0]), q);
+
+            let mut a = [0.0; 5];
+            q.write_to_slice(&mut a[1..]);
+            assert_eq!(a, [0.0, 1.0, 2.0, 3.0, 4.0]);
+            assert_eq!($quat::from_slice(&a[1..]), q);
+        });
+
         glam_test!(test_to_axis_angle, {
             {
                 let q = $quat::from_xyzw(
@@ -547,6 +774,45 @@ macro_rules! impl_quat_tests {
                 assert_approx_eq!(axis, axis2);
                 assert_approx_eq!(angle, angle2);
             }
+            {
+                // round trip through from_axis_angle for a range of axes and angles
+                for axis in [
+                    $vec3::X,
+                    $vec3::NEG_Y,
+                    $vec3::new(1.0, 2.0, 3.0).normalize(),
+                    $vec3::new(-3.0, 1.0, -2.0).normalize(),
+                ] {
+                    for angle in [deg(1.0), deg(45.0), deg(90.0), deg(135.0), deg(179.0)] {
+                        let q = $quat::from_axis_angle(axis, angle);
+                        let (axis2, angle2) = q.to_axis_angle();
+                        assert!(axis2.is_normalized());
+                        assert_approx_eq!(axis, axis2, 1e-5);
+                        assert_approx_eq!(angle, angle2, 1e-5);
+                    }
+                }
+            }
+            {
+                // angles greater than PI are returned as a rotation about the negated axis
+                let q = $quat::from_axis_angle($vec3::Y, deg(270.0));
+                let (axis, angle) = q.to_axis_angle();
+                assert_approx_eq!(axis, $vec3::NEG_Y, 1e-6);
+                assert_approx_eq!(angle, deg(90.0), 1e-6);
+                assert_approx_eq!(q, -$quat::from_axis_angle(axis, angle), 1e-6);
+                // the same rotation with a negated quaternion gives the same result
+                let (axis2, angle2) = (-q).to_axis_angle();
+                assert_eq!(axis, axis2);
+                assert_eq!(angle, angle2);
+            }
+            {
+                // angles near zero return a stable default instead of NaN
+                let q = $quat::from_axis_angle($vec3::Z, 1e-9);
+                let (axis, angle) = q.to_axis_angle();
+                assert_eq!(axis, $vec3::X);
+                assert_eq!(angle, 0.0);
+                let (axis, angle) = (-$quat::IDENTITY).to_axis_angle();
+                assert_eq!(axis, $vec3::X);
+                assert_eq!(angle, 0.0);
+            }
         });
     };
 }
@@ -566,6 +832,26 @@ mod quat {
         }
     });
 
+    glam_test!(test_mul_vec3a_matches_vec3, {
+        for (i, axis) in [Vec3::X, Vec3::Y, Vec3::Z, Vec3::ONE.normalize()]
+            .into_iter()
+            .enumerate()
+        {
+            for angle in [-170.0, -45.0, 0.0, 30.0, 90.0, 180.0] {
+                let q = Quat::from_axis_angle(axis, deg(angle));
+                for v in [
+                    Vec3::X,
+                    Vec3::new(1.0, -2.0, 3.0),
+                    Vec3::splat(i as f32 - 1.5),
+                ] {
+                    let expected = Vec3A::from(q * v);
+                    assert_approx_eq!(expected, q * Vec3A::from(v), 1e-5);
+                    assert_approx_eq!(expected, q.mul_vec3a(Vec3A::from(v)), 1e-5);
+                }
+            }
+        }
+    });
+
     glam_test!(test_mul_vec3a, {
         let qrz = Quat::from_rotation_z(deg(90.0));
         assert_approx_eq!(Vec3A::Y, qrz * Vec3A::X);
@@ -647,6 +933,20 @@ mod quat {
             Quat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0),
             DQuat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0).as_f32()
         );
+        assert_eq!(
+            Quat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0).as_f64(),
+            Quat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0).as_dquat()
+        );
+        assert_eq!(
+            DQuat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0).as_f32(),
+            DQuat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0).as_quat()
+        );
+
+        // every f32 survives the round trip through f64
+        let q = Quat::from_euler(EulerRot::YXZ, 1.0, 2.0, 3.0);
+        assert_eq!(q, q.as_dquat().as_quat());
+        let q = DQuat::from_xyzw(0.5, -0.5, 0.5, -0.5);
+        assert_eq!(q, q.as_quat().as_dquat());
     });
 
     impl_quat_tests!(f32, quat, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
@@ -112,6 +112,14 @@ macro_rules! impl_vec_float_normalize_tests {
             assert_eq!(from_x_y(0.0, 0.0).try_normalize(), None);
             assert_eq!(from_x_y(MIN_POSITIVE, 0.0).try_normalize(), None);
 
+            // We expect `try_normalize` to handle subnormal inputs where `length_squared()`
+            // underflows to zero:
+            assert_eq!(from_x_y(MIN_POSITIVE / 2.0, 0.0).try_normalize(), None);
+            assert_eq!(
+                from_x_y(MIN_POSITIVE / 2.0, MIN_POSITIVE / 2.0).try_normalize(),
+                None
+            );
+
             // We expect `try_normalize` to return None when inputs are non-finite:
             assert_eq!(from_x_y(INFINITY, 0.0).try_normalize(), None);
             assert_eq!(from_x_y(NAN, 0.0).try_normalize(), None);
@@ -135,6 +143,24 @@ macro_rules! impl_vec_float_normalize_tests {
             assert_eq!(from_x_y(0.0, 0.0).normalize_or_zero(), $vec::ZERO);
             assert_eq!(from_x_y(MIN_POSITIVE, 0.0).normalize_or_zero(), $vec::ZERO);
 
+            // We expect `normalize_or_zero` to handle subnormal inputs where `length_squared()`
+            // underflows to zero:
+            assert_eq!(
+                from_x_y(MIN_POSITIVE / 2.0, 0.0).normalize_or_zero(),
+                $vec::ZERO
+            );
+            assert_eq!(
+                from_x_y(MIN_POSITIVE / 2.0, MIN_POSITIVE / 2.0).normalize_or_zero(),
+                $vec::ZERO
+            );
+
+            // We expect small inputs to still normalize when `length_squared()` is subnormal
+            // rather than zero:
+            let tiny = from_x_y(MIN_POSITIVE.sqrt() * 0.5, MIN_POSITIVE.sqrt() * 0.5);
+            assert!(tiny.length_squared() < MIN_POSITIVE && tiny.length_squared() > 0.0);
+            assert!(tiny.normalize_or_zero().is_normalized());
+            assert_eq!(tiny.try_normalize(), Some(tiny.normalize_or_zero()));
+
             // We expect `normalize_or_zero` to return zero when inputs are non-finite:
             assert_eq!(from_x_y(INFINITY, 0.0).normalize_or_zero(), $vec::ZERO);
             assert_eq!(from_x_y(NAN, 0.0).normalize_or_zero(), $vec::ZERO);
@@ -143,6 +169,33 @@ macro_rules! impl_vec_float_normalize_tests {
             assert_eq!(from_x_y(MAX, 0.0).normalize_or_zero(), $vec::ZERO);
             assert_eq!(from_x_y(MAX, MAX).normalize_or_zero(), $vec::ZERO);
         });
+
+        glam_test!(test_normalize_or, {
+            let fallback = from_x_y(0.0, 1.0);
+            assert_eq!(
+                from_x_y(-42.0, 0.0).normalize_or(fallback),
+                from_x_y(-1.0, 0.0)
+            );
+            assert_eq!(
+                from_x_y(MAX.sqrt(), 0.0).normalize_or(fallback),
+                from_x_y(1.0, 0.0)
+            );
+
+            // We expect `normalize_or` to return the fallback in the same cases that
+            // `try_normalize` returns `None`:
+            for v in [
+                from_x_y(0.0, 0.0),
+                from_x_y(MIN_POSITIVE, 0.0),
+                from_x_y(MIN_POSITIVE / 2.0, MIN_POSITIVE / 2.0),
+                from_x_y(INFINITY, 0.0),
+                from_x_y(NAN, 0.0),
+                from_x_y(MAX, MAX),
+            ] {
+                assert_eq!(v.try_normalize(), None);
+                assert_eq!(v.normalize_or(fallback), fallback);
+                assert_eq!(v.normalize_or_zero(), $vec::ZERO);
+            }
+        });
     };
 }
 
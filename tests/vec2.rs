@@ -14,11 +14,31 @@ macro_rules! impl_vec2_tests {
             assert_eq!([1 as $t, 2 as $t], *V2.as_ref());
         });
 
+        glam_test!(test_as_ref_aliases, {
+            let mut v = $new(1 as $t, 2 as $t);
+            let p = v.as_ref().as_ptr();
+            assert_eq!(p, &v.x as *const $t);
+            assert_eq!(p, v.as_mut().as_mut_ptr() as *const $t);
+            v.as_mut()[1] = 5 as $t;
+            assert_eq!(5 as $t, v.y);
+            assert_eq!(v.to_array(), *v.as_ref());
+            assert_eq!(v, $vec2::from_array(*v.as_ref()));
+        });
+
         glam_test!(test_vec2_consts, {
             assert_eq!($vec2::ZERO, $new(0 as $t, 0 as $t));
             assert_eq!($vec2::ONE, $new(1 as $t, 1 as $t));
             assert_eq!($vec2::X, $new(1 as $t, 0 as $t));
             assert_eq!($vec2::Y, $new(0 as $t, 1 as $t));
+            assert_eq!([$vec2::X, $vec2::Y], $vec2::AXES);
+
+            // associated constants are usable in const contexts
+            const AXES: [$vec2; 2] = $vec2::AXES;
+            const ONE: $vec2 = $vec2::ONE;
+            assert_eq!(AXES, $vec2::AXES);
+            assert_eq!(ONE, $vec2::splat(1 as $t));
+            assert_eq!($vec2::MIN, $vec2::splat(<$t>::MIN));
+            assert_eq!($vec2::MAX, $vec2::splat(<$t>::MAX));
         });
 
         glam_test!(test_new, {
@@ -66,6 +86,26 @@ macro_rules! impl_vec2_tests {
                 )
             );
             assert_eq!(format!("{}", a), "[1, 2]");
+            // flags apply to every element
+            assert_eq!(format!("{:+}", a), "[+1, +2]");
+            assert_eq!(format!("{:3}", a), "[  1,   2]");
+            assert_eq!(format!("{:_<3}", a), "[1__, 2__]");
+        });
+
+        glam_test!(test_from_str, {
+            let a = $new(1 as $t, 2 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), " [ 1 ,2 ] ".parse());
+            assert_eq!(Err(glam::ParseError::MissingBrackets), "1".parse::<$vec2>());
+            assert_eq!(Err(glam::ParseError::WrongLength), "[1]".parse::<$vec2>());
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                "[1, 2, 3]".parse::<$vec2>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidElement),
+                "[1, x]".parse::<$vec2>()
+            );
         });
 
         glam_test!(test_zero, {
@@ -95,6 +135,14 @@ macro_rules! impl_vec2_tests {
             assert_eq!($vec2::new(1 as $t, 2 as $t), a);
         });
 
+        glam_test!(test_index_out_of_bounds, {
+            should_panic!({ $vec2::ZERO[2] });
+            should_panic!({
+                let mut a = $vec2::ZERO;
+                a[2] = 1 as $t;
+            });
+        });
+
         glam_test!(test_dot_unsigned, {
             let x = $new(1 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t);
@@ -186,6 +234,7 @@ macro_rules! impl_vec2_tests {
             assert_eq!(vec(6, 6).clamp(min, max), vec(6, 6));
             assert_eq!(vec(7, 7).clamp(min, max), vec(6, 7));
             assert_eq!(vec(9, 9).clamp(min, max), vec(6, 8));
+            assert_eq!(vec(0, 9).clamp(vec(4, 4), vec(4, 4)), vec(4, 4));
 
             should_glam_assert!({ $vec2::clamp($vec2::ZERO, $vec2::ONE, $vec2::ZERO) });
         });
@@ -197,6 +246,39 @@ macro_rules! impl_vec2_tests {
             assert_eq!(2 as $t, $new(2 as $t, 1 as $t).max_element());
         });
 
+        glam_test!(test_map_from_fn, {
+            let v = $new(1 as $t, 2 as $t);
+            assert_eq!($new(2 as $t, 4 as $t), v.map(|x| x * (2 as $t)));
+            assert_eq!(v, v.map(|x| x));
+            assert_eq!(v, $vec2::from_fn(|i| (i + 1) as $t));
+            assert_eq!(v, $vec2::from_fn(|i| v[i]));
+        });
+
+        glam_test!(test_min_max_position, {
+            for i in 0..2 {
+                let mut a = [2 as $t; 2];
+                a[i] = 1 as $t;
+                assert_eq!(i, $vec2::from_array(a).min_position());
+                a[i] = 3 as $t;
+                assert_eq!(i, $vec2::from_array(a).max_position());
+            }
+            // ties return the lowest index
+            assert_eq!(0, $vec2::ONE.min_position());
+            assert_eq!(0, $vec2::ONE.max_position());
+            assert_eq!(0, $new(1 as $t, 1 as $t).min_position());
+            assert_eq!(0, $new(1 as $t, 1 as $t).max_position());
+        });
+
+        glam_test!(test_element_sum_product, {
+            let v = $new(2 as $t, 3 as $t);
+            assert_eq!(5 as $t, v.element_sum());
+            assert_eq!(6 as $t, v.element_product());
+            assert_eq!(0 as $t, $vec2::ZERO.element_sum());
+            assert_eq!(0 as $t, $vec2::ZERO.element_product());
+            assert_eq!(2 as $t, $vec2::ONE.element_sum());
+            assert_eq!(1 as $t, $vec2::ONE.element_product());
+        });
+
         glam_test!(test_eq, {
             let a = $new(1 as $t, 1 as $t);
             let b = $new(1 as $t, 2 as $t);
@@ -236,6 +318,12 @@ macro_rules! impl_vec2_tests {
             let a = $new(1 as $t, 2 as $t);
             let b = a.extend(3 as $t);
             assert_eq!($vec3::new(1 as $t, 2 as $t, 3 as $t), b);
+            // the new element is placed in the z lane and the others are preserved
+            assert_eq!(b.x, a.x);
+            assert_eq!(b.y, a.y);
+            assert_eq!(b.z, 3 as $t);
+            assert_eq!(a, b.truncate());
+            assert_eq!($vec2::ZERO.extend(1 as $t), $vec3::Z);
         });
 
         glam_test!(test_vec2mask, {
@@ -453,18 +541,35 @@ macro_rules! impl_vec2_tests {
 
             should_panic!({ $vec2::ONE.write_to_slice(&mut [0 as $t]) });
             should_panic!({ $vec2::from_slice(&[0 as $t]) });
+
+            // longer slices, e.g. interleaved vertex data, only use the first 2 elements
+            let mut b = [9 as $t; 4];
+            v.write_to_slice(&mut b[1..]);
+            assert_eq!([9 as $t, 1 as $t, 2 as $t, 9 as $t], b);
+            assert_eq!(v, $vec2::from_slice(&b[1..]));
         });
 
         glam_test!(test_sum, {
             let one = $vec2::ONE;
             assert_eq!(vec![one, one].iter().sum::<$vec2>(), one + one);
             assert_eq!(vec![one, one].into_iter().sum::<$vec2>(), one + one);
+
+            // an empty iterator sums to zero
+            assert_eq!(Vec::<$vec2>::new().iter().sum::<$vec2>(), $vec2::ZERO);
+            assert_eq!(Vec::<$vec2>::new().into_iter().sum::<$vec2>(), $vec2::ZERO);
         });
 
         glam_test!(test_product, {
             let two = $vec2::new(2 as $t, 2 as $t);
             assert_eq!(vec![two, two].iter().product::<$vec2>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$vec2>(), two * two);
+
+            // an empty iterator has a product of one
+            assert_eq!(Vec::<$vec2>::new().iter().product::<$vec2>(), $vec2::ONE);
+            assert_eq!(
+                Vec::<$vec2>::new().into_iter().product::<$vec2>(),
+                $vec2::ONE
+            );
         });
     };
 }
@@ -473,6 +578,77 @@ macro_rules! impl_vec2_signed_tests {
     ($t:ident, $new:ident, $vec2:ident, $vec3:ident, $mask:ident) => {
         impl_vec2_tests!($t, $new, $vec2, $vec3, $mask);
 
+        glam_test!(test_vec2_consts_signed, {
+            assert_eq!($vec2::NEG_ONE, $new(-1 as $t, -1 as $t));
+            assert_eq!($vec2::NEG_ONE, -$vec2::ONE);
+            assert_eq!($vec2::NEG_X, $new(-1 as $t, 0 as $t));
+            assert_eq!($vec2::NEG_Y, $new(0 as $t, -1 as $t));
+            assert_eq!($vec2::NEG_X, -$vec2::X);
+            assert_eq!($vec2::NEG_Y, -$vec2::Y);
+            assert_eq!($vec2::AXES.iter().sum::<$vec2>(), $vec2::ONE);
+        });
+
+        glam_test!(test_from_str_signed, {
+            let a = $new(-1 as $t, 2 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+        });
+
+        glam_test!(test_rem_truncated, {
+            // the remainder has the same sign as the dividend, like the scalar `%` operator
+            let a = $new(-7 as $t, 7 as $t);
+            let b = $new(4 as $t, -4 as $t);
+            assert_eq!($new(-3 as $t, 3 as $t), a % b);
+            assert_eq!($new(-3 as $t, 3 as $t), a % 4 as $t);
+            assert_eq!($new(-3 as $t, 3 as $t), a % -4 as $t);
+            let mut c = a;
+            c %= b;
+            assert_eq!($new(-3 as $t, 3 as $t), c);
+            c = a;
+            c %= 4 as $t;
+            assert_eq!($new(-3 as $t, 3 as $t), c);
+            assert_eq!(
+                $new((-7 as $t) % (4 as $t), (-7 as $t) % (-4 as $t)),
+                -7 as $t % b
+            );
+        });
+
+        glam_test!(test_div_rem_euclid_signed, {
+            // unlike `%`, the remainder is never negative, whatever the signs of the operands
+            let a = $new(-7 as $t, 7 as $t);
+            let b = $new(4 as $t, -4 as $t);
+            assert_eq!($new(1 as $t, 3 as $t), a.rem_euclid(b));
+            assert_eq!($new(-2 as $t, -1 as $t), a.div_euclid(b));
+            assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b));
+            assert!(a.rem_euclid(b).cmpge($vec2::ZERO).all());
+            // wrapping coordinates into a grid of 4 wide tiles
+            assert_eq!(
+                $new(3 as $t, 3 as $t),
+                $new(-1 as $t, -5 as $t).rem_euclid($vec2::splat(4 as $t))
+            );
+        });
+
+        glam_test!(test_clamp_signed, {
+            fn vec(x: i32, y: i32) -> $vec2 {
+                $vec2::new(x as $t, y as $t)
+            }
+            let min = vec(-8, -3);
+            let max = vec(-1, 6);
+            assert_eq!(vec(-9, -9).clamp(min, max), vec(-8, -3));
+            assert_eq!(vec(-4, 0).clamp(min, max), vec(-4, 0));
+            assert_eq!(vec(9, 9).clamp(min, max), vec(-1, 6));
+            assert_eq!(vec(-4, -4).min_element(), -4 as $t);
+            assert_eq!(vec(-4, -2).max_element(), -2 as $t);
+        });
+
+        glam_test!(test_copysign_signed, {
+            let a = $new(-3 as $t, 2 as $t);
+            assert_eq!(a.copysign($new(5 as $t, -1 as $t)), $new(3 as $t, -2 as $t));
+            assert_eq!(a.copysign($vec2::ONE), a.abs());
+            assert_eq!(a.copysign($vec2::NEG_ONE), -a.abs());
+            // zero is treated as positive
+            assert_eq!(a.copysign($vec2::ZERO), a.abs());
+        });
+
         glam_test!(test_dot_signed, {
             let x = $new(1 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t);
@@ -505,6 +681,30 @@ macro_rules! impl_vec2_signed_tests {
             assert_eq!(v1.perp().dot(v1), 0 as $t);
             assert_eq!(v2.perp().dot(v2), 0 as $t);
             assert_eq!(v1.perp().dot(v2), v1.perp_dot(v2));
+
+            // perp rotates counter-clockwise
+            assert_eq!($vec2::X.perp(), $vec2::Y);
+            assert_eq!($vec2::Y.perp(), $vec2::NEG_X);
+            assert_eq!($vec2::X.perp().perp(), $vec2::NEG_X);
+
+            // perp_dot is positive when rhs is counter-clockwise from self
+            assert_eq!($vec2::X.perp_dot($vec2::Y), 1 as $t);
+            assert_eq!($vec2::Y.perp_dot($vec2::X), -1 as $t);
+            assert_eq!(v1.perp_dot(v1), 0 as $t);
+            // and is the signed area of the parallelogram spanned by self and rhs
+            assert_eq!(
+                $vec2::new(3 as $t, 0 as $t).perp_dot($vec2::new(1 as $t, 2 as $t)),
+                6 as $t
+            );
+
+            // winding of a triangle
+            let a = $vec2::new(0 as $t, 0 as $t);
+            let b = $vec2::new(4 as $t, 0 as $t);
+            let c = $vec2::new(1 as $t, 3 as $t);
+            assert!((b - a).perp_dot(c - a) > 0 as $t, "counter-clockwise");
+            assert!((c - a).perp_dot(b - a) < 0 as $t, "clockwise");
+            assert_eq!((b - a).perp_dot(c - a), (c - b).perp_dot(a - b));
+            assert_eq!((b - a).perp_dot(b - a), 0 as $t, "collinear");
         });
 
         glam_test!(test_rotate, {
@@ -544,12 +744,45 @@ macro_rules! impl_vec2_eq_hash_tests {
             assert_ne!(a, c);
             assert_ne!(a_hashed, c_hashed);
         });
+
+        glam_test!(test_ord, {
+            use core::cmp::Ordering;
+            let a = $new(1 as $t, 2 as $t);
+            assert_eq!(Ordering::Equal, a.cmp(&a));
+            // earlier components take precedence, later ones only break ties
+            assert!(a < $new(2 as $t, 0 as $t));
+            assert!(a > $new(0 as $t, 9 as $t));
+            assert!(a < $new(1 as $t, 5 as $t));
+            assert!(a > $new(1 as $t, 0 as $t));
+            assert_eq!(Some(Ordering::Less), a.partial_cmp(&$new(1 as $t, 3 as $t)));
+
+            // `Ord` is lexicographic while the inherent `max` is component-wise
+            let (a, b) = ($new(1 as $t, 5 as $t), $new(2 as $t, 0 as $t));
+            assert_eq!(core::cmp::max(a, b), b);
+            assert_eq!(a.max(b), $new(2 as $t, 5 as $t));
+            assert_ne!(core::cmp::max(a, b), a.max(b));
+        });
     };
 }
 
 macro_rules! impl_vec2_float_tests {
     ($t:ident, $new:ident, $vec2:ident, $vec3:ident, $mask:ident) => {
         impl_vec2_signed_tests!($t, $new, $vec2, $vec3, $mask);
+
+        glam_test!(test_fmt_float, {
+            let a = $new(1.0, -2.5);
+            assert_eq!(format!("{:.2}", a), "[1.00, -2.50]");
+            assert_eq!(format!("{:+5.1}", a), "[ +1.0,  -2.5]");
+        });
+
+        glam_test!(test_from_str_float, {
+            let a = $new(-1.5e10, 0.25);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), "[-1.5e10, 2.5E-1]".parse());
+            assert_eq!(Ok($vec2::INFINITY), format!("{}", $vec2::INFINITY).parse());
+            assert!(format!("{}", $vec2::NAN).parse::<$vec2>().unwrap().is_nan());
+        });
+
         impl_vec_float_normalize_tests!($t, $vec2);
 
         use core::$t::INFINITY;
@@ -588,6 +821,21 @@ macro_rules! impl_vec2_float_tests {
             assert_eq!($new(0.5, 0.25), $new(2.0, 4.0).recip());
         });
 
+        glam_test!(test_recip_precision, {
+            // these use a full precision division rather than an approximation
+            let v = $new(3.0, 7.0);
+            assert_eq!(v.recip(), $new(1.0 / 3.0, 1.0 / 7.0));
+            assert_eq!(v.length_recip(), 1.0 / v.length());
+            assert_eq!($vec2::ZERO.recip(), $vec2::splat(INFINITY));
+            assert_eq!((-$vec2::ZERO).recip(), $vec2::splat(NEG_INFINITY));
+            assert_eq!($vec2::splat(INFINITY).recip(), $vec2::ZERO);
+            assert!($vec2::NAN.recip().is_nan_mask().all());
+            // multiplying by the reciprocal is close to, but not always the same as, dividing
+            let a = $vec2::splat(10.0);
+            let b = $vec2::splat(3.0);
+            assert_approx_eq!(a / b, a * b.recip(), 1e-6);
+        });
+
         glam_test!(test_project_reject, {
             assert_eq!($new(0.0, 1.0), $new(1.0, 1.0).project_onto($new(0.0, 2.0)));
             assert_eq!($new(1.0, 0.0), $new(1.0, 1.0).reject_from($new(0.0, 2.0)));
@@ -599,12 +847,76 @@ macro_rules! impl_vec2_float_tests {
                 $new(1.0, 0.0),
                 $new(1.0, 1.0).reject_from_normalized($new(0.0, 1.0))
             );
+            // the projection and rejection sum to the original vector
+            let v = $new(3.0, -2.0);
+            let n = $new(1.5, 4.0);
+            assert_approx_eq!(v.project_onto(n) + v.reject_from(n), v, 1e-6);
+            assert_approx_eq!(v.reject_from(n).dot(n), 0.0, 1e-5);
+            let n = n.normalize();
+            assert_approx_eq!(
+                v.project_onto_normalized(n) + v.reject_from_normalized(n),
+                v,
+                1e-6
+            );
             should_glam_assert!({ $vec2::ONE.project_onto($vec2::ZERO) });
             should_glam_assert!({ $vec2::ONE.reject_from($vec2::ZERO) });
             should_glam_assert!({ $vec2::ONE.project_onto_normalized($vec2::ONE) });
             should_glam_assert!({ $vec2::ONE.reject_from_normalized($vec2::ONE) });
         });
 
+        glam_test!(test_reflect, {
+            let normal = $vec2::Y;
+            // 45 degree reflection off a horizontal surface
+            assert_approx_eq!(
+                $vec2::from_angle(-core::$t::consts::FRAC_PI_4).reflect(normal),
+                $vec2::from_angle(core::$t::consts::FRAC_PI_4)
+            );
+            assert_approx_eq!(
+                $new(1.0, -1.0).normalize().reflect(normal),
+                $new(1.0, 1.0).normalize()
+            );
+            // head on incidence is reversed
+            assert_eq!($new(0.0, -1.0).reflect(normal), $new(0.0, 1.0));
+            // grazing incidence is unchanged
+            assert_eq!($vec2::X.reflect(normal), $vec2::X);
+            assert_eq!((-$vec2::X).reflect(normal), -$vec2::X);
+            should_glam_assert!({ $vec2::X.reflect($vec2::ONE) });
+        });
+
+        glam_test!(test_refract, {
+            let normal = $vec2::Y;
+            // head on incidence is not bent
+            assert_approx_eq!($new(0.0, -1.0).refract(normal, 1.5), $new(0.0, -1.0));
+            // matching indices of refraction leave the direction unchanged
+            let incident = $new(1.0, -1.0).normalize();
+            assert_approx_eq!(incident.refract(normal, 1.0), incident);
+            // Snell's law, the tangential component is scaled by eta
+            let eta = 1.0 / 1.5;
+            let sin_t = eta * incident.x;
+            assert_approx_eq!(
+                incident.refract(normal, eta),
+                $new(sin_t, -(1.0 - sin_t * sin_t).sqrt()),
+                1e-6
+            );
+            // grazing incidence into a denser medium bends towards the normal
+            assert_approx_eq!(
+                $vec2::X.refract(normal, 0.5),
+                $new(0.5, -(0.75 as $t).sqrt()),
+                1e-6
+            );
+            // grazing incidence into a less dense medium is totally internally reflected
+            assert_eq!($vec2::X.refract(normal, 2.0), $vec2::ZERO);
+            // the critical angle for eta = 2.0 is where the sine of the incident angle is 0.5
+            let below = $new(0.49, -(1.0 - 0.49 * 0.49 as $t).sqrt());
+            let t = below.refract(normal, 2.0);
+            assert!(t.is_normalized());
+            assert!(t.y < 0.0);
+            let above = $new(0.51, -(1.0 - 0.51 * 0.51 as $t).sqrt());
+            assert_eq!(above.refract(normal, 2.0), $vec2::ZERO);
+            should_glam_assert!({ $vec2::X.refract($vec2::ONE, 1.0) });
+            should_glam_assert!({ $vec2::ONE.refract(normal, 1.0) });
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec2::ZERO.signum(), $vec2::ONE);
             assert_eq!((-$vec2::ZERO).signum(), -$vec2::ONE);
@@ -616,6 +928,16 @@ macro_rules! impl_vec2_float_tests {
         });
 
         glam_test!(test_copysign, {
+            // the sign of zero is preserved, `==` doesn't distinguish between -0.0 and 0.0
+            assert_eq!(
+                $vec2::ZERO.copysign(-$vec2::ONE).is_negative_bitmask(),
+                0b11
+            );
+            assert_eq!((-$vec2::ZERO).copysign($vec2::ONE).is_negative_bitmask(), 0);
+            // only the sign bit of rhs is used, and NAN magnitudes remain NAN
+            assert_eq!($vec2::ONE.copysign(-$vec2::NAN), -$vec2::ONE);
+            assert_eq!((-$vec2::ONE).copysign($vec2::NAN), $vec2::ONE);
+            assert!($vec2::NAN.copysign(-$vec2::ONE).is_nan_mask().all());
             assert_eq!($vec2::ZERO.copysign(-$vec2::ZERO), -$vec2::ZERO);
             assert_eq!((-$vec2::ZERO).copysign(-$vec2::ZERO), -$vec2::ZERO);
             assert_eq!($vec2::ZERO.copysign($vec2::ZERO), $vec2::ZERO);
@@ -652,6 +974,15 @@ macro_rules! impl_vec2_float_tests {
             assert!($vec2::splat(NAN).copysign(-$vec2::ONE).is_nan_mask().all());
         });
 
+        glam_test!(test_copysign_restores_sign, {
+            // restoring the sign after working with the magnitude is exact, including for -0.0
+            let bits = |v: $vec2| v.to_array().map(|x| x.to_bits());
+            let v = $vec2::from_fn(|i| [-0.0, 0.0, -2.5, 3.0][i]);
+            assert_eq!(bits(v), bits(v.abs().copysign(v)));
+            assert_eq!(bits(v), bits(v.abs() * v.signum()));
+            assert_eq!($vec2::from_fn(|i| [-1.0, 1.0, -1.0, 1.0][i]), v.signum());
+        });
+
         glam_test!(test_is_negative_bitmask, {
             assert_eq!($vec2::ZERO.is_negative_bitmask(), 0b00);
             assert_eq!((-$vec2::ZERO).is_negative_bitmask(), 0b11);
@@ -682,6 +1013,12 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(NEG_INFINITY, INFINITY)
             );
             assert!($vec2::new(NAN, 0.0).round().x.is_nan());
+            // half-way cases round away from zero and the sign of zero is kept
+            assert_eq!($vec2::splat(2.5).round(), $vec2::splat(3.0));
+            assert_eq!($vec2::splat(-2.5).round(), $vec2::splat(-3.0));
+            assert_eq!($vec2::splat(0.49999997).round(), $vec2::ZERO);
+            assert_eq!($vec2::splat(8388609.0).round(), $vec2::splat(8388609.0));
+            assert_eq!($vec2::splat(-0.25).round().is_negative_bitmask(), 0b11);
         });
 
         glam_test!(test_floor, {
@@ -697,6 +1034,34 @@ macro_rules! impl_vec2_float_tests {
             );
         });
 
+        glam_test!(test_trunc, {
+            assert_eq!($vec2::new(1.5, -1.5).trunc(), $vec2::new(1.0, -1.0));
+            assert_eq!($vec2::splat(INFINITY).trunc(), $vec2::splat(INFINITY));
+            assert_eq!(
+                $vec2::splat(NEG_INFINITY).trunc(),
+                $vec2::splat(NEG_INFINITY)
+            );
+            assert!($vec2::NAN.trunc().is_nan_mask().all());
+            assert_eq!($vec2::splat(-2000000.123).trunc(), $vec2::splat(-2000000.0));
+            // the sign of zero is kept
+            assert_eq!($vec2::splat(-0.5).trunc().is_negative_bitmask(), 0b11);
+        });
+
+        glam_test!(test_floor_round_ceil_to_ivec, {
+            use glam::{IVec2, UVec2};
+            let v = $vec2::new(-1.5, 2.5);
+            // `as` truncates towards zero, which is wrong for negative grid coordinates
+            assert_eq!(IVec2::new(-1, 2), v.as_ivec2());
+            assert_eq!(IVec2::new(-2, 2), v.floor_to_ivec());
+            assert_eq!(IVec2::new(-2, 3), v.round_to_ivec());
+            assert_eq!(IVec2::new(-1, 3), v.ceil_to_ivec());
+            // out of range elements saturate and NAN becomes zero
+            assert_eq!(IVec2::MIN, $vec2::splat(-1e20).floor_to_ivec());
+            assert_eq!(IVec2::MAX, $vec2::splat(1e20).ceil_to_ivec());
+            assert_eq!(IVec2::ZERO, $vec2::NAN.round_to_ivec());
+            assert_eq!(UVec2::ZERO, $vec2::splat(-1.5).as_uvec2());
+        });
+
         glam_test!(test_fract, {
             assert_approx_eq!($vec2::new(1.35, -1.5).fract(), $vec2::new(0.35, 0.5));
             assert_approx_eq!(
@@ -704,6 +1069,8 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(0.877, 0.123),
                 0.002
             );
+            // negative elements result in a positive fraction, unlike the scalar `fract`
+            assert_eq!($vec2::splat(-0.25).fract(), $vec2::splat(0.75));
         });
 
         glam_test!(test_ceil, {
@@ -727,6 +1094,76 @@ macro_rules! impl_vec2_float_tests {
             assert_approx_eq!($vec2::ZERO, v0.lerp(v1, 0.5));
         });
 
+        glam_test!(test_smooth_lerp, {
+            let a = $vec2::new(1.0, 2.0);
+            let b = $vec2::new(5.0, -2.0);
+            assert_eq!(a, a.smooth_lerp(b, 0.0));
+            assert_eq!(b, a.smooth_lerp(b, 1.0));
+            assert_approx_eq!(a.midpoint(b), a.smooth_lerp(b, 0.5));
+            // eased by 3t^2 - 2t^3
+            assert_approx_eq!(a.lerp(b, 0.15625), a.smooth_lerp(b, 0.25));
+            assert_approx_eq!(a.lerp(b, 0.84375), a.smooth_lerp(b, 0.75));
+            // `s` is clamped
+            assert_eq!(a, a.smooth_lerp(b, -1.0));
+            assert_eq!(b, a.smooth_lerp(b, 2.0));
+        });
+
+        glam_test!(test_move_towards, {
+            // the distance between v0 and v1 is 5.0
+            let v0 = $vec2::new(1.0, 2.0);
+            let v1 = $vec2::new(4.0, 6.0);
+            assert_approx_eq!(v0.move_towards(v1, 3.0), $vec2::new(2.8, 4.4), 1e-6);
+            assert_eq!(v0.move_towards(v1, 5.0), v1);
+            // doesn't overshoot
+            assert_eq!(v0.move_towards(v1, 10.0), v1);
+            // zero or negative max_delta doesn't move
+            assert_eq!(v0.move_towards(v1, 0.0), v0);
+            assert_eq!(v0.move_towards(v1, -1.0), v0);
+            // no NaN when already at the target
+            assert_eq!(v0.move_towards(v0, 1.0), v0);
+            assert_eq!($vec2::ZERO.move_towards($vec2::ZERO, 1.0), $vec2::ZERO);
+        });
+
+        glam_test!(test_midpoint, {
+            let v0 = $vec2::new(1.0, 2.0);
+            let v1 = $vec2::new(3.0, 6.0);
+            assert_eq!(v0.midpoint(v1), $vec2::new(2.0, 4.0));
+            assert_eq!(v0.midpoint(v1), v0.lerp(v1, 0.5));
+            assert_eq!(v0.midpoint(v1), v1.midpoint(v0));
+            assert_eq!(v0.midpoint(v0), v0);
+        });
+
+        glam_test!(test_abs_diff_eq, {
+            let a = $vec2::ONE;
+            assert!(a.abs_diff_eq(a, 0.0));
+            // just under, at and just over the threshold
+            assert!(a.abs_diff_eq(a + $vec2::X * 0.09, 0.1));
+            assert!(a.abs_diff_eq(a - $vec2::Y * 0.5, 0.5));
+            assert!(!a.abs_diff_eq(a + $vec2::X * 0.11, 0.1));
+            assert!(!a.abs_diff_eq(a - $vec2::Y * 0.11, 0.1));
+            assert!(!a.abs_diff_eq($vec2::NAN, 1.0));
+        });
+
+        glam_test!(test_float_consts, {
+            assert_eq!($vec2::INFINITY, $vec2::splat(<$t>::INFINITY));
+            assert_eq!($vec2::NEG_INFINITY, $vec2::splat(<$t>::NEG_INFINITY));
+            assert!($vec2::NAN.is_nan_mask().all());
+
+            // bounds of a point set
+            let points = [
+                $vec2::new(1.0, -2.0),
+                $vec2::new(-3.0, 4.0),
+                $vec2::new(0.5, 0.0),
+            ];
+            let (min, max) = points
+                .iter()
+                .fold(($vec2::INFINITY, $vec2::NEG_INFINITY), |(min, max), &p| {
+                    (min.min(p), max.max(p))
+                });
+            assert_eq!($vec2::new(-3.0, -2.0), min);
+            assert_eq!($vec2::new(1.0, 4.0), max);
+        });
+
         glam_test!(test_is_finite, {
             assert!($vec2::new(0.0, 0.0).is_finite());
             assert!($vec2::new(-1e-10, 1e10).is_finite());
@@ -736,6 +1173,18 @@ macro_rules! impl_vec2_float_tests {
             assert!(!$vec2::new(INFINITY, NEG_INFINITY).is_finite());
         });
 
+        glam_test!(test_is_nan, {
+            assert!(!$vec2::ZERO.is_nan());
+            assert!(!$vec2::new(INFINITY, NEG_INFINITY).is_nan());
+            assert!($vec2::new(0.0, NAN).is_nan());
+            assert!($vec2::NAN.is_nan());
+            assert_eq!(
+                $vec2::new(NAN, INFINITY).is_nan_mask(),
+                $mask::new(true, false)
+            );
+            assert_eq!($vec2::NAN.is_nan_mask(), $mask::new(true, true));
+        });
+
         glam_test!(test_powf, {
             assert_eq!($vec2::new(2.0, 4.0).powf(2.0), $vec2::new(4.0, 16.0));
         });
@@ -747,6 +1196,160 @@ macro_rules! impl_vec2_float_tests {
             );
         });
 
+        glam_test!(test_distance_3_4_5, {
+            let a = $vec2::new(1.0, -2.0);
+            let b = a + $vec2::new(3.0, 4.0);
+            assert_eq!(5.0, a.distance(b));
+            assert_eq!(5.0, b.distance(a));
+            assert_eq!(25.0, a.distance_squared(b));
+            assert_eq!(25.0, b.distance_squared(a));
+            assert_eq!(0.0, a.distance(a));
+        });
+
+        glam_test!(test_perp_rotates_90, {
+            let r = $vec2::from_angle(core::$t::consts::FRAC_PI_2);
+            for v in [$vec2::X, $vec2::new(3.0, -2.0), $vec2::new(-0.5, 7.0)] {
+                assert_approx_eq!(r.rotate(v), v.perp(), 1e-6);
+                assert_approx_eq!(v.angle_between(v.perp()), core::$t::consts::FRAC_PI_2, 1e-6);
+            }
+        });
+
+        glam_test!(test_min_max_position_nan, {
+            // NaN elements are ignored unless all elements are NaN
+            assert_eq!(1, $vec2::new(NAN, 1.0).min_position());
+            assert_eq!(1, $vec2::new(NAN, 1.0).max_position());
+            assert_eq!(0, $vec2::new(1.0, NAN).min_position());
+            assert_eq!(0, $vec2::new(1.0, NAN).max_position());
+            assert_eq!(0, $vec2::NAN.min_position());
+            assert_eq!(0, $vec2::NAN.max_position());
+        });
+
+        glam_test!(test_ln, {
+            assert_approx_eq!($vec2::new(1.0, 2.0).ln(), $vec2::new(0.0, (2.0 as $t).ln()));
+            assert_approx_eq!($vec2::new(2.0, 3.0).exp().ln(), $vec2::new(2.0, 3.0), 1e-6);
+            let v = $vec2::new(0.0, -1.0).ln();
+            assert_eq!(v.x, NEG_INFINITY);
+            assert!(v.y.is_nan());
+        });
+
+        glam_test!(test_select_bits, {
+            // select copies elements unchanged, including signed zeros and NaN payloads
+            let nan = $t::from_bits(<$t>::NAN.to_bits() | 1);
+            let a = $vec2::from_fn(|i| if i % 2 == 0 { -0.0 } else { nan });
+            let b = $vec2::from_fn(|i| i as $t + 1.0);
+            let bits = |v: $vec2| v.to_array().map(|x| x.to_bits());
+            let all = $vec2::ZERO.cmpeq($vec2::ZERO);
+            let none = $vec2::ZERO.cmpne($vec2::ZERO);
+            assert_eq!(bits(a), bits($vec2::select(all, a, b)));
+            assert_eq!(bits(a), bits($vec2::select(none, b, a)));
+
+            // branch free min and max using comparison masks
+            let c = $vec2::from_fn(|i| if i % 2 == 0 { 3.0 } else { -3.0 });
+            assert_eq!(b.min(c), $vec2::select(b.cmplt(c), b, c));
+            assert_eq!(b.max(c), $vec2::select(b.cmpgt(c), b, c));
+
+            // branch free clamping and sign handling
+            let min = $vec2::splat(-1.5);
+            let max = $vec2::splat(1.5);
+            let clamped = $vec2::select(c.cmplt(min), min, $vec2::select(c.cmpgt(max), max, c));
+            assert_eq!(c.clamp(min, max), clamped);
+            assert_eq!(c.abs(), $vec2::select(c.cmplt($vec2::ZERO), -c, c));
+        });
+
+        glam_test!(test_step_smoothstep, {
+            fn step(edge: $t, x: $t) -> $t {
+                if x < edge {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            fn smoothstep(edge0: $t, edge1: $t, x: $t) -> $t {
+                let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+                t * t * (3.0 - 2.0 * t)
+            }
+            let edge0 = $vec2::from_fn(|i| i as $t - 1.0);
+            let edge1 = edge0 + 2.0;
+            // below, at, inside and above the edges
+            for x in [-3.0, -1.0, -0.5, 0.0, 0.25, 1.0, 1.5, 2.0, 4.0] {
+                let v = $vec2::splat(x);
+                let expected = $vec2::from_fn(|i| step(edge0[i], x));
+                assert_eq!(expected, v.step(edge0));
+                let expected = $vec2::from_fn(|i| smoothstep(edge0[i], edge1[i], x));
+                assert_approx_eq!(expected, v.smoothstep(edge0, edge1), 1e-6);
+            }
+            assert_eq!($vec2::ONE, $vec2::ONE.step($vec2::ONE));
+            assert_eq!(
+                $vec2::ZERO,
+                $vec2::splat(-10.0).smoothstep($vec2::ZERO, $vec2::ONE)
+            );
+            assert_eq!(
+                $vec2::ONE,
+                $vec2::splat(10.0).smoothstep($vec2::ZERO, $vec2::ONE)
+            );
+            assert_eq!(
+                $vec2::splat(0.5),
+                $vec2::splat(0.5).smoothstep($vec2::ZERO, $vec2::ONE)
+            );
+        });
+
+        glam_test!(test_to_degrees_radians, {
+            for i in -720..=720 {
+                let x = i as $t * 0.37;
+                let v = $vec2::splat(x);
+                assert_eq!($vec2::splat(x.to_degrees()), v.to_degrees());
+                assert_eq!($vec2::splat(x.to_radians()), v.to_radians());
+            }
+            let v = $vec2::from_fn(|i| (i as $t + 1.0) * 90.0);
+            assert_eq!(v.to_radians(), v.map(|x| x.to_radians()));
+            assert_approx_eq!(v, v.to_radians().to_degrees(), 1e-4);
+            assert_approx_eq!(
+                $vec2::splat(core::$t::consts::PI),
+                $vec2::splat(180.0).to_radians()
+            );
+        });
+
+        glam_test!(test_div_rem_euclid, {
+            let a = $vec2::new(-7.0, 7.0);
+            let b = $vec2::new(4.0, -4.0);
+            assert_eq!(a.div_euclid(b), $vec2::new(-2.0, -1.0));
+            assert_eq!(a.rem_euclid(b), $vec2::new(1.0, 3.0));
+            assert_eq!((-a).div_euclid(-b), $vec2::new(-1.0, -2.0));
+            assert_eq!((-a).rem_euclid(-b), $vec2::new(3.0, 1.0));
+            for (x, y) in [
+                (-7.5, 2.0),
+                (7.5, -2.0),
+                (-7.5, -2.0),
+                (-0.5, 3.0),
+                (-1e-30, 1.0),
+            ] {
+                let v = $vec2::new(x, y).rem_euclid($vec2::new(y, x));
+                assert_eq!(v, $vec2::new(x.rem_euclid(y), y.rem_euclid(x)));
+                let v = $vec2::new(x, y).div_euclid($vec2::new(y, x));
+                assert_eq!(v, $vec2::new(x.div_euclid(y), y.div_euclid(x)));
+            }
+            // rem_euclid + div_euclid * rhs == self
+            assert_eq!(a.div_euclid(b) * b + a.rem_euclid(b), a);
+            assert!($vec2::ONE.rem_euclid($vec2::ZERO).is_nan_mask().all());
+            // `%` truncates where `rem_euclid` does not
+            for (x, y) in [(-7.5, 2.0), (7.5, -2.0), (-7.5, -2.0), (-0.5, 3.0)] {
+                let v = $vec2::splat(x) % $vec2::splat(y);
+                assert_eq!(v, $vec2::splat(x % y));
+                assert_eq!(v.rem_euclid($vec2::splat(y)), $vec2::splat(x.rem_euclid(y)));
+            }
+        });
+
+        glam_test!(test_rem_signed_zero, {
+            // like the scalar `%`, zero results have the sign of the dividend
+            let r = $vec2::splat(-0.0) % $vec2::ONE;
+            assert_eq!(r.is_negative_bitmask(), 0b11);
+            let r = $vec2::splat(-1.0) % 1.0;
+            assert_eq!(r.is_negative_bitmask(), 0b11);
+            assert_eq!(r, $vec2::ZERO);
+            let r = $vec2::splat(1.0) % -1.0;
+            assert_eq!(r.is_negative_bitmask(), 0);
+        });
+
         glam_test!(test_angle_between, {
             let angle = $vec2::new(1.0, 0.0).angle_between($vec2::new(0.0, 1.0));
             assert_approx_eq!(core::$t::consts::FRAC_PI_2, angle, 1e-6);
@@ -756,6 +1359,50 @@ macro_rules! impl_vec2_float_tests {
 
             let angle = $vec2::new(-1.0, 0.0).angle_between($vec2::new(0.0, 1.0));
             assert_approx_eq!(-core::$t::consts::FRAC_PI_2, angle, 1e-6);
+
+            // the signed angle covers the full range, for rotations built with rotate
+            let v = $vec2::new(2.0, -1.0);
+            for degrees in (-175..=175).step_by(5) {
+                let angle = (degrees as $t).to_radians();
+                let rotated = $vec2::from_angle(angle).rotate(v) * 3.0;
+                assert_approx_eq!(angle, v.angle_between(rotated), 1e-3);
+                assert_approx_eq!(-angle, rotated.angle_between(v), 1e-3);
+            }
+            // rounding may give either sign for a rotation by PI
+            let rotated = $vec2::from_angle(core::$t::consts::PI).rotate(v);
+            assert_approx_eq!(core::$t::consts::PI, v.angle_between(rotated).abs(), 1e-3);
+
+            // opposite vectors return +PI in either order
+            let angle = $vec2::X.angle_between($vec2::NEG_X);
+            assert_approx_eq!(core::$t::consts::PI, angle, 1e-6);
+            let angle = $vec2::NEG_X.angle_between($vec2::X);
+            assert_approx_eq!(core::$t::consts::PI, angle, 1e-6);
+
+            // nearly parallel vectors don't produce NaN due to rounding
+            for v in [
+                $vec2::new(1.0, 1.0),
+                $vec2::new(0.1, 3.0),
+                $vec2::new(-7.0, 0.3),
+                $vec2::new(1e-3, 1e-3),
+            ] {
+                assert_approx_eq!(v.angle_between(v * 3.0), 0.0, 1e-3);
+                assert_approx_eq!(v.angle_between(-v * 3.0).abs(), core::$t::consts::PI, 1e-3);
+            }
+
+            // small angles near `0` and `π` keep their precision
+            let small = (1e-4 as $t).atan();
+            assert_approx_eq!(
+                small,
+                $vec2::new(1.0, 0.0).angle_between($vec2::new(1.0, 1e-4)),
+                1e-10
+            );
+            assert_approx_eq!(
+                core::$t::consts::PI - small,
+                $vec2::new(1.0, 0.0)
+                    .angle_between($vec2::new(-1.0, 1e-4))
+                    .abs(),
+                1e-6
+            );
         });
 
         glam_test!(test_clamp_length, {
@@ -774,7 +1421,10 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(0.6, 0.8).clamp_length(10.0, 20.0),
                 $vec2::new(6.0, 8.0) // lengthened to length 10.0
             );
+            // Zero length is unchanged
+            assert_eq!($vec2::ZERO.clamp_length(1.0, 2.0), $vec2::ZERO);
             should_glam_assert!({ $vec2::ONE.clamp_length(1.0, 0.0) });
+            should_glam_assert!({ $vec2::ONE.clamp_length(-1.0, 1.0) });
         });
 
         glam_test!(test_clamp_length_max, {
@@ -788,6 +1438,12 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(2.0, 1.0).clamp_length_max(5.0),
                 $vec2::new(2.0, 1.0) // unchanged
             );
+            // Exactly at the limit is unchanged
+            assert_eq!($vec2::X.clamp_length_max(1.0), $vec2::X);
+            // Zero length is unchanged
+            assert_eq!($vec2::ZERO.clamp_length_max(1.0), $vec2::ZERO);
+            assert_eq!($vec2::ZERO.clamp_length_max(0.0), $vec2::ZERO);
+            should_glam_assert!({ $vec2::ONE.clamp_length_max(-1.0) });
         });
 
         glam_test!(test_clamp_length_min, {
@@ -801,6 +1457,9 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(0.6, 0.8).clamp_length_min(10.0),
                 $vec2::new(6.0, 8.0) // lengthened to length 10.0
             );
+            // Zero length is unchanged
+            assert_eq!($vec2::ZERO.clamp_length_min(1.0), $vec2::ZERO);
+            should_glam_assert!({ $vec2::ONE.clamp_length_min(-1.0) });
         });
 
         #[cfg(any(feature = "glam-assert", feature = "debug-glam-assert"))]
@@ -815,6 +1474,12 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(1.0, 1.0).mul_add($vec2::new(0.5, 2.0), $vec2::new(-1.0, -1.0)),
                 $vec2::new(-0.5, 1.0)
             );
+            // the result is fused so `-e * e` is not lost to rounding
+            let e = $t::EPSILON;
+            assert_eq!(
+                $vec2::splat(1.0 + e).mul_add($vec2::splat(1.0 - e), $vec2::NEG_ONE),
+                $vec2::splat(-e * e)
+            );
         });
 
         glam_test!(test_from_angle, {
@@ -832,6 +1497,116 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(0.0, -1.0)
             );
         });
+
+        glam_test!(test_to_angle, {
+            use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            assert_approx_eq!($vec2::X.to_angle(), 0.0);
+            assert_approx_eq!($vec2::Y.to_angle(), FRAC_PI_2);
+            assert_approx_eq!($vec2::NEG_X.to_angle(), PI);
+            assert_approx_eq!($vec2::NEG_Y.to_angle(), -FRAC_PI_2);
+            // the input does not need to be normalized
+            assert_approx_eq!($vec2::new(2.0, 2.0).to_angle(), FRAC_PI_4);
+            for angle in [-3.0, -1.5, -0.5, 0.0, 0.5, 1.5, 3.0] {
+                assert_approx_eq!($vec2::from_angle(angle).to_angle(), angle, 1e-6);
+            }
+        });
+
+        glam_test!(test_rotate_compose, {
+            let v = $vec2::new(3.0, -2.0);
+            let (a, b) = (0.75, 1.5);
+            // rotating by two angles is the same as rotating by their sum
+            assert_approx_eq!(
+                $vec2::from_angle(a).rotate($vec2::from_angle(b).rotate(v)),
+                $vec2::from_angle(a + b).rotate(v),
+                1e-6
+            );
+            assert_approx_eq!(
+                $vec2::from_angle(a).rotate($vec2::from_angle(b)),
+                $vec2::from_angle(a + b),
+                1e-6
+            );
+            assert_approx_eq!(
+                $vec2::from_angle(a).rotate($vec2::from_angle(-a).rotate(v)),
+                v,
+                1e-6
+            );
+            // rotation preserves length and matches the angle of the rotated vector
+            let rotated = $vec2::from_angle(a).rotate(v);
+            assert_approx_eq!(rotated.length(), v.length(), 1e-6);
+            assert_approx_eq!(rotated.to_angle(), v.to_angle() + a, 1e-6);
+        });
+    };
+}
+
+macro_rules! impl_vec2_int_tests {
+    ($t:ident, $new:ident, $vec2:ident) => {
+        glam_test!(test_rem_by_zero, {
+            should_panic!({ $new(5 as $t, 5 as $t) % $new(1 as $t, 0 as $t) });
+            should_panic!({ $new(5 as $t, 5 as $t) % 0 as $t });
+        });
+
+        glam_test!(test_div_rem_euclid_int, {
+            let a = $vec2::splat(7 as $t);
+            let b = $vec2::splat(4 as $t);
+            assert_eq!($vec2::splat(1 as $t), a.div_euclid(b));
+            assert_eq!($vec2::splat(3 as $t), a.rem_euclid(b));
+            should_panic!({ $vec2::ONE.div_euclid($vec2::X) });
+            should_panic!({ $vec2::ONE.rem_euclid($vec2::X) });
+        });
+
+        glam_test!(test_element_sum_product_wrapping, {
+            assert_eq!($t::MIN, $new($t::MAX, 1).element_sum());
+            assert_eq!($t::MAX.wrapping_mul(2), $new($t::MAX, 2).element_product());
+        });
+
+        glam_test!(test_wrapping_saturating_checked, {
+            let max = $new(<$t>::MAX, 1 as $t);
+            let min = $new(<$t>::MIN, 1 as $t);
+            let one = $new(1 as $t, 1 as $t);
+
+            assert_eq!($new(<$t>::MIN, 2 as $t), max.wrapping_add(one));
+            assert_eq!($new(<$t>::MAX, 0 as $t), min.wrapping_sub(one));
+
+            assert_eq!($new(<$t>::MAX, 2 as $t), max.saturating_add(one));
+            assert_eq!($new(<$t>::MIN, 0 as $t), min.saturating_sub(one));
+
+            // any overflowing element gives `None`
+            assert_eq!(None, max.checked_add(one));
+            assert_eq!(None, min.checked_sub(one));
+            assert_eq!(Some(max), (max - one).checked_add(one));
+            assert_eq!(Some(min), (min + one).checked_sub(one));
+            assert_eq!(Some($vec2::MAX), $vec2::MAX.checked_sub($vec2::ZERO));
+        });
+
+        glam_test!(test_midpoint_int, {
+            assert_eq!(
+                $new(1 as $t, 2 as $t).midpoint($new(3 as $t, 6 as $t)),
+                $new(2 as $t, 4 as $t)
+            );
+            // rounds towards negative infinity
+            assert_eq!(
+                $new(0 as $t, 1 as $t).midpoint($new(1 as $t, 4 as $t)),
+                $new(0 as $t, 2 as $t)
+            );
+            // doesn't overflow
+            assert_eq!(
+                $vec2::splat($t::MAX).midpoint($vec2::splat($t::MAX)),
+                $vec2::splat($t::MAX)
+            );
+            assert_eq!(
+                $vec2::splat($t::MAX).midpoint($vec2::splat($t::MAX - 1)),
+                $vec2::splat($t::MAX - 1)
+            );
+            assert_eq!(
+                $vec2::splat($t::MAX - 2).midpoint($vec2::splat($t::MAX)),
+                $vec2::splat($t::MAX - 1)
+            );
+            // for signed types the exact result is -0.5 which rounds down to -1
+            assert_eq!(
+                $vec2::splat($t::MIN).midpoint($vec2::splat($t::MAX)),
+                $vec2::splat($t::MIN / 2 + $t::MAX / 2)
+            );
+        });
     };
 }
 
@@ -842,7 +1617,13 @@ macro_rules! impl_vec2_scalar_shift_op_test {
                 for y in $t_min..$t_max {
                     for rhs in $rhs_min..$rhs_max {
                         assert_eq!($vec2::new(x, y) << rhs, $vec2::new(x << rhs, y << rhs));
+                        let mut v = $vec2::new(x, y);
+                        v <<= rhs;
+                        assert_eq!(v, $vec2::new(x << rhs, y << rhs));
                         assert_eq!($vec2::new(x, y) >> rhs, $vec2::new(x >> rhs, y >> rhs));
+                        let mut v = $vec2::new(x, y);
+                        v >>= rhs;
+                        assert_eq!(v, $vec2::new(x >> rhs, y >> rhs));
                     }
                 }
             }
@@ -890,10 +1671,16 @@ macro_rules! impl_vec2_shift_op_test {
                                 $vec2::new(x1, y1) << $rhs::new(x2, y2),
                                 $vec2::new(x1 << x2, y1 << y2)
                             );
+                            let mut v = $vec2::new(x1, y1);
+                            v <<= $rhs::new(x2, y2);
+                            assert_eq!(v, $vec2::new(x1 << x2, y1 << y2));
                             assert_eq!(
                                 $vec2::new(x1, y1) >> $rhs::new(x2, y2),
                                 $vec2::new(x1 >> x2, y1 >> y2)
                             );
+                            let mut v = $vec2::new(x1, y1);
+                            v >>= $rhs::new(x2, y2);
+                            assert_eq!(v, $vec2::new(x1 >> x2, y1 >> y2));
                         }
                     }
                 }
@@ -922,8 +1709,17 @@ macro_rules! impl_vec2_scalar_bit_op_tests {
                 for y in $t_min..$t_max {
                     for rhs in $t_min..$t_max {
                         assert_eq!($vec2::new(x, y) & rhs, $vec2::new(x & rhs, y & rhs));
+                        let mut v = $vec2::new(x, y);
+                        v &= rhs;
+                        assert_eq!(v, $vec2::new(x & rhs, y & rhs));
                         assert_eq!($vec2::new(x, y) | rhs, $vec2::new(x | rhs, y | rhs));
+                        let mut v = $vec2::new(x, y);
+                        v |= rhs;
+                        assert_eq!(v, $vec2::new(x | rhs, y | rhs));
                         assert_eq!($vec2::new(x, y) ^ rhs, $vec2::new(x ^ rhs, y ^ rhs));
+                        let mut v = $vec2::new(x, y);
+                        v ^= rhs;
+                        assert_eq!(v, $vec2::new(x ^ rhs, y ^ rhs));
                     }
                 }
             }
@@ -944,14 +1740,23 @@ macro_rules! impl_vec2_bit_op_tests {
                                 $vec2::new(x1, y1) & $vec2::new(x2, y2),
                                 $vec2::new(x1 & x2, y1 & y2)
                             );
+                            let mut v = $vec2::new(x1, y1);
+                            v &= $vec2::new(x2, y2);
+                            assert_eq!(v, $vec2::new(x1 & x2, y1 & y2));
                             assert_eq!(
                                 $vec2::new(x1, y1) | $vec2::new(x2, y2),
                                 $vec2::new(x1 | x2, y1 | y2)
                             );
+                            let mut v = $vec2::new(x1, y1);
+                            v |= $vec2::new(x2, y2);
+                            assert_eq!(v, $vec2::new(x1 | x2, y1 | y2));
                             assert_eq!(
                                 $vec2::new(x1, y1) ^ $vec2::new(x2, y2),
                                 $vec2::new(x1 ^ x2, y1 ^ y2)
                             );
+                            let mut v = $vec2::new(x1, y1);
+                            v ^= $vec2::new(x2, y2);
+                            assert_eq!(v, $vec2::new(x1 ^ x2, y1 ^ y2));
                         }
                     }
                 }
@@ -1011,6 +1816,87 @@ mod vec2 {
         assert_eq!(Vec2::new(1.0, 2.0), U64Vec2::new(1, 2).as_vec2());
         assert_eq!(I64Vec2::new(1, 2), U64Vec2::new(1, 2).as_i64vec2());
         assert_eq!(UVec2::new(1, 2), U64Vec2::new(1, 2).as_uvec2());
+
+        // every f32 survives the round trip through f64
+        let v = Vec2::new(0.1, -2.5e-7);
+        assert_eq!(v, v.as_dvec2().as_vec2());
+        // but only f64 values that are representable as f32 do
+        let v = DVec2::new(0.5, -1.25);
+        assert_eq!(v, v.as_vec2().as_dvec2());
+        assert_ne!(DVec2::splat(0.1), DVec2::splat(0.1).as_vec2().as_dvec2());
+    });
+
+    glam_test!(test_int_from, {
+        use glam::{I64Vec2, IVec2, U64Vec2, UVec2};
+        // lossless conversions implement `From`
+        assert_eq!(
+            I64Vec2::new(-1, i32::MIN as i64),
+            I64Vec2::from(IVec2::new(-1, i32::MIN))
+        );
+        assert_eq!(
+            I64Vec2::new(1, u32::MAX as i64),
+            I64Vec2::from(UVec2::new(1, u32::MAX))
+        );
+        assert_eq!(
+            U64Vec2::new(1, u32::MAX as u64),
+            U64Vec2::from(UVec2::new(1, u32::MAX))
+        );
+    });
+
+    glam_test!(test_int_try_from, {
+        use glam::{I64Vec2, IVec2, U64Vec2, UVec2};
+        assert_eq!(Ok(UVec2::splat(2)), UVec2::try_from(IVec2::splat(2)));
+        assert_eq!(
+            Ok(IVec2::new(-1, i32::MIN)),
+            IVec2::try_from(I64Vec2::new(-1, i32::MIN as i64))
+        );
+        // the error reports the first element that is out of range
+        let index = |e: glam::TryFromIntVecError| e.index();
+        assert_eq!(Err(1), UVec2::try_from(IVec2::new(1, -2)).map_err(index));
+        assert_eq!(
+            Err(0),
+            IVec2::try_from(UVec2::splat(u32::MAX)).map_err(index)
+        );
+        assert_eq!(
+            Err(1),
+            IVec2::try_from(I64Vec2::new(0, i64::MIN)).map_err(index)
+        );
+        assert_eq!(Err(0), U64Vec2::try_from(I64Vec2::NEG_ONE).map_err(index));
+        assert_eq!(Err(0), I64Vec2::try_from(U64Vec2::MAX).map_err(index));
+        assert_eq!(Err(0), UVec2::try_from(U64Vec2::MAX).map_err(index));
+        assert_eq!(
+            "element `y` is out of range of the target type",
+            UVec2::try_from(-IVec2::Y).unwrap_err().to_string()
+        );
+    });
+
+    glam_test!(test_int_saturating_as, {
+        use glam::{I64Vec2, IVec2, U64Vec2, UVec2};
+        // `as` wraps around where the saturating casts clamp to the target range
+        let v = IVec2::new(-1, 5);
+        assert_eq!(UVec2::new(u32::MAX, 5), v.as_uvec2());
+        assert_eq!(UVec2::new(0, 5), v.saturating_as_uvec2());
+        assert_eq!(U64Vec2::new(0, 5), v.saturating_as_u64vec2());
+        assert_eq!(IVec2::splat(i32::MAX), UVec2::MAX.saturating_as_ivec2());
+        let v = I64Vec2::new(i64::MAX, -5);
+        assert_eq!(IVec2::new(i32::MAX, -5), v.saturating_as_ivec2());
+        assert_eq!(UVec2::new(u32::MAX, 0), v.saturating_as_uvec2());
+        assert_eq!(U64Vec2::new(i64::MAX as u64, 0), v.saturating_as_u64vec2());
+        let v = U64Vec2::new(u64::MAX, 5);
+        assert_eq!(IVec2::new(i32::MAX, 5), v.saturating_as_ivec2());
+        assert_eq!(UVec2::new(u32::MAX, 5), v.saturating_as_uvec2());
+        assert_eq!(I64Vec2::new(i64::MAX, 5), v.saturating_as_i64vec2());
+    });
+
+    glam_test!(test_f16_array, {
+        assert_eq!(Vec2::new(1.0, -0.0).to_f16_array(), [0x3c00, 0x8000]);
+        assert_eq!(Vec2::from_f16_array([0x3c00, 0x8000]), Vec2::new(1.0, -0.0));
+        // ties round to even
+        assert_eq!(Vec2::new(2049.0, 2051.0).to_f16_array(), [0x6800, 0x6802]);
+        assert_eq!(
+            Vec2::from_f16_array([0x6800, 0x6802]),
+            Vec2::new(2048.0, 2052.0)
+        );
     });
 
     impl_vec2_float_tests!(f32, vec2, Vec2, Vec3, BVec2);
@@ -1036,6 +1922,34 @@ mod dvec2 {
 mod ivec2 {
     use glam::{ivec2, BVec2, IVec2, IVec3, UVec2};
 
+    glam_test!(test_ord_negative, {
+        // negative components order before positive ones, `x` first
+        let mut v = vec![
+            ivec2(1, -1),
+            ivec2(-1, 1),
+            ivec2(-1, -2),
+            ivec2(0, 0),
+            ivec2(-2, 5),
+            ivec2(-2, -3),
+        ];
+        v.sort();
+        assert_eq!(
+            v,
+            [
+                ivec2(-2, -3),
+                ivec2(-2, 5),
+                ivec2(-1, -2),
+                ivec2(-1, 1),
+                ivec2(0, 0),
+                ivec2(1, -1),
+            ]
+        );
+
+        let set: std::collections::BTreeSet<IVec2> = v.into_iter().collect();
+        assert_eq!(Some(&ivec2(-2, -3)), set.iter().next());
+        assert_eq!(Some(&ivec2(1, -1)), set.iter().next_back());
+    });
+
     glam_test!(test_align, {
         use core::mem;
         assert_eq!(8, mem::size_of::<IVec2>());
@@ -1049,6 +1963,7 @@ mod ivec2 {
 
     impl_vec2_signed_tests!(i32, ivec2, IVec2, IVec3, BVec2);
     impl_vec2_eq_hash_tests!(i32, ivec2);
+    impl_vec2_int_tests!(i32, ivec2, IVec2);
 
     impl_vec2_scalar_shift_op_tests!(IVec2, -2, 2);
     impl_vec2_shift_op_tests!(IVec2);
@@ -1073,6 +1988,7 @@ mod uvec2 {
 
     impl_vec2_tests!(u32, uvec2, UVec2, UVec3, BVec2);
     impl_vec2_eq_hash_tests!(u32, uvec2);
+    impl_vec2_int_tests!(u32, uvec2, UVec2);
 
     impl_vec2_scalar_shift_op_tests!(UVec2, 0, 2);
     impl_vec2_shift_op_tests!(UVec2);
@@ -1097,6 +2013,7 @@ mod i64vec2 {
 
     impl_vec2_signed_tests!(i64, i64vec2, I64Vec2, I64Vec3, BVec2);
     impl_vec2_eq_hash_tests!(i64, i64vec2);
+    impl_vec2_int_tests!(i64, i64vec2, I64Vec2);
 
     impl_vec2_scalar_shift_op_tests!(I64Vec2, -2, 2);
     impl_vec2_shift_op_tests!(I64Vec2);
@@ -1121,6 +2038,7 @@ mod u64vec2 {
 
     impl_vec2_tests!(u64, u64vec2, U64Vec2, U64Vec3, BVec2);
     impl_vec2_eq_hash_tests!(u64, u64vec2);
+    impl_vec2_int_tests!(u64, u64vec2, U64Vec2);
 
     impl_vec2_scalar_shift_op_tests!(U64Vec2, 0, 2);
     impl_vec2_shift_op_tests!(U64Vec2);
@@ -12,6 +12,27 @@ macro_rules! impl_vec3_tests {
             assert_eq!([1 as $t, 1 as $t, 1 as $t], *V0.as_ref());
             assert_eq!([1 as $t, 2 as $t, 3 as $t], *V1.as_ref());
             assert_eq!([1 as $t, 2 as $t, 3 as $t], *V2.as_ref());
+
+            const TABLE: [$vec3; 5] = [
+                $vec3::ZERO,
+                $vec3::X,
+                $vec3::Y,
+                $vec3::Z,
+                $vec3::new(1 as $t, 2 as $t, 3 as $t),
+            ];
+            assert_eq!(TABLE[0] + TABLE[1] + TABLE[2] + TABLE[3], $vec3::ONE);
+            assert_eq!(TABLE[4], V1);
+        });
+
+        glam_test!(test_as_ref_aliases, {
+            let mut v = $new(1 as $t, 2 as $t, 3 as $t);
+            let p = v.as_ref().as_ptr();
+            assert_eq!(p, &v.x as *const $t);
+            assert_eq!(p, v.as_mut().as_mut_ptr() as *const $t);
+            v.as_mut()[2] = 5 as $t;
+            assert_eq!(5 as $t, v.z);
+            assert_eq!(v.to_array(), *v.as_ref());
+            assert_eq!(v, $vec3::from_array(*v.as_ref()));
         });
 
         glam_test!(test_vec3_consts, {
@@ -20,6 +41,15 @@ macro_rules! impl_vec3_tests {
             assert_eq!($vec3::X, $new(1 as $t, 0 as $t, 0 as $t));
             assert_eq!($vec3::Y, $new(0 as $t, 1 as $t, 0 as $t));
             assert_eq!($vec3::Z, $new(0 as $t, 0 as $t, 1 as $t));
+            assert_eq!([$vec3::X, $vec3::Y, $vec3::Z], $vec3::AXES);
+
+            // associated constants are usable in const contexts
+            const AXES: [$vec3; 3] = $vec3::AXES;
+            const ONE: $vec3 = $vec3::ONE;
+            assert_eq!(AXES, $vec3::AXES);
+            assert_eq!(ONE, $vec3::splat(1 as $t));
+            assert_eq!($vec3::MIN, $vec3::splat(<$t>::MIN));
+            assert_eq!($vec3::MAX, $vec3::splat(<$t>::MAX));
         });
 
         glam_test!(test_new, {
@@ -70,6 +100,32 @@ macro_rules! impl_vec3_tests {
                 )
             );
             assert_eq!(format!("{}", a), "[1, 2, 3]");
+            // flags apply to every element
+            assert_eq!(format!("{:+}", a), "[+1, +2, +3]");
+            assert_eq!(format!("{:3}", a), "[  1,   2,   3]");
+            assert_eq!(format!("{:_<3}", a), "[1__, 2__, 3__]");
+        });
+
+        glam_test!(test_from_str, {
+            let a = $new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), " [ 1 ,2 ,3 ] ".parse());
+            assert_eq!(
+                Err(glam::ParseError::MissingBrackets),
+                "1, 2".parse::<$vec3>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                "[1, 2]".parse::<$vec3>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                "[1, 2, 3, 4]".parse::<$vec3>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidElement),
+                "[1, x, 3]".parse::<$vec3>()
+            );
         });
 
         glam_test!(test_zero, {
@@ -103,6 +159,14 @@ macro_rules! impl_vec3_tests {
             assert_eq!((1 as $t, 2 as $t, 3 as $t), a.into());
         });
 
+        glam_test!(test_index_out_of_bounds, {
+            should_panic!({ $vec3::ZERO[3] });
+            should_panic!({
+                let mut a = $vec3::ZERO;
+                a[3] = 1 as $t;
+            });
+        });
+
         glam_test!(test_dot_unsigned, {
             let x = $new(1 as $t, 0 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t, 0 as $t);
@@ -200,6 +264,7 @@ macro_rules! impl_vec3_tests {
             assert_eq!(vec(6, 6, 6).clamp(min, max), vec(6, 6, 6));
             assert_eq!(vec(7, 7, 7).clamp(min, max), vec(6, 7, 7));
             assert_eq!(vec(9, 9, 9).clamp(min, max), vec(6, 8, 8));
+            assert_eq!(vec(0, 4, 9).clamp(vec(4, 4, 4), vec(4, 4, 4)), vec(4, 4, 4));
 
             should_glam_assert!({ $vec3::clamp($vec3::ZERO, $vec3::ONE, $vec3::ZERO) });
         });
@@ -213,6 +278,39 @@ macro_rules! impl_vec3_tests {
             assert_eq!(3 as $t, $new(2 as $t, 3 as $t, 1 as $t).max_element());
         });
 
+        glam_test!(test_map_from_fn, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!($new(2 as $t, 4 as $t, 6 as $t), v.map(|x| x * (2 as $t)));
+            assert_eq!(v, v.map(|x| x));
+            assert_eq!(v, $vec3::from_fn(|i| (i + 1) as $t));
+            assert_eq!(v, $vec3::from_fn(|i| v[i]));
+        });
+
+        glam_test!(test_min_max_position, {
+            for i in 0..3 {
+                let mut a = [2 as $t; 3];
+                a[i] = 1 as $t;
+                assert_eq!(i, $vec3::from_array(a).min_position());
+                a[i] = 3 as $t;
+                assert_eq!(i, $vec3::from_array(a).max_position());
+            }
+            // ties return the lowest index
+            assert_eq!(0, $vec3::ONE.min_position());
+            assert_eq!(0, $vec3::ONE.max_position());
+            assert_eq!(1, $new(2 as $t, 1 as $t, 1 as $t).min_position());
+            assert_eq!(0, $new(2 as $t, 1 as $t, 1 as $t).max_position());
+        });
+
+        glam_test!(test_element_sum_product, {
+            let v = $new(2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(9 as $t, v.element_sum());
+            assert_eq!(24 as $t, v.element_product());
+            assert_eq!(0 as $t, $vec3::ZERO.element_sum());
+            assert_eq!(0 as $t, $vec3::ZERO.element_product());
+            assert_eq!(3 as $t, $vec3::ONE.element_sum());
+            assert_eq!(1 as $t, $vec3::ONE.element_product());
+        });
+
         glam_test!(test_eq, {
             let a = $new(1 as $t, 1 as $t, 1 as $t);
             let b = $new(1 as $t, 2 as $t, 3 as $t);
@@ -253,8 +351,22 @@ macro_rules! impl_vec3_tests {
             let a = $new(1 as $t, 2 as $t, 3 as $t);
             let b = a.extend(4 as $t);
             assert_eq!((1 as $t, 2 as $t, 3 as $t, 4 as $t), b.into());
+            // the new element is placed in the w lane and the others are preserved
+            assert_eq!(b.x, a.x);
+            assert_eq!(b.y, a.y);
+            assert_eq!(b.z, a.z);
+            assert_eq!(b.w, 4 as $t);
+            assert_eq!(
+                (0 as $t, 0 as $t, 0 as $t, 1 as $t),
+                $vec3::ZERO.extend(1 as $t).into()
+            );
             let c = $vec3::from(b.truncate());
             assert_eq!(a, c);
+            assert_eq!((1 as $t, 2 as $t), a.truncate().into());
+            assert_eq!(
+                (9 as $t, 5 as $t),
+                $new(9 as $t, 5 as $t, 6 as $t).truncate().into()
+            );
         });
 
         glam_test!(test_mask, {
@@ -504,18 +616,35 @@ macro_rules! impl_vec3_tests {
 
             should_panic!({ $vec3::ONE.write_to_slice(&mut [0 as $t; 2]) });
             should_panic!({ $vec3::from_slice(&[0 as $t; 2]) });
+
+            // longer slices, e.g. interleaved vertex data, only use the first 3 elements
+            let mut b = [9 as $t; 5];
+            v.write_to_slice(&mut b[1..]);
+            assert_eq!([9 as $t, 1 as $t, 2 as $t, 3 as $t, 9 as $t], b);
+            assert_eq!(v, $vec3::from_slice(&b[1..]));
         });
 
         glam_test!(test_sum, {
             let one = $vec3::ONE;
             assert_eq!(vec![one, one].iter().sum::<$vec3>(), one + one);
             assert_eq!(vec![one, one].into_iter().sum::<$vec3>(), one + one);
+
+            // an empty iterator sums to zero
+            assert_eq!(Vec::<$vec3>::new().iter().sum::<$vec3>(), $vec3::ZERO);
+            assert_eq!(Vec::<$vec3>::new().into_iter().sum::<$vec3>(), $vec3::ZERO);
         });
 
         glam_test!(test_product, {
             let two = $vec3::new(2 as $t, 2 as $t, 2 as $t);
             assert_eq!(vec![two, two].iter().product::<$vec3>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$vec3>(), two * two);
+
+            // an empty iterator has a product of one
+            assert_eq!(Vec::<$vec3>::new().iter().product::<$vec3>(), $vec3::ONE);
+            assert_eq!(
+                Vec::<$vec3>::new().into_iter().product::<$vec3>(),
+                $vec3::ONE
+            );
         });
     };
 }
@@ -524,6 +653,61 @@ macro_rules! impl_vec3_signed_tests {
     ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
         impl_vec3_tests!($t, $new, $vec3, $mask);
 
+        glam_test!(test_vec3_consts_signed, {
+            assert_eq!($vec3::NEG_ONE, $new(-1 as $t, -1 as $t, -1 as $t));
+            assert_eq!($vec3::NEG_ONE, -$vec3::ONE);
+            assert_eq!($vec3::NEG_X, $new(-1 as $t, 0 as $t, 0 as $t));
+            assert_eq!($vec3::NEG_Y, $new(0 as $t, -1 as $t, 0 as $t));
+            assert_eq!($vec3::NEG_Z, $new(0 as $t, 0 as $t, -1 as $t));
+            assert_eq!($vec3::NEG_X, -$vec3::X);
+            assert_eq!($vec3::NEG_Y, -$vec3::Y);
+            assert_eq!($vec3::NEG_Z, -$vec3::Z);
+            assert_eq!($vec3::AXES.iter().sum::<$vec3>(), $vec3::ONE);
+        });
+
+        glam_test!(test_from_str_signed, {
+            let a = $new(-1 as $t, 2 as $t, -3 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+        });
+
+        glam_test!(test_rem_truncated, {
+            // the remainder has the same sign as the dividend, like the scalar `%` operator
+            let a = $new(-7 as $t, 7 as $t, -7 as $t);
+            let b = $new(4 as $t, -4 as $t, -4 as $t);
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t), a % b);
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t), a % 4 as $t);
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t), a % -4 as $t);
+            let mut c = a;
+            c %= b;
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t), c);
+            c = a;
+            c %= 4 as $t;
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t), c);
+            assert_eq!(
+                $new(
+                    (-7 as $t) % (4 as $t),
+                    (-7 as $t) % (-4 as $t),
+                    (-7 as $t) % (-4 as $t)
+                ),
+                -7 as $t % b
+            );
+        });
+
+        glam_test!(test_div_rem_euclid_signed, {
+            // unlike `%`, the remainder is never negative, whatever the signs of the operands
+            let a = $new(-7 as $t, 7 as $t, -7 as $t);
+            let b = $new(4 as $t, -4 as $t, -4 as $t);
+            assert_eq!($new(1 as $t, 3 as $t, 1 as $t), a.rem_euclid(b));
+            assert_eq!($new(-2 as $t, -1 as $t, 2 as $t), a.div_euclid(b));
+            assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b));
+            assert!(a.rem_euclid(b).cmpge($vec3::ZERO).all());
+            // wrapping coordinates into a grid of 4 wide tiles
+            assert_eq!(
+                $new(3 as $t, 3 as $t, 0 as $t),
+                $new(-1 as $t, -5 as $t, -8 as $t).rem_euclid($vec3::splat(4 as $t))
+            );
+        });
+
         glam_test!(test_neg, {
             let a = $new(1 as $t, 2 as $t, 3 as $t);
             assert_eq!((-1 as $t, -2 as $t, -3 as $t), (-a).into());
@@ -537,6 +721,31 @@ macro_rules! impl_vec3_signed_tests {
             );
         });
 
+        glam_test!(test_clamp_signed, {
+            fn vec(x: i32, y: i32, z: i32) -> $vec3 {
+                $vec3::new(x as $t, y as $t, z as $t)
+            }
+            let min = vec(-8, -3, 2);
+            let max = vec(-1, 6, 4);
+            assert_eq!(vec(-9, -9, -9).clamp(min, max), vec(-8, -3, 2));
+            assert_eq!(vec(-4, 0, 3).clamp(min, max), vec(-4, 0, 3));
+            assert_eq!(vec(9, 9, 9).clamp(min, max), vec(-1, 6, 4));
+            assert_eq!(vec(-4, -2, 1).min_element(), -4 as $t);
+            assert_eq!(vec(-4, -2, -6).max_element(), -2 as $t);
+        });
+
+        glam_test!(test_copysign_signed, {
+            let a = $new(-3 as $t, 2 as $t, -1 as $t);
+            assert_eq!(
+                a.copysign($new(5 as $t, -1 as $t, 0 as $t)),
+                $new(3 as $t, -2 as $t, 1 as $t)
+            );
+            assert_eq!(a.copysign($vec3::ONE), a.abs());
+            assert_eq!(a.copysign($vec3::NEG_ONE), -a.abs());
+            // zero is treated as positive
+            assert_eq!(a.copysign($vec3::ZERO), a.abs());
+        });
+
         glam_test!(test_dot_signed, {
             let x = $new(1 as $t, 0 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t, 0 as $t);
@@ -589,12 +798,51 @@ macro_rules! impl_vec3_eq_hash_tests {
             assert_ne!(a, c);
             assert_ne!(a_hashed, c_hashed);
         });
+
+        glam_test!(test_ord, {
+            use core::cmp::Ordering;
+            let a = $new(1 as $t, 2 as $t, 3 as $t);
+            assert_eq!(Ordering::Equal, a.cmp(&a));
+            // earlier components take precedence, later ones only break ties
+            assert!(a < $new(2 as $t, 0 as $t, 0 as $t));
+            assert!(a > $new(0 as $t, 9 as $t, 9 as $t));
+            assert!(a < $new(1 as $t, 2 as $t, 5 as $t));
+            assert!(a > $new(1 as $t, 2 as $t, 0 as $t));
+            assert_eq!(
+                Some(Ordering::Less),
+                a.partial_cmp(&$new(1 as $t, 3 as $t, 0 as $t))
+            );
+
+            // `Ord` is lexicographic while the inherent `max` is component-wise
+            let (a, b) = (
+                $new(1 as $t, 5 as $t, 0 as $t),
+                $new(2 as $t, 0 as $t, 0 as $t),
+            );
+            assert_eq!(core::cmp::max(a, b), b);
+            assert_eq!(a.max(b), $new(2 as $t, 5 as $t, 0 as $t));
+            assert_ne!(core::cmp::max(a, b), a.max(b));
+        });
     };
 }
 
 macro_rules! impl_vec3_float_tests {
     ($t:ident, $new:ident, $vec3:ident, $mask:ident) => {
         impl_vec3_signed_tests!($t, $new, $vec3, $mask);
+
+        glam_test!(test_fmt_float, {
+            let a = $new(1.0, -2.5, 0.125);
+            assert_eq!(format!("{:.2}", a), "[1.00, -2.50, 0.12]");
+            assert_eq!(format!("{:+5.1}", a), "[ +1.0,  -2.5,  +0.1]");
+        });
+
+        glam_test!(test_from_str_float, {
+            let a = $new(-1.5e10, 0.25, 0.003);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), "[-1.5e10, 2.5E-1, 3e-3]".parse());
+            assert_eq!(Ok($vec3::INFINITY), format!("{}", $vec3::INFINITY).parse());
+            assert!(format!("{}", $vec3::NAN).parse::<$vec3>().unwrap().is_nan());
+        });
+
         impl_vec_float_normalize_tests!($t, $vec3);
 
         use core::$t::INFINITY;
@@ -604,6 +852,20 @@ macro_rules! impl_vec3_float_tests {
         glam_test!(test_nan, {
             assert!($vec3::NAN.is_nan());
             assert!(!$vec3::NAN.is_finite());
+            assert!(!$vec3::ZERO.is_nan());
+            assert!(!$vec3::new(INFINITY, NEG_INFINITY, 0.0).is_nan());
+            assert!($vec3::new(0.0, 0.0, NAN).is_nan());
+            assert_eq!(
+                $vec3::new(NAN, INFINITY, 0.0).is_nan_mask(),
+                $mask::new(true, false, false)
+            );
+            assert_eq!($vec3::NAN.is_nan_mask(), $mask::new(true, true, true));
+
+            // one NaN lane and one infinite lane
+            let v = $vec3::new(NAN, INFINITY, 1.0);
+            assert!(v.is_nan());
+            assert!(!v.is_finite());
+            assert_eq!(v.is_nan_mask(), $mask::new(true, false, false));
         });
 
         glam_test!(test_funcs, {
@@ -643,6 +905,21 @@ macro_rules! impl_vec3_float_tests {
             assert_eq!($new(0.5, 0.25, 0.125), $new(2.0, 4.0, 8.0).recip());
         });
 
+        glam_test!(test_recip_precision, {
+            // these use a full precision division rather than an approximation
+            let v = $new(3.0, 7.0, -11.0);
+            assert_eq!(v.recip(), $new(1.0 / 3.0, 1.0 / 7.0, 1.0 / -11.0));
+            assert_eq!(v.length_recip(), 1.0 / v.length());
+            assert_eq!($vec3::ZERO.recip(), $vec3::splat(INFINITY));
+            assert_eq!((-$vec3::ZERO).recip(), $vec3::splat(NEG_INFINITY));
+            assert_eq!($vec3::splat(INFINITY).recip(), $vec3::ZERO);
+            assert!($vec3::NAN.recip().is_nan_mask().all());
+            // multiplying by the reciprocal is close to, but not always the same as, dividing
+            let a = $vec3::splat(10.0);
+            let b = $vec3::splat(3.0);
+            assert_approx_eq!(a / b, a * b.recip(), 1e-6);
+        });
+
         glam_test!(test_project_reject, {
             assert_eq!(
                 $new(0.0, 0.0, 1.0),
@@ -660,12 +937,77 @@ macro_rules! impl_vec3_float_tests {
                 $new(1.0, 0.0, 0.0),
                 $new(1.0, 0.0, 1.0).reject_from_normalized($new(0.0, 0.0, 1.0))
             );
+            // the projection and rejection sum to the original vector
+            let v = $new(3.0, -2.0, 0.5);
+            let n = $new(1.5, 4.0, -1.0);
+            assert_approx_eq!(v.project_onto(n) + v.reject_from(n), v, 1e-6);
+            assert_approx_eq!(v.reject_from(n).dot(n), 0.0, 1e-5);
+            let n = n.normalize();
+            assert_approx_eq!(
+                v.project_onto_normalized(n) + v.reject_from_normalized(n),
+                v,
+                1e-6
+            );
             should_glam_assert!({ $vec3::ONE.project_onto($vec3::ZERO) });
             should_glam_assert!({ $vec3::ONE.reject_from($vec3::ZERO) });
             should_glam_assert!({ $vec3::ONE.project_onto_normalized($vec3::ONE) });
             should_glam_assert!({ $vec3::ONE.reject_from_normalized($vec3::ONE) });
         });
 
+        glam_test!(test_reflect, {
+            let normal = $vec3::Z;
+            assert_approx_eq!(
+                $new(1.0, 1.0, -1.0).normalize().reflect(normal),
+                $new(1.0, 1.0, 1.0).normalize()
+            );
+            // head on incidence is reversed
+            assert_eq!($new(0.0, 0.0, -1.0).reflect(normal), $new(0.0, 0.0, 1.0));
+            // grazing incidence is unchanged
+            assert_eq!($vec3::X.reflect(normal), $vec3::X);
+            assert_eq!($vec3::Y.reflect(normal), $vec3::Y);
+            should_glam_assert!({ $vec3::X.reflect($vec3::ONE) });
+        });
+
+        glam_test!(test_refract, {
+            let normal = $vec3::Z;
+            // head on incidence is not bent
+            assert_approx_eq!(
+                $new(0.0, 0.0, -1.0).refract(normal, 1.5),
+                $new(0.0, 0.0, -1.0)
+            );
+            // air (1.0) to glass (1.5) at 45 degrees incidence refracts to 28.1255 degrees
+            let incident = $new(1.0, 0.0, -1.0).normalize();
+            let t = incident.refract(normal, 1.0 / 1.5);
+            assert_approx_eq!(t, $new(0.4714045, 0.0, -0.8819171), 1e-6);
+            assert_approx_eq!(t.angle_between(-normal).to_degrees(), 28.1255, 1e-3);
+            // matching indices of refraction leave the direction unchanged
+            let incident = $new(1.0, 1.0, -1.0).normalize();
+            assert_approx_eq!(incident.refract(normal, 1.0), incident);
+            // Snell's law, the tangential component is scaled by eta
+            let eta = 1.0 / 1.5;
+            let t = incident.refract(normal, eta);
+            assert!(t.is_normalized());
+            assert_approx_eq!(t.x, eta * incident.x, 1e-6);
+            assert_approx_eq!(t.y, eta * incident.y, 1e-6);
+            // grazing incidence into a denser medium bends towards the normal
+            assert_approx_eq!(
+                $vec3::Y.refract(normal, 0.5),
+                $new(0.0, 0.5, -(0.75 as $t).sqrt()),
+                1e-6
+            );
+            // grazing incidence into a less dense medium is totally internally reflected
+            assert_eq!($vec3::Y.refract(normal, 2.0), $vec3::ZERO);
+            // the critical angle for eta = 2.0 is where the sine of the incident angle is 0.5
+            let below = $new(0.0, 0.49, -(1.0 - 0.49 * 0.49 as $t).sqrt());
+            let t = below.refract(normal, 2.0);
+            assert!(t.is_normalized());
+            assert!(t.z < 0.0);
+            let above = $new(0.0, 0.51, -(1.0 - 0.51 * 0.51 as $t).sqrt());
+            assert_eq!(above.refract(normal, 2.0), $vec3::ZERO);
+            should_glam_assert!({ $vec3::X.refract($vec3::ONE, 1.0) });
+            should_glam_assert!({ $vec3::ONE.refract(normal, 1.0) });
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec3::ZERO.signum(), $vec3::ONE);
             assert_eq!((-$vec3::ZERO).signum(), -$vec3::ONE);
@@ -677,6 +1019,16 @@ macro_rules! impl_vec3_float_tests {
         });
 
         glam_test!(test_copysign, {
+            // the sign of zero is preserved, `==` doesn't distinguish between -0.0 and 0.0
+            assert_eq!(
+                $vec3::ZERO.copysign(-$vec3::ONE).is_negative_bitmask(),
+                0b111
+            );
+            assert_eq!((-$vec3::ZERO).copysign($vec3::ONE).is_negative_bitmask(), 0);
+            // only the sign bit of rhs is used, and NAN magnitudes remain NAN
+            assert_eq!($vec3::ONE.copysign(-$vec3::NAN), -$vec3::ONE);
+            assert_eq!((-$vec3::ONE).copysign($vec3::NAN), $vec3::ONE);
+            assert!($vec3::NAN.copysign(-$vec3::ONE).is_nan_mask().all());
             assert_eq!($vec3::ZERO.copysign(-$vec3::ZERO), -$vec3::ZERO);
             assert_eq!((-$vec3::ZERO).copysign(-$vec3::ZERO), -$vec3::ZERO);
             assert_eq!($vec3::ZERO.copysign($vec3::ZERO), $vec3::ZERO);
@@ -713,6 +1065,15 @@ macro_rules! impl_vec3_float_tests {
             assert!($vec3::splat(NAN).copysign(-$vec3::ONE).is_nan_mask().all());
         });
 
+        glam_test!(test_copysign_restores_sign, {
+            // restoring the sign after working with the magnitude is exact, including for -0.0
+            let bits = |v: $vec3| v.to_array().map(|x| x.to_bits());
+            let v = $vec3::from_fn(|i| [-0.0, 0.0, -2.5, 3.0][i]);
+            assert_eq!(bits(v), bits(v.abs().copysign(v)));
+            assert_eq!(bits(v), bits(v.abs() * v.signum()));
+            assert_eq!($vec3::from_fn(|i| [-1.0, 1.0, -1.0, 1.0][i]), v.signum());
+        });
+
         glam_test!(test_is_negative_bitmask, {
             assert_eq!($vec3::ZERO.is_negative_bitmask(), 0b000);
             assert_eq!((-$vec3::ZERO).is_negative_bitmask(), 0b111);
@@ -744,6 +1105,12 @@ macro_rules! impl_vec3_float_tests {
                 $vec3::new(NEG_INFINITY, INFINITY, 0.0)
             );
             assert!($vec3::new(NAN, 0.0, 0.0).round().x.is_nan());
+            // half-way cases round away from zero and the sign of zero is kept
+            assert_eq!($vec3::splat(2.5).round(), $vec3::splat(3.0));
+            assert_eq!($vec3::splat(-2.5).round(), $vec3::splat(-3.0));
+            assert_eq!($vec3::splat(0.49999997).round(), $vec3::ZERO);
+            assert_eq!($vec3::splat(8388609.0).round(), $vec3::splat(8388609.0));
+            assert_eq!($vec3::splat(-0.25).round().is_negative_bitmask(), 0b111);
         });
 
         glam_test!(test_floor, {
@@ -762,6 +1129,58 @@ macro_rules! impl_vec3_float_tests {
             );
         });
 
+        glam_test!(test_trunc, {
+            assert_eq!(
+                $vec3::new(1.5, -1.5, 2.5).trunc(),
+                $vec3::new(1.0, -1.0, 2.0)
+            );
+            assert_eq!($vec3::splat(INFINITY).trunc(), $vec3::splat(INFINITY));
+            assert_eq!(
+                $vec3::splat(NEG_INFINITY).trunc(),
+                $vec3::splat(NEG_INFINITY)
+            );
+            assert!($vec3::NAN.trunc().is_nan_mask().all());
+            assert_eq!($vec3::splat(-2000000.123).trunc(), $vec3::splat(-2000000.0));
+            // the sign of zero is kept
+            assert_eq!($vec3::splat(-0.5).trunc().is_negative_bitmask(), 0b111);
+        });
+
+        glam_test!(test_floor_round_ceil_to_ivec, {
+            use glam::{IVec3, UVec3};
+            let v = $vec3::new(-1.5, 2.5, -0.25);
+            // `as` truncates towards zero, which is wrong for negative grid coordinates
+            assert_eq!(IVec3::new(-1, 2, 0), v.as_ivec3());
+            assert_eq!(IVec3::new(-2, 2, -1), v.floor_to_ivec());
+            assert_eq!(IVec3::new(-2, 3, 0), v.round_to_ivec());
+            assert_eq!(IVec3::new(-1, 3, 0), v.ceil_to_ivec());
+            // out of range elements saturate and NAN becomes zero
+            assert_eq!(IVec3::MIN, $vec3::splat(-1e20).floor_to_ivec());
+            assert_eq!(IVec3::MAX, $vec3::splat(1e20).ceil_to_ivec());
+            assert_eq!(IVec3::ZERO, $vec3::NAN.round_to_ivec());
+            assert_eq!(UVec3::ZERO, $vec3::splat(-1.5).as_uvec3());
+        });
+
+        glam_test!(test_rgb_u8, {
+            assert_eq!($new(0.0, 0.5, 1.0).to_rgb_u8(), [0, 128, 255]);
+            // out of range and NaN elements are clamped
+            assert_eq!($new(-1.0, 2.0, $t::NAN).to_rgb_u8(), [0, 255, 0]);
+            assert_eq!(
+                $new($t::NEG_INFINITY, $t::INFINITY, 0.0).to_rgb_u8(),
+                [0, 255, 0]
+            );
+            // rounds to nearest
+            assert_eq!(
+                $new(0.498 / 255.0, 0.502 / 255.0, 254.6 / 255.0).to_rgb_u8(),
+                [0, 1, 255]
+            );
+
+            assert_eq!($vec3::from_rgb_u8([0, 51, 255]), $new(0.0, 0.2, 1.0));
+            for i in 0..=255 {
+                let rgb = [i, 255 - i, i / 2];
+                assert_eq!($vec3::from_rgb_u8(rgb).to_rgb_u8(), rgb);
+            }
+        });
+
         glam_test!(test_fract, {
             assert_approx_eq!(
                 $vec3::new(1.35, 1.5, -1.5).fract(),
@@ -772,6 +1191,8 @@ macro_rules! impl_vec3_float_tests {
                 $vec3::new(0.877, 0.123, 0.9),
                 0.002
             );
+            // negative elements result in a positive fraction, unlike the scalar `fract`
+            assert_eq!($vec3::splat(-0.25).fract(), $vec3::splat(0.75));
         });
 
         glam_test!(test_ceil, {
@@ -798,6 +1219,76 @@ macro_rules! impl_vec3_float_tests {
             assert_approx_eq!($vec3::ZERO, v0.lerp(v1, 0.5));
         });
 
+        glam_test!(test_smooth_lerp, {
+            let a = $vec3::new(1.0, 2.0, -3.0);
+            let b = $vec3::new(5.0, -2.0, 1.0);
+            assert_eq!(a, a.smooth_lerp(b, 0.0));
+            assert_eq!(b, a.smooth_lerp(b, 1.0));
+            assert_approx_eq!(a.midpoint(b), a.smooth_lerp(b, 0.5));
+            // eased by 3t^2 - 2t^3
+            assert_approx_eq!(a.lerp(b, 0.15625), a.smooth_lerp(b, 0.25));
+            assert_approx_eq!(a.lerp(b, 0.84375), a.smooth_lerp(b, 0.75));
+            // `s` is clamped
+            assert_eq!(a, a.smooth_lerp(b, -1.0));
+            assert_eq!(b, a.smooth_lerp(b, 2.0));
+        });
+
+        glam_test!(test_move_towards, {
+            // the distance between v0 and v1 is 5.0
+            let v0 = $vec3::new(1.0, 2.0, 3.0);
+            let v1 = $vec3::new(4.0, 6.0, 3.0);
+            assert_approx_eq!(v0.move_towards(v1, 3.0), $vec3::new(2.8, 4.4, 3.0), 1e-6);
+            assert_eq!(v0.move_towards(v1, 5.0), v1);
+            // doesn't overshoot
+            assert_eq!(v0.move_towards(v1, 10.0), v1);
+            // zero or negative max_delta doesn't move
+            assert_eq!(v0.move_towards(v1, 0.0), v0);
+            assert_eq!(v0.move_towards(v1, -1.0), v0);
+            // no NaN when already at the target
+            assert_eq!(v0.move_towards(v0, 1.0), v0);
+            assert_eq!($vec3::ZERO.move_towards($vec3::ZERO, 1.0), $vec3::ZERO);
+        });
+
+        glam_test!(test_midpoint, {
+            let v0 = $vec3::new(1.0, 2.0, 3.0);
+            let v1 = $vec3::new(3.0, 6.0, 9.0);
+            assert_eq!(v0.midpoint(v1), $vec3::new(2.0, 4.0, 6.0));
+            assert_eq!(v0.midpoint(v1), v0.lerp(v1, 0.5));
+            assert_eq!(v0.midpoint(v1), v1.midpoint(v0));
+            assert_eq!(v0.midpoint(v0), v0);
+        });
+
+        glam_test!(test_abs_diff_eq, {
+            let a = $vec3::ONE;
+            assert!(a.abs_diff_eq(a, 0.0));
+            // just under, at and just over the threshold
+            assert!(a.abs_diff_eq(a + $vec3::X * 0.09, 0.1));
+            assert!(a.abs_diff_eq(a - $vec3::Y * 0.5, 0.5));
+            assert!(!a.abs_diff_eq(a + $vec3::X * 0.11, 0.1));
+            assert!(!a.abs_diff_eq(a - $vec3::Y * 0.11, 0.1));
+            assert!(!a.abs_diff_eq($vec3::NAN, 1.0));
+        });
+
+        glam_test!(test_float_consts, {
+            assert_eq!($vec3::INFINITY, $vec3::splat(<$t>::INFINITY));
+            assert_eq!($vec3::NEG_INFINITY, $vec3::splat(<$t>::NEG_INFINITY));
+            assert!($vec3::NAN.is_nan_mask().all());
+
+            // bounds of a point set
+            let points = [
+                $vec3::new(1.0, -2.0, 3.0),
+                $vec3::new(-3.0, 4.0, 0.0),
+                $vec3::new(0.5, 0.0, -5.0),
+            ];
+            let (min, max) = points
+                .iter()
+                .fold(($vec3::INFINITY, $vec3::NEG_INFINITY), |(min, max), &p| {
+                    (min.min(p), max.max(p))
+                });
+            assert_eq!($vec3::new(-3.0, -2.0, -5.0), min);
+            assert_eq!($vec3::new(1.0, 4.0, 3.0), max);
+        });
+
         glam_test!(test_is_finite, {
             assert!($vec3::new(0.0, 0.0, 0.0).is_finite());
             assert!($vec3::new(-1e-10, 1.0, 1e10).is_finite());
@@ -821,6 +1312,158 @@ macro_rules! impl_vec3_float_tests {
             );
         });
 
+        glam_test!(test_distance_3_4_5, {
+            let a = $vec3::new(1.0, -2.0, 0.5);
+            let b = a + $vec3::new(3.0, 0.0, 4.0);
+            assert_eq!(5.0, a.distance(b));
+            assert_eq!(5.0, b.distance(a));
+            assert_eq!(25.0, a.distance_squared(b));
+            assert_eq!(25.0, b.distance_squared(a));
+            assert_eq!(0.0, a.distance(a));
+        });
+
+        glam_test!(test_min_max_position_nan, {
+            // NaN elements are ignored unless all elements are NaN
+            assert_eq!(2, $vec3::new(NAN, 2.0, 1.0).min_position());
+            assert_eq!(1, $vec3::new(NAN, 2.0, 1.0).max_position());
+            assert_eq!(2, $vec3::new(1.0, NAN, 0.0).min_position());
+            assert_eq!(0, $vec3::new(1.0, NAN, 0.0).max_position());
+            assert_eq!(0, $vec3::NAN.min_position());
+            assert_eq!(0, $vec3::NAN.max_position());
+        });
+
+        glam_test!(test_ln, {
+            assert_approx_eq!(
+                $vec3::new(1.0, 2.0, 3.0).ln(),
+                $vec3::new(0.0, (2.0 as $t).ln(), (3.0 as $t).ln())
+            );
+            assert_approx_eq!(
+                $vec3::new(2.0, 3.0, 4.0).exp().ln(),
+                $vec3::new(2.0, 3.0, 4.0),
+                1e-6
+            );
+            let v = $vec3::new(0.0, -1.0, 1.0).ln();
+            assert_eq!(v.x, NEG_INFINITY);
+            assert!(v.y.is_nan());
+            assert_eq!(v.z, 0.0);
+        });
+
+        glam_test!(test_select_bits, {
+            // select copies elements unchanged, including signed zeros and NaN payloads
+            let nan = $t::from_bits(<$t>::NAN.to_bits() | 1);
+            let a = $vec3::from_fn(|i| if i % 2 == 0 { -0.0 } else { nan });
+            let b = $vec3::from_fn(|i| i as $t + 1.0);
+            let bits = |v: $vec3| v.to_array().map(|x| x.to_bits());
+            let all = $vec3::ZERO.cmpeq($vec3::ZERO);
+            let none = $vec3::ZERO.cmpne($vec3::ZERO);
+            assert_eq!(bits(a), bits($vec3::select(all, a, b)));
+            assert_eq!(bits(a), bits($vec3::select(none, b, a)));
+
+            // branch free min and max using comparison masks
+            let c = $vec3::from_fn(|i| if i % 2 == 0 { 3.0 } else { -3.0 });
+            assert_eq!(b.min(c), $vec3::select(b.cmplt(c), b, c));
+            assert_eq!(b.max(c), $vec3::select(b.cmpgt(c), b, c));
+
+            // branch free clamping and sign handling
+            let min = $vec3::splat(-1.5);
+            let max = $vec3::splat(1.5);
+            let clamped = $vec3::select(c.cmplt(min), min, $vec3::select(c.cmpgt(max), max, c));
+            assert_eq!(c.clamp(min, max), clamped);
+            assert_eq!(c.abs(), $vec3::select(c.cmplt($vec3::ZERO), -c, c));
+        });
+
+        glam_test!(test_step_smoothstep, {
+            fn step(edge: $t, x: $t) -> $t {
+                if x < edge {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            fn smoothstep(edge0: $t, edge1: $t, x: $t) -> $t {
+                let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+                t * t * (3.0 - 2.0 * t)
+            }
+            let edge0 = $vec3::from_fn(|i| i as $t - 1.0);
+            let edge1 = edge0 + 2.0;
+            // below, at, inside and above the edges
+            for x in [-3.0, -1.0, -0.5, 0.0, 0.25, 1.0, 1.5, 2.0, 4.0] {
+                let v = $vec3::splat(x);
+                let expected = $vec3::from_fn(|i| step(edge0[i], x));
+                assert_eq!(expected, v.step(edge0));
+                let expected = $vec3::from_fn(|i| smoothstep(edge0[i], edge1[i], x));
+                assert_approx_eq!(expected, v.smoothstep(edge0, edge1), 1e-6);
+            }
+            assert_eq!($vec3::ONE, $vec3::ONE.step($vec3::ONE));
+            assert_eq!(
+                $vec3::ZERO,
+                $vec3::splat(-10.0).smoothstep($vec3::ZERO, $vec3::ONE)
+            );
+            assert_eq!(
+                $vec3::ONE,
+                $vec3::splat(10.0).smoothstep($vec3::ZERO, $vec3::ONE)
+            );
+            assert_eq!(
+                $vec3::splat(0.5),
+                $vec3::splat(0.5).smoothstep($vec3::ZERO, $vec3::ONE)
+            );
+        });
+
+        glam_test!(test_to_degrees_radians, {
+            for i in -720..=720 {
+                let x = i as $t * 0.37;
+                let v = $vec3::splat(x);
+                assert_eq!($vec3::splat(x.to_degrees()), v.to_degrees());
+                assert_eq!($vec3::splat(x.to_radians()), v.to_radians());
+            }
+            let v = $vec3::from_fn(|i| (i as $t + 1.0) * 90.0);
+            assert_eq!(v.to_radians(), v.map(|x| x.to_radians()));
+            assert_approx_eq!(v, v.to_radians().to_degrees(), 1e-4);
+            assert_approx_eq!(
+                $vec3::splat(core::$t::consts::PI),
+                $vec3::splat(180.0).to_radians()
+            );
+        });
+
+        glam_test!(test_div_rem_euclid, {
+            let a = $vec3::new(-7.0, 7.0, -7.0);
+            let b = $vec3::new(4.0, -4.0, -4.0);
+            assert_eq!(a.div_euclid(b), $vec3::new(-2.0, -1.0, 2.0));
+            assert_eq!(a.rem_euclid(b), $vec3::new(1.0, 3.0, 1.0));
+            for (x, y) in [
+                (-7.5, 2.0),
+                (7.5, -2.0),
+                (-7.5, -2.0),
+                (-0.5, 3.0),
+                (-1e-30, 1.0),
+            ] {
+                let v = $vec3::new(x, y, x).rem_euclid($vec3::new(y, x, -y));
+                let e = $vec3::new(x.rem_euclid(y), y.rem_euclid(x), x.rem_euclid(-y));
+                assert_eq!(v, e);
+                let v = $vec3::new(x, y, x).div_euclid($vec3::new(y, x, -y));
+                let e = $vec3::new(x.div_euclid(y), y.div_euclid(x), x.div_euclid(-y));
+                assert_eq!(v, e);
+            }
+            assert_eq!(a.div_euclid(b) * b + a.rem_euclid(b), a);
+            // `%` truncates where `rem_euclid` does not
+            for (x, y) in [(-7.5, 2.0), (7.5, -2.0), (-7.5, -2.0), (-0.5, 3.0)] {
+                let v = $vec3::splat(x) % $vec3::splat(y);
+                assert_eq!(v, $vec3::splat(x % y));
+                assert_eq!(v.rem_euclid($vec3::splat(y)), $vec3::splat(x.rem_euclid(y)));
+            }
+        });
+
+        glam_test!(test_rem_signed_zero, {
+            // like the scalar `%`, zero results have the sign of the dividend
+            let r = $vec3::splat(-0.0) % $vec3::ONE;
+            assert_eq!(r.is_negative_bitmask(), 0b111);
+            let r = $vec3::splat(-1.0) % 1.0;
+            assert_eq!(r.is_negative_bitmask(), 0b111);
+            assert_eq!(r, $vec3::ZERO);
+            let r = $vec3::splat(1.0) % -1.0;
+            assert_eq!(r.is_negative_bitmask(), 0);
+        });
+
         glam_test!(test_angle_between, {
             let angle = $vec3::new(1.0, 0.0, 1.0).angle_between($vec3::new(1.0, 1.0, 0.0));
             assert_approx_eq!(core::$t::consts::FRAC_PI_3, angle, 1e-6);
@@ -830,6 +1473,33 @@ macro_rules! impl_vec3_float_tests {
 
             let angle = $vec3::new(-1.0, 0.0, -1.0).angle_between($vec3::new(1.0, -1.0, 0.0));
             assert_approx_eq!(2.0 * core::$t::consts::FRAC_PI_3, angle, 1e-6);
+
+            let angle = $vec3::Y.angle_between($vec3::NEG_Y);
+            assert_approx_eq!(core::$t::consts::PI, angle, 1e-6);
+
+            // nearly parallel vectors don't produce NaN due to rounding
+            for v in [
+                $vec3::new(1.0, 1.0, 1.0),
+                $vec3::new(0.1, 3.0, -0.7),
+                $vec3::new(-7.0, 0.3, 2.0),
+                $vec3::new(1e-3, 1e-3, 1e-3),
+            ] {
+                assert_approx_eq!(v.angle_between(v * 3.0), 0.0, 1e-3);
+                assert_approx_eq!(v.angle_between(-v * 3.0), core::$t::consts::PI, 1e-3);
+            }
+
+            // small angles near `0` and `π` keep their precision
+            let small = (1e-4 as $t).atan();
+            assert_approx_eq!(
+                small,
+                $vec3::new(1.0, 0.0, 0.0).angle_between($vec3::new(1.0, 1e-4, 0.0)),
+                1e-10
+            );
+            assert_approx_eq!(
+                core::$t::consts::PI - small,
+                $vec3::new(1.0, 0.0, 0.0).angle_between($vec3::new(-1.0, 0.0, 1e-4)),
+                1e-6
+            );
         });
 
         glam_test!(test_clamp_length, {
@@ -848,7 +1518,10 @@ macro_rules! impl_vec3_float_tests {
                 $vec3::new(0.6, 0.8, 0.0).clamp_length(10.0, 20.0),
                 $vec3::new(6.0, 8.0, 0.0) // lengthened to length 10.0
             );
+            // Zero length is unchanged
+            assert_eq!($vec3::ZERO.clamp_length(1.0, 2.0), $vec3::ZERO);
             should_glam_assert!({ $vec3::ONE.clamp_length(1.0, 0.0) });
+            should_glam_assert!({ $vec3::ONE.clamp_length(-1.0, 1.0) });
         });
 
         glam_test!(test_clamp_length_max, {
@@ -862,6 +1535,12 @@ macro_rules! impl_vec3_float_tests {
                 $vec3::new(2.0, 1.0, 0.0).clamp_length_max(5.0),
                 $vec3::new(2.0, 1.0, 0.0) // unchanged
             );
+            // Exactly at the limit is unchanged
+            assert_eq!($vec3::X.clamp_length_max(1.0), $vec3::X);
+            // Zero length is unchanged
+            assert_eq!($vec3::ZERO.clamp_length_max(1.0), $vec3::ZERO);
+            assert_eq!($vec3::ZERO.clamp_length_max(0.0), $vec3::ZERO);
+            should_glam_assert!({ $vec3::ONE.clamp_length_max(-1.0) });
         });
 
         glam_test!(test_clamp_length_min, {
@@ -875,6 +1554,9 @@ macro_rules! impl_vec3_float_tests {
                 $vec3::new(0.6, 0.8, 0.0).clamp_length_min(10.0),
                 $vec3::new(6.0, 8.0, 0.0) // lengthened to length 10.0
             );
+            // Zero length is unchanged
+            assert_eq!($vec3::ZERO.clamp_length_min(1.0), $vec3::ZERO);
+            should_glam_assert!({ $vec3::ONE.clamp_length_min(-1.0) });
         });
 
         glam_test!(test_any_ortho, {
@@ -894,7 +1576,63 @@ macro_rules! impl_vec3_float_tests {
                 let (a, b) = n.any_orthonormal_pair();
                 assert!(a.is_normalized() && n.dot(a).abs() < eps);
                 assert!(b.is_normalized() && n.dot(b).abs() < eps);
+                assert!(a.dot(b).abs() < eps);
+                assert!((a.cross(b) - n).length() < 4.0 * eps);
+            }
+
+            should_glam_assert!({ $vec3::ONE.any_orthonormal_vector() });
+            should_glam_assert!({ $vec3::ONE.any_orthonormal_pair() });
+        });
+
+        glam_test!(test_octahedral_encode_decode, {
+            use core::$t::consts::{PI, TAU};
+            let mut dirs = vec![
+                // poles and axes
+                $vec3::X,
+                $vec3::Y,
+                $vec3::Z,
+                $vec3::NEG_X,
+                $vec3::NEG_Y,
+                $vec3::NEG_Z,
+                // the equator and the diagonal seams of the folded lower hemisphere
+                $new(1.0, 1.0, 0.0),
+                $new(-1.0, 1.0, 1e-4),
+                $new(1.0, -1.0, -1e-4),
+                $new(1.0, 0.0, -1.0),
+                $new(0.0, -1.0, -1.0),
+                $new(-1.0, -1.0, -1.0),
+                $new(1e-4, 1e-4, -1.0),
+            ];
+            for i in 0..=32 {
+                for j in 0..64 {
+                    let (sin_theta, cos_theta) = (PI * i as $t / 32.0).sin_cos();
+                    let (sin_phi, cos_phi) = (TAU * j as $t / 64.0).sin_cos();
+                    dirs.push($new(sin_theta * cos_phi, sin_theta * sin_phi, cos_theta));
+                }
             }
+            for d in dirs {
+                let d = d.normalize();
+                let e = d.octahedral_encode();
+                assert!(e.abs().max_element() <= 1.0);
+                let r = $vec3::from(e.octahedral_decode());
+                assert!(r.is_normalized());
+                assert!(
+                    (r - d).abs().max_element() <= 4.0 * <$t>::EPSILON,
+                    "{:?} -> {:?} -> {:?}",
+                    d,
+                    e,
+                    r
+                );
+            }
+
+            // the upper pole maps to the center and the lower pole to the corners
+            assert_eq!($vec3::Z.octahedral_encode().length(), 0.0);
+            assert_eq!($vec3::NEG_Z.octahedral_encode().abs().min_element(), 1.0);
+            // slightly out of range inputs are clamped
+            let e = $vec3::NEG_Z.octahedral_encode() * 1.01;
+            assert_eq!($vec3::from(e.octahedral_decode()), $vec3::NEG_Z);
+
+            should_glam_assert!({ $vec3::ONE.octahedral_encode() });
         });
 
         glam_test!(test_mul_add, {
@@ -907,6 +1645,81 @@ macro_rules! impl_vec3_float_tests {
     };
 }
 
+macro_rules! impl_vec3_int_tests {
+    ($t:ident, $new:ident, $vec3:ident) => {
+        glam_test!(test_rem_by_zero, {
+            should_panic!({ $new(5 as $t, 5 as $t, 5 as $t) % $new(1 as $t, 1 as $t, 0 as $t) });
+            should_panic!({ $new(5 as $t, 5 as $t, 5 as $t) % 0 as $t });
+        });
+
+        glam_test!(test_div_rem_euclid_int, {
+            let a = $vec3::splat(7 as $t);
+            let b = $vec3::splat(4 as $t);
+            assert_eq!($vec3::splat(1 as $t), a.div_euclid(b));
+            assert_eq!($vec3::splat(3 as $t), a.rem_euclid(b));
+            should_panic!({ $vec3::ONE.div_euclid($vec3::X) });
+            should_panic!({ $vec3::ONE.rem_euclid($vec3::X) });
+        });
+
+        glam_test!(test_element_sum_product_wrapping, {
+            assert_eq!($t::MIN, $new($t::MAX, 1, 0).element_sum());
+            assert_eq!(
+                $t::MAX.wrapping_mul(2),
+                $new($t::MAX, 2, 1).element_product()
+            );
+        });
+
+        glam_test!(test_wrapping_saturating_checked, {
+            let max = $new(<$t>::MAX, 1 as $t, 2 as $t);
+            let min = $new(<$t>::MIN, 1 as $t, 2 as $t);
+            let one = $new(1 as $t, 1 as $t, 1 as $t);
+
+            assert_eq!($new(<$t>::MIN, 2 as $t, 3 as $t), max.wrapping_add(one));
+            assert_eq!($new(<$t>::MAX, 0 as $t, 1 as $t), min.wrapping_sub(one));
+
+            assert_eq!($new(<$t>::MAX, 2 as $t, 3 as $t), max.saturating_add(one));
+            assert_eq!($new(<$t>::MIN, 0 as $t, 1 as $t), min.saturating_sub(one));
+
+            // any overflowing element gives `None`
+            assert_eq!(None, max.checked_add(one));
+            assert_eq!(None, min.checked_sub(one));
+            assert_eq!(Some(max), (max - one).checked_add(one));
+            assert_eq!(Some(min), (min + one).checked_sub(one));
+            assert_eq!(Some($vec3::MAX), $vec3::MAX.checked_sub($vec3::ZERO));
+        });
+
+        glam_test!(test_midpoint_int, {
+            assert_eq!(
+                $new(1 as $t, 2 as $t, 3 as $t).midpoint($new(3 as $t, 6 as $t, 9 as $t)),
+                $new(2 as $t, 4 as $t, 6 as $t)
+            );
+            // rounds towards negative infinity
+            assert_eq!(
+                $new(0 as $t, 1 as $t, 2 as $t).midpoint($new(1 as $t, 4 as $t, 3 as $t)),
+                $new(0 as $t, 2 as $t, 2 as $t)
+            );
+            // doesn't overflow
+            assert_eq!(
+                $vec3::splat($t::MAX).midpoint($vec3::splat($t::MAX)),
+                $vec3::splat($t::MAX)
+            );
+            assert_eq!(
+                $vec3::splat($t::MAX).midpoint($vec3::splat($t::MAX - 1)),
+                $vec3::splat($t::MAX - 1)
+            );
+            assert_eq!(
+                $vec3::splat($t::MAX - 2).midpoint($vec3::splat($t::MAX)),
+                $vec3::splat($t::MAX - 1)
+            );
+            // for signed types the exact result is -0.5 which rounds down to -1
+            assert_eq!(
+                $vec3::splat($t::MIN).midpoint($vec3::splat($t::MAX)),
+                $vec3::splat($t::MIN / 2 + $t::MAX / 2)
+            );
+        });
+    };
+}
+
 macro_rules! impl_vec3_scalar_shift_op_test {
     ($vec3:ident, $t_min:literal, $t_max:literal, $rhs_min:literal, $rhs_max:literal) => {
         glam_test!(test_vec3_scalar_shift_ops, {
@@ -918,10 +1731,16 @@ macro_rules! impl_vec3_scalar_shift_op_test {
                                 $vec3::new(x, y, z) << rhs,
                                 $vec3::new(x << rhs, y << rhs, z << rhs)
                             );
+                            let mut v = $vec3::new(x, y, z);
+                            v <<= rhs;
+                            assert_eq!(v, $vec3::new(x << rhs, y << rhs, z << rhs));
                             assert_eq!(
                                 $vec3::new(x, y, z) >> rhs,
                                 $vec3::new(x >> rhs, y >> rhs, z >> rhs)
                             );
+                            let mut v = $vec3::new(x, y, z);
+                            v >>= rhs;
+                            assert_eq!(v, $vec3::new(x >> rhs, y >> rhs, z >> rhs));
                         }
                     }
                 }
@@ -972,10 +1791,16 @@ macro_rules! impl_vec3_shift_op_test {
                                         $vec3::new(x1, y1, z1) << $rhs::new(x2, y2, z2),
                                         $vec3::new(x1 << x2, y1 << y2, z1 << z2)
                                     );
+                                    let mut v = $vec3::new(x1, y1, z1);
+                                    v <<= $rhs::new(x2, y2, z2);
+                                    assert_eq!(v, $vec3::new(x1 << x2, y1 << y2, z1 << z2));
                                     assert_eq!(
                                         $vec3::new(x1, y1, z1) >> $rhs::new(x2, y2, z2),
                                         $vec3::new(x1 >> x2, y1 >> y2, z1 >> z2)
                                     );
+                                    let mut v = $vec3::new(x1, y1, z1);
+                                    v >>= $rhs::new(x2, y2, z2);
+                                    assert_eq!(v, $vec3::new(x1 >> x2, y1 >> y2, z1 >> z2));
                                 }
                             }
                         }
@@ -1010,14 +1835,23 @@ macro_rules! impl_vec3_scalar_bit_op_tests {
                                 $vec3::new(x, y, z) & rhs,
                                 $vec3::new(x & rhs, y & rhs, z & rhs)
                             );
+                            let mut v = $vec3::new(x, y, z);
+                            v &= rhs;
+                            assert_eq!(v, $vec3::new(x & rhs, y & rhs, z & rhs));
                             assert_eq!(
                                 $vec3::new(x, y, z) | rhs,
                                 $vec3::new(x | rhs, y | rhs, z | rhs)
                             );
+                            let mut v = $vec3::new(x, y, z);
+                            v |= rhs;
+                            assert_eq!(v, $vec3::new(x | rhs, y | rhs, z | rhs));
                             assert_eq!(
                                 $vec3::new(x, y, z) ^ rhs,
                                 $vec3::new(x ^ rhs, y ^ rhs, z ^ rhs)
                             );
+                            let mut v = $vec3::new(x, y, z);
+                            v ^= rhs;
+                            assert_eq!(v, $vec3::new(x ^ rhs, y ^ rhs, z ^ rhs));
                         }
                     }
                 }
@@ -1041,14 +1875,23 @@ macro_rules! impl_vec3_bit_op_tests {
                                         $vec3::new(x1, y1, z1) & $vec3::new(x2, y2, z2),
                                         $vec3::new(x1 & x2, y1 & y2, z1 & z2)
                                     );
+                                    let mut v = $vec3::new(x1, y1, z1);
+                                    v &= $vec3::new(x2, y2, z2);
+                                    assert_eq!(v, $vec3::new(x1 & x2, y1 & y2, z1 & z2));
                                     assert_eq!(
                                         $vec3::new(x1, y1, z1) | $vec3::new(x2, y2, z2),
                                         $vec3::new(x1 | x2, y1 | y2, z1 | z2)
                                     );
+                                    let mut v = $vec3::new(x1, y1, z1);
+                                    v |= $vec3::new(x2, y2, z2);
+                                    assert_eq!(v, $vec3::new(x1 | x2, y1 | y2, z1 | z2));
                                     assert_eq!(
                                         $vec3::new(x1, y1, z1) ^ $vec3::new(x2, y2, z2),
                                         $vec3::new(x1 ^ x2, y1 ^ y2, z1 ^ z2)
                                     );
+                                    let mut v = $vec3::new(x1, y1, z1);
+                                    v ^= $vec3::new(x2, y2, z2);
+                                    assert_eq!(v, $vec3::new(x1 ^ x2, y1 ^ y2, z1 ^ z2));
                                 }
                             }
                         }
@@ -1172,6 +2015,102 @@ mod vec3 {
         assert_eq!(UVec3::new(1, 2, 3), U64Vec3::new(1, 2, 3).as_uvec3());
         assert_eq!(Vec3::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_vec3());
         assert_eq!(Vec3A::new(1.0, 2.0, 3.0), U64Vec3::new(1, 2, 3).as_vec3a());
+
+        // every f32 survives the round trip through f64
+        let v = Vec3::new(0.1, -2.5e-7, 1e30);
+        assert_eq!(v, v.as_dvec3().as_vec3());
+        let v = Vec3A::new(0.1, -2.5e-7, 1e30);
+        assert_eq!(v, v.as_dvec3().as_vec3a());
+        // but only f64 values that are representable as f32 do
+        let v = DVec3::new(0.5, -1.25, 1024.0);
+        assert_eq!(v, v.as_vec3().as_dvec3());
+        assert_ne!(DVec3::splat(0.1), DVec3::splat(0.1).as_vec3().as_dvec3());
+    });
+
+    glam_test!(test_int_from, {
+        use glam::{I64Vec3, IVec3, U64Vec3, UVec3};
+        // lossless conversions implement `From`
+        assert_eq!(
+            I64Vec3::new(-1, i32::MIN as i64, i32::MAX as i64),
+            I64Vec3::from(IVec3::new(-1, i32::MIN, i32::MAX))
+        );
+        assert_eq!(
+            I64Vec3::new(1, u32::MAX as i64, 0),
+            I64Vec3::from(UVec3::new(1, u32::MAX, 0))
+        );
+        assert_eq!(
+            U64Vec3::new(1, u32::MAX as u64, 0),
+            U64Vec3::from(UVec3::new(1, u32::MAX, 0))
+        );
+    });
+
+    glam_test!(test_int_try_from, {
+        use glam::{I64Vec3, IVec3, U64Vec3, UVec3};
+        assert_eq!(Ok(UVec3::splat(2)), UVec3::try_from(IVec3::splat(2)));
+        assert_eq!(
+            Ok(IVec3::new(-1, i32::MIN, i32::MAX)),
+            IVec3::try_from(I64Vec3::new(-1, i32::MIN as i64, i32::MAX as i64))
+        );
+        // the error reports the first element that is out of range
+        let index = |e: glam::TryFromIntVecError| e.index();
+        assert_eq!(
+            Err(1),
+            UVec3::try_from(IVec3::new(1, -2, -3)).map_err(index)
+        );
+        assert_eq!(
+            Err(0),
+            IVec3::try_from(UVec3::splat(u32::MAX)).map_err(index)
+        );
+        assert_eq!(
+            Err(2),
+            IVec3::try_from(I64Vec3::new(0, 0, i64::MIN)).map_err(index)
+        );
+        assert_eq!(Err(0), U64Vec3::try_from(I64Vec3::NEG_ONE).map_err(index));
+        assert_eq!(Err(0), I64Vec3::try_from(U64Vec3::MAX).map_err(index));
+        assert_eq!(Err(0), UVec3::try_from(U64Vec3::MAX).map_err(index));
+        assert_eq!(
+            "element `y` is out of range of the target type",
+            UVec3::try_from(-IVec3::Y).unwrap_err().to_string()
+        );
+    });
+
+    glam_test!(test_int_saturating_as, {
+        use glam::{I64Vec3, IVec3, U64Vec3, UVec3};
+        // `as` wraps around where the saturating casts clamp to the target range
+        let v = IVec3::new(-1, 5, i32::MIN);
+        assert_eq!(UVec3::new(u32::MAX, 5, 1 << 31), v.as_uvec3());
+        assert_eq!(UVec3::new(0, 5, 0), v.saturating_as_uvec3());
+        assert_eq!(U64Vec3::new(0, 5, 0), v.saturating_as_u64vec3());
+        assert_eq!(IVec3::splat(i32::MAX), UVec3::MAX.saturating_as_ivec3());
+        let v = I64Vec3::new(i64::MAX, -5, i64::MIN);
+        assert_eq!(IVec3::new(i32::MAX, -5, i32::MIN), v.saturating_as_ivec3());
+        assert_eq!(UVec3::new(u32::MAX, 0, 0), v.saturating_as_uvec3());
+        assert_eq!(
+            U64Vec3::new(i64::MAX as u64, 0, 0),
+            v.saturating_as_u64vec3()
+        );
+        let v = U64Vec3::new(u64::MAX, 5, 0);
+        assert_eq!(IVec3::new(i32::MAX, 5, 0), v.saturating_as_ivec3());
+        assert_eq!(UVec3::new(u32::MAX, 5, 0), v.saturating_as_uvec3());
+        assert_eq!(I64Vec3::new(i64::MAX, 5, 0), v.saturating_as_i64vec3());
+    });
+
+    glam_test!(test_f16_array, {
+        let v = Vec3::new(0.5, -2.0, 1e6);
+        assert_eq!(v.to_f16_array(), [0x3800, 0xc000, 0x7c00]);
+        assert_eq!(
+            Vec3::from_f16_array(v.to_f16_array()),
+            Vec3::new(0.5, -2.0, f32::INFINITY)
+        );
+        // subnormals
+        assert_eq!(
+            Vec3::new(5.960_464_5e-8, 0.0, 0.0).to_f16_array(),
+            [1, 0, 0]
+        );
+        assert_eq!(
+            Vec3::from_f16_array([0x3ff, 0, 0]).x,
+            1023.0 * 2f32.powi(-24)
+        );
     });
 
     impl_vec3_float_tests!(f32, vec3, Vec3, BVec3);
@@ -1181,6 +2120,23 @@ mod vec3a {
     use glam::BVec3A;
     use glam::{vec3a, Vec3A, Vec4};
 
+    glam_test!(test_mask_hash_matches_bvec3, {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash>(v: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // masks hash their bitmask so the result does not depend on the storage
+        let a = glam::BVec3A::new(true, false, true);
+        let b = glam::BVec3::new(true, false, true);
+        assert_eq!(a.bitmask(), b.bitmask());
+        assert_eq!(hash(a), hash(b));
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(16, mem::size_of::<Vec3A>());
@@ -1189,6 +2145,15 @@ mod vec3a {
         assert_eq!(16, mem::align_of::<BVec3A>());
     });
 
+    glam_test!(test_as_ref_skips_padding, {
+        let mut v = Vec3A::from(Vec4::new(1.0, 2.0, 3.0, 4.0));
+        let a: &[f32; 3] = v.as_ref();
+        assert_eq!([1.0, 2.0, 3.0], *a);
+        assert_eq!(a.as_ptr(), &v.x as *const f32);
+        *v.as_mut() = [5.0, 6.0, 7.0];
+        assert_eq!(vec3a(5.0, 6.0, 7.0), v);
+    });
+
     glam_test!(test_mask_align16, {
         // make sure the unused 'w' value doesn't break Vec3Ab behaviour
         let a = Vec4::ZERO;
@@ -1244,6 +2209,41 @@ mod vec3a {
         assert_eq!(v2.min_element(), 2.0);
     });
 
+    glam_test!(test_map_skips_padding, {
+        let calls = core::cell::Cell::new(0);
+        let v = Vec3A::from(Vec4::new(1.0, 2.0, 3.0, 4.0)).map(|x| {
+            calls.set(calls.get() + 1);
+            x + 1.0
+        });
+        assert_eq!(3, calls.get());
+        assert_eq!(Vec3A::new(2.0, 3.0, 4.0), v);
+    });
+
+    glam_test!(test_element_sum_product_from_vec4, {
+        // checks that the 4th element is unused.
+        let v = Vec3A::from(Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(v.element_sum(), 6.0);
+        assert_eq!(v.element_product(), 6.0);
+    });
+
+    glam_test!(test_f16_array, {
+        let v = Vec3A::new(0.5, -2.0, 1e6);
+        assert_eq!(v.to_f16_array(), [0x3800, 0xc000, 0x7c00]);
+        assert_eq!(
+            Vec3A::from_f16_array(v.to_f16_array()),
+            Vec3A::new(0.5, -2.0, f32::INFINITY)
+        );
+        // subnormals
+        assert_eq!(
+            Vec3A::new(5.960_464_5e-8, 0.0, 0.0).to_f16_array(),
+            [1, 0, 0]
+        );
+        assert_eq!(
+            Vec3A::from_f16_array([0x3ff, 0, 0]).x,
+            1023.0 * 2f32.powi(-24)
+        );
+    });
+
     impl_vec3_float_tests!(f32, vec3a, Vec3A, BVec3A);
 }
 
@@ -1264,6 +2264,34 @@ mod dvec3 {
 mod ivec3 {
     use glam::{ivec3, BVec3, IVec3, UVec3};
 
+    glam_test!(test_ord_negative, {
+        // negative components order before positive ones, `x` first
+        let mut v = vec![
+            ivec3(1, -1, 0),
+            ivec3(-1, 1, 0),
+            ivec3(-1, -2, 0),
+            ivec3(0, 0, 0),
+            ivec3(-2, 5, 0),
+            ivec3(-1, -2, -1),
+        ];
+        v.sort();
+        assert_eq!(
+            v,
+            [
+                ivec3(-2, 5, 0),
+                ivec3(-1, -2, -1),
+                ivec3(-1, -2, 0),
+                ivec3(-1, 1, 0),
+                ivec3(0, 0, 0),
+                ivec3(1, -1, 0),
+            ]
+        );
+
+        let set: std::collections::BTreeSet<IVec3> = v.into_iter().collect();
+        assert_eq!(Some(&ivec3(-2, 5, 0)), set.iter().next());
+        assert_eq!(Some(&ivec3(1, -1, 0)), set.iter().next_back());
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(12, mem::size_of::<IVec3>());
@@ -1274,6 +2302,7 @@ mod ivec3 {
 
     impl_vec3_signed_tests!(i32, ivec3, IVec3, BVec3);
     impl_vec3_eq_hash_tests!(i32, ivec3);
+    impl_vec3_int_tests!(i32, ivec3, IVec3);
 
     impl_vec3_scalar_shift_op_tests!(IVec3, -2, 2);
     impl_vec3_shift_op_tests!(IVec3);
@@ -1295,6 +2324,7 @@ mod uvec3 {
 
     impl_vec3_tests!(u32, uvec3, UVec3, BVec3);
     impl_vec3_eq_hash_tests!(u32, uvec3);
+    impl_vec3_int_tests!(u32, uvec3, UVec3);
 
     impl_vec3_scalar_shift_op_tests!(UVec3, 0, 2);
     impl_vec3_shift_op_tests!(UVec3);
@@ -1316,6 +2346,7 @@ mod i64vec3 {
 
     impl_vec3_signed_tests!(i64, i64vec3, I64Vec3, BVec3);
     impl_vec3_eq_hash_tests!(i64, i64vec3);
+    impl_vec3_int_tests!(i64, i64vec3, I64Vec3);
 
     impl_vec3_scalar_shift_op_tests!(I64Vec3, -2, 2);
     impl_vec3_shift_op_tests!(I64Vec3);
@@ -1337,6 +2368,7 @@ mod u64vec3 {
 
     impl_vec3_tests!(u64, u64vec3, U64Vec3, BVec3);
     impl_vec3_eq_hash_tests!(u64, u64vec3);
+    impl_vec3_int_tests!(u64, u64vec3, U64Vec3);
 
     impl_vec3_scalar_shift_op_tests!(U64Vec3, 0, 2);
     impl_vec3_shift_op_tests!(U64Vec3);
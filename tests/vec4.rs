@@ -14,6 +14,17 @@ macro_rules! impl_vec4_tests {
             assert_eq!([1 as $t, 2 as $t, 3 as $t, 4 as $t], *V2.as_ref());
         });
 
+        glam_test!(test_as_ref_aliases, {
+            let mut v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            let p = v.as_ref().as_ptr();
+            assert_eq!(p, &v.x as *const $t);
+            assert_eq!(p, v.as_mut().as_mut_ptr() as *const $t);
+            v.as_mut()[3] = 5 as $t;
+            assert_eq!(5 as $t, v.w);
+            assert_eq!(v.to_array(), *v.as_ref());
+            assert_eq!(v, $vec4::from_array(*v.as_ref()));
+        });
+
         glam_test!(test_vec4_consts, {
             assert_eq!($vec4::ZERO, $new(0 as $t, 0 as $t, 0 as $t, 0 as $t));
             assert_eq!($vec4::ONE, $new(1 as $t, 1 as $t, 1 as $t, 1 as $t));
@@ -21,6 +32,15 @@ macro_rules! impl_vec4_tests {
             assert_eq!($vec4::Y, $new(0 as $t, 1 as $t, 0 as $t, 0 as $t));
             assert_eq!($vec4::Z, $new(0 as $t, 0 as $t, 1 as $t, 0 as $t));
             assert_eq!($vec4::W, $new(0 as $t, 0 as $t, 0 as $t, 1 as $t));
+            assert_eq!([$vec4::X, $vec4::Y, $vec4::Z, $vec4::W], $vec4::AXES);
+
+            // associated constants are usable in const contexts
+            const AXES: [$vec4; 4] = $vec4::AXES;
+            const ONE: $vec4 = $vec4::ONE;
+            assert_eq!(AXES, $vec4::AXES);
+            assert_eq!(ONE, $vec4::splat(1 as $t));
+            assert_eq!($vec4::MIN, $vec4::splat(<$t>::MIN));
+            assert_eq!($vec4::MAX, $vec4::splat(<$t>::MAX));
         });
 
         glam_test!(test_new, {
@@ -100,6 +120,32 @@ macro_rules! impl_vec4_tests {
                 )
             );
             assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+            // flags apply to every element
+            assert_eq!(format!("{:+}", a), "[+1, +2, +3, +4]");
+            assert_eq!(format!("{:3}", a), "[  1,   2,   3,   4]");
+            assert_eq!(format!("{:_<3}", a), "[1__, 2__, 3__, 4__]");
+        });
+
+        glam_test!(test_from_str, {
+            let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), " [ 1 ,2 ,3 ,4 ] ".parse());
+            assert_eq!(
+                Err(glam::ParseError::MissingBrackets),
+                "1, 2, 3".parse::<$vec4>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                "[1, 2, 3]".parse::<$vec4>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::WrongLength),
+                "[1, 2, 3, 4, 5]".parse::<$vec4>()
+            );
+            assert_eq!(
+                Err(glam::ParseError::InvalidElement),
+                "[1, x, 3, 4]".parse::<$vec4>()
+            );
         });
 
         glam_test!(test_zero, {
@@ -137,6 +183,14 @@ macro_rules! impl_vec4_tests {
             assert_eq!((1 as $t, 2 as $t, 3 as $t, 4 as $t), a.into());
         });
 
+        glam_test!(test_index_out_of_bounds, {
+            should_panic!({ $vec4::ZERO[4] });
+            should_panic!({
+                let mut a = $vec4::ZERO;
+                a[4] = 1 as $t;
+            });
+        });
+
         glam_test!(test_dot_unsigned, {
             let x = $new(1 as $t, 0 as $t, 0 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t, 0 as $t, 0 as $t);
@@ -236,6 +290,10 @@ macro_rules! impl_vec4_tests {
             assert_eq!(vec(6, 6, 6, 6).clamp(min, max), vec(6, 6, 6, 6));
             assert_eq!(vec(7, 7, 7, 7).clamp(min, max), vec(6, 6, 7, 7));
             assert_eq!(vec(9, 9, 9, 9).clamp(min, max), vec(6, 6, 8, 8));
+            assert_eq!(
+                vec(0, 4, 9, 4).clamp(vec(4, 4, 4, 4), vec(4, 4, 4, 4)),
+                vec(4, 4, 4, 4)
+            );
 
             should_glam_assert!({ $vec4::clamp($vec4::ZERO, $vec4::ONE, $vec4::ZERO) });
         });
@@ -287,6 +345,51 @@ macro_rules! impl_vec4_tests {
             );
         });
 
+        glam_test!(test_map_from_fn, {
+            let v = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(
+                $new(2 as $t, 4 as $t, 6 as $t, 8 as $t),
+                v.map(|x| x * (2 as $t))
+            );
+            assert_eq!(v, v.map(|x| x));
+            assert_eq!(v, $vec4::from_fn(|i| (i + 1) as $t));
+            assert_eq!(v, $vec4::from_fn(|i| v[i]));
+        });
+
+        glam_test!(test_min_max_position, {
+            for i in 0..4 {
+                let mut a = [2 as $t; 4];
+                a[i] = 1 as $t;
+                assert_eq!(i, $vec4::from_array(a).min_position());
+                a[i] = 3 as $t;
+                assert_eq!(i, $vec4::from_array(a).max_position());
+            }
+            // ties return the lowest index
+            assert_eq!(0, $vec4::ONE.min_position());
+            assert_eq!(0, $vec4::ONE.max_position());
+            assert_eq!(1, $new(2 as $t, 1 as $t, 2 as $t, 1 as $t).min_position());
+            assert_eq!(0, $new(2 as $t, 1 as $t, 2 as $t, 1 as $t).max_position());
+        });
+
+        glam_test!(test_element_sum_product, {
+            let v = $new(2 as $t, 3 as $t, 4 as $t, 5 as $t);
+            assert_eq!(14 as $t, v.element_sum());
+            assert_eq!(120 as $t, v.element_product());
+            assert_eq!(0 as $t, $vec4::ZERO.element_sum());
+            assert_eq!(0 as $t, $vec4::ZERO.element_product());
+            assert_eq!(4 as $t, $vec4::ONE.element_sum());
+            assert_eq!(1 as $t, $vec4::ONE.element_product());
+        });
+
+        glam_test!(test_truncate, {
+            let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!((1 as $t, 2 as $t, 3 as $t), a.truncate().into());
+            assert_eq!(
+                (4 as $t, 3 as $t, 2 as $t),
+                $new(4 as $t, 3 as $t, 2 as $t, 1 as $t).truncate().into()
+            );
+        });
+
         glam_test!(test_eq, {
             let a = $new(1 as $t, 1 as $t, 1 as $t, 1 as $t);
             let b = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
@@ -585,18 +688,38 @@ macro_rules! impl_vec4_tests {
             let mut a = [0 as $t, 0 as $t, 0 as $t, 0 as $t];
             v.write_to_slice(&mut a);
             assert_eq!(v, $vec4::from_slice(&a));
+
+            should_panic!({ $vec4::ONE.write_to_slice(&mut [0 as $t; 3]) });
+            should_panic!({ $vec4::from_slice(&[0 as $t; 3]) });
+
+            // longer slices, e.g. interleaved vertex data, only use the first 4 elements
+            let mut b = [9 as $t; 6];
+            v.write_to_slice(&mut b[1..]);
+            assert_eq!([9 as $t, 1 as $t, 2 as $t, 3 as $t, 4 as $t, 9 as $t], b);
+            assert_eq!(v, $vec4::from_slice(&b[1..]));
         });
 
         glam_test!(test_sum, {
             let one = $vec4::ONE;
             assert_eq!(vec![one, one].iter().sum::<$vec4>(), one + one);
             assert_eq!(vec![one, one].into_iter().sum::<$vec4>(), one + one);
+
+            // an empty iterator sums to zero
+            assert_eq!(Vec::<$vec4>::new().iter().sum::<$vec4>(), $vec4::ZERO);
+            assert_eq!(Vec::<$vec4>::new().into_iter().sum::<$vec4>(), $vec4::ZERO);
         });
 
         glam_test!(test_product, {
             let two = $vec4::new(2 as $t, 2 as $t, 2 as $t, 2 as $t);
             assert_eq!(vec![two, two].iter().product::<$vec4>(), two * two);
             assert_eq!(vec![two, two].into_iter().product::<$vec4>(), two * two);
+
+            // an empty iterator has a product of one
+            assert_eq!(Vec::<$vec4>::new().iter().product::<$vec4>(), $vec4::ONE);
+            assert_eq!(
+                Vec::<$vec4>::new().into_iter().product::<$vec4>(),
+                $vec4::ONE
+            );
         });
     };
 }
@@ -605,6 +728,64 @@ macro_rules! impl_vec4_signed_tests {
     ($t:ident, $new:ident, $vec4:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
         impl_vec4_tests!($t, $new, $vec4, $vec3, $vec2, $mask);
 
+        glam_test!(test_vec4_consts_signed, {
+            assert_eq!($vec4::NEG_ONE, $new(-1 as $t, -1 as $t, -1 as $t, -1 as $t));
+            assert_eq!($vec4::NEG_ONE, -$vec4::ONE);
+            assert_eq!($vec4::NEG_X, $new(-1 as $t, 0 as $t, 0 as $t, 0 as $t));
+            assert_eq!($vec4::NEG_Y, $new(0 as $t, -1 as $t, 0 as $t, 0 as $t));
+            assert_eq!($vec4::NEG_Z, $new(0 as $t, 0 as $t, -1 as $t, 0 as $t));
+            assert_eq!($vec4::NEG_W, $new(0 as $t, 0 as $t, 0 as $t, -1 as $t));
+            assert_eq!($vec4::NEG_X, -$vec4::X);
+            assert_eq!($vec4::NEG_Y, -$vec4::Y);
+            assert_eq!($vec4::NEG_Z, -$vec4::Z);
+            assert_eq!($vec4::NEG_W, -$vec4::W);
+            assert_eq!($vec4::AXES.iter().sum::<$vec4>(), $vec4::ONE);
+        });
+
+        glam_test!(test_from_str_signed, {
+            let a = $new(-1 as $t, 2 as $t, -3 as $t, 4 as $t);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+        });
+
+        glam_test!(test_rem_truncated, {
+            // the remainder has the same sign as the dividend, like the scalar `%` operator
+            let a = $new(-7 as $t, 7 as $t, -7 as $t, 7 as $t);
+            let b = $new(4 as $t, -4 as $t, -4 as $t, 4 as $t);
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t, 3 as $t), a % b);
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t, 3 as $t), a % 4 as $t);
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t, 3 as $t), a % -4 as $t);
+            let mut c = a;
+            c %= b;
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t, 3 as $t), c);
+            c = a;
+            c %= 4 as $t;
+            assert_eq!($new(-3 as $t, 3 as $t, -3 as $t, 3 as $t), c);
+            assert_eq!(
+                $new(
+                    (-7 as $t) % (4 as $t),
+                    (-7 as $t) % (-4 as $t),
+                    (-7 as $t) % (-4 as $t),
+                    (-7 as $t) % (4 as $t)
+                ),
+                -7 as $t % b
+            );
+        });
+
+        glam_test!(test_div_rem_euclid_signed, {
+            // unlike `%`, the remainder is never negative, whatever the signs of the operands
+            let a = $new(-7 as $t, 7 as $t, -7 as $t, 7 as $t);
+            let b = $new(4 as $t, -4 as $t, -4 as $t, 4 as $t);
+            assert_eq!($new(1 as $t, 3 as $t, 1 as $t, 3 as $t), a.rem_euclid(b));
+            assert_eq!($new(-2 as $t, -1 as $t, 2 as $t, 1 as $t), a.div_euclid(b));
+            assert_eq!(a, a.div_euclid(b) * b + a.rem_euclid(b));
+            assert!(a.rem_euclid(b).cmpge($vec4::ZERO).all());
+            // wrapping coordinates into a grid of 4 wide tiles
+            assert_eq!(
+                $new(3 as $t, 3 as $t, 0 as $t, 2 as $t),
+                $new(-1 as $t, -5 as $t, -8 as $t, 6 as $t).rem_euclid($vec4::splat(4 as $t))
+            );
+        });
+
         glam_test!(test_neg, {
             let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
             assert_eq!((-1 as $t, -2 as $t, -3 as $t, -4 as $t), (-a).into());
@@ -618,6 +799,31 @@ macro_rules! impl_vec4_signed_tests {
             );
         });
 
+        glam_test!(test_clamp_signed, {
+            fn vec(x: i32, y: i32, z: i32, w: i32) -> $vec4 {
+                $vec4::new(x as $t, y as $t, z as $t, w as $t)
+            }
+            let min = vec(-8, -3, 2, -5);
+            let max = vec(-1, 6, 4, -5);
+            assert_eq!(vec(-9, -9, -9, -9).clamp(min, max), vec(-8, -3, 2, -5));
+            assert_eq!(vec(-4, 0, 3, -5).clamp(min, max), vec(-4, 0, 3, -5));
+            assert_eq!(vec(9, 9, 9, 9).clamp(min, max), vec(-1, 6, 4, -5));
+            assert_eq!(vec(-4, -2, 1, 0).min_element(), -4 as $t);
+            assert_eq!(vec(-4, -2, -6, -3).max_element(), -2 as $t);
+        });
+
+        glam_test!(test_copysign_signed, {
+            let a = $new(-3 as $t, 2 as $t, -1 as $t, 4 as $t);
+            assert_eq!(
+                a.copysign($new(5 as $t, -1 as $t, 0 as $t, -7 as $t)),
+                $new(3 as $t, -2 as $t, 1 as $t, -4 as $t)
+            );
+            assert_eq!(a.copysign($vec4::ONE), a.abs());
+            assert_eq!(a.copysign($vec4::NEG_ONE), -a.abs());
+            // zero is treated as positive
+            assert_eq!(a.copysign($vec4::ZERO), a.abs());
+        });
+
         glam_test!(test_dot_signed, {
             let x = $new(1 as $t, 0 as $t, 0 as $t, 0 as $t);
             let y = $new(0 as $t, 1 as $t, 0 as $t, 0 as $t);
@@ -676,12 +882,51 @@ macro_rules! impl_vec4_eq_hash_tests {
             assert_ne!(a, c);
             assert_ne!(a_hashed, c_hashed);
         });
+
+        glam_test!(test_ord, {
+            use core::cmp::Ordering;
+            let a = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(Ordering::Equal, a.cmp(&a));
+            // earlier components take precedence, later ones only break ties
+            assert!(a < $new(2 as $t, 0 as $t, 0 as $t, 0 as $t));
+            assert!(a > $new(0 as $t, 9 as $t, 9 as $t, 9 as $t));
+            assert!(a < $new(1 as $t, 2 as $t, 3 as $t, 5 as $t));
+            assert!(a > $new(1 as $t, 2 as $t, 3 as $t, 0 as $t));
+            assert_eq!(
+                Some(Ordering::Less),
+                a.partial_cmp(&$new(1 as $t, 3 as $t, 0 as $t, 0 as $t))
+            );
+
+            // `Ord` is lexicographic while the inherent `max` is component-wise
+            let (a, b) = (
+                $new(1 as $t, 5 as $t, 0 as $t, 0 as $t),
+                $new(2 as $t, 0 as $t, 0 as $t, 0 as $t),
+            );
+            assert_eq!(core::cmp::max(a, b), b);
+            assert_eq!(a.max(b), $new(2 as $t, 5 as $t, 0 as $t, 0 as $t));
+            assert_ne!(core::cmp::max(a, b), a.max(b));
+        });
     };
 }
 
 macro_rules! impl_vec4_float_tests {
     ($t:ident, $new:ident, $vec4:ident, $vec3:ident, $vec2:ident, $mask:ident) => {
         impl_vec4_signed_tests!($t, $new, $vec4, $vec3, $vec2, $mask);
+
+        glam_test!(test_fmt_float, {
+            let a = $new(1.0, -2.5, 0.125, 1e3);
+            assert_eq!(format!("{:.2}", a), "[1.00, -2.50, 0.12, 1000.00]");
+            assert_eq!(format!("{:+5.1}", a), "[ +1.0,  -2.5,  +0.1, +1000.0]");
+        });
+
+        glam_test!(test_from_str_float, {
+            let a = $new(-1.5e10, 0.25, 0.003, 1.0);
+            assert_eq!(Ok(a), format!("{}", a).parse());
+            assert_eq!(Ok(a), "[-1.5e10, 2.5E-1, 3e-3, 1]".parse());
+            assert_eq!(Ok($vec4::INFINITY), format!("{}", $vec4::INFINITY).parse());
+            assert!(format!("{}", $vec4::NAN).parse::<$vec4>().unwrap().is_nan());
+        });
+
         impl_vec_float_normalize_tests!($t, $vec4);
 
         use core::$t::INFINITY;
@@ -734,6 +979,24 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_recip_precision, {
+            // these use a full precision division rather than an approximation
+            let v = $new(3.0, 7.0, -11.0, 0.1);
+            assert_eq!(
+                v.recip(),
+                $new(1.0 / 3.0, 1.0 / 7.0, 1.0 / -11.0, 1.0 / 0.1)
+            );
+            assert_eq!(v.length_recip(), 1.0 / v.length());
+            assert_eq!($vec4::ZERO.recip(), $vec4::splat(INFINITY));
+            assert_eq!((-$vec4::ZERO).recip(), $vec4::splat(NEG_INFINITY));
+            assert_eq!($vec4::splat(INFINITY).recip(), $vec4::ZERO);
+            assert!($vec4::NAN.recip().is_nan_mask().all());
+            // multiplying by the reciprocal is close to, but not always the same as, dividing
+            let a = $vec4::splat(10.0);
+            let b = $vec4::splat(3.0);
+            assert_approx_eq!(a / b, a * b.recip(), 1e-6);
+        });
+
         glam_test!(test_project_reject, {
             assert_eq!(
                 $new(0.0, 0.0, 0.0, 1.0),
@@ -751,12 +1014,36 @@ macro_rules! impl_vec4_float_tests {
                 $new(0.0, 1.0, 0.0, 0.0),
                 $new(0.0, 1.0, 0.0, 1.0).reject_from_normalized($new(0.0, 0.0, 0.0, 1.0))
             );
+            // the projection and rejection sum to the original vector
+            let v = $new(3.0, -2.0, 0.5, 1.0);
+            let n = $new(1.5, 4.0, -1.0, 2.0);
+            assert_approx_eq!(v.project_onto(n) + v.reject_from(n), v, 1e-6);
+            assert_approx_eq!(v.reject_from(n).dot(n), 0.0, 1e-5);
+            let n = n.normalize();
+            assert_approx_eq!(
+                v.project_onto_normalized(n) + v.reject_from_normalized(n),
+                v,
+                1e-6
+            );
             should_glam_assert!({ $vec4::ONE.project_onto($vec4::ZERO) });
             should_glam_assert!({ $vec4::ONE.reject_from($vec4::ZERO) });
             should_glam_assert!({ $vec4::ONE.project_onto_normalized($vec4::ONE) });
             should_glam_assert!({ $vec4::ONE.reject_from_normalized($vec4::ONE) });
         });
 
+        glam_test!(test_reflect_refract, {
+            let normal = $vec4::Z;
+            let incident = $new(1.0, 0.0, -1.0, 0.0).normalize();
+            assert_approx_eq!(
+                incident.reflect(normal),
+                $new(1.0, 0.0, 1.0, 0.0).normalize()
+            );
+            assert_approx_eq!(incident.refract(normal, 1.0), incident);
+            assert_eq!($vec4::X.refract(normal, 2.0), $vec4::ZERO);
+            should_glam_assert!({ $vec4::X.reflect($vec4::ONE) });
+            should_glam_assert!({ $vec4::ONE.refract(normal, 1.0) });
+        });
+
         glam_test!(test_signum, {
             assert_eq!($vec4::ZERO.signum(), $vec4::ONE);
             assert_eq!((-$vec4::ZERO).signum(), -$vec4::ONE);
@@ -768,6 +1055,16 @@ macro_rules! impl_vec4_float_tests {
         });
 
         glam_test!(test_copysign, {
+            // the sign of zero is preserved, `==` doesn't distinguish between -0.0 and 0.0
+            assert_eq!(
+                $vec4::ZERO.copysign(-$vec4::ONE).is_negative_bitmask(),
+                0b1111
+            );
+            assert_eq!((-$vec4::ZERO).copysign($vec4::ONE).is_negative_bitmask(), 0);
+            // only the sign bit of rhs is used, and NAN magnitudes remain NAN
+            assert_eq!($vec4::ONE.copysign(-$vec4::NAN), -$vec4::ONE);
+            assert_eq!((-$vec4::ONE).copysign($vec4::NAN), $vec4::ONE);
+            assert!($vec4::NAN.copysign(-$vec4::ONE).is_nan_mask().all());
             assert_eq!($vec4::ZERO.copysign(-$vec4::ZERO), -$vec4::ZERO);
             assert_eq!((-$vec4::ZERO).copysign(-$vec4::ZERO), -$vec4::ZERO);
             assert_eq!($vec4::ZERO.copysign($vec4::ZERO), $vec4::ZERO);
@@ -804,6 +1101,15 @@ macro_rules! impl_vec4_float_tests {
             assert!($vec4::splat(NAN).copysign(-$vec4::ONE).is_nan_mask().all());
         });
 
+        glam_test!(test_copysign_restores_sign, {
+            // restoring the sign after working with the magnitude is exact, including for -0.0
+            let bits = |v: $vec4| v.to_array().map(|x| x.to_bits());
+            let v = $vec4::from_fn(|i| [-0.0, 0.0, -2.5, 3.0][i]);
+            assert_eq!(bits(v), bits(v.abs().copysign(v)));
+            assert_eq!(bits(v), bits(v.abs() * v.signum()));
+            assert_eq!($vec4::from_fn(|i| [-1.0, 1.0, -1.0, 1.0][i]), v.signum());
+        });
+
         glam_test!(test_is_negative_bitmask, {
             assert_eq!($vec4::ZERO.is_negative_bitmask(), 0b0000);
             assert_eq!((-$vec4::ZERO).is_negative_bitmask(), 0b1111);
@@ -850,6 +1156,12 @@ macro_rules! impl_vec4_float_tests {
                 $vec4::new(NEG_INFINITY, INFINITY, 1.0, -1.0)
             );
             assert!($vec4::new(NAN, 0.0, 0.0, 1.0).round().x.is_nan());
+            // half-way cases round away from zero and the sign of zero is kept
+            assert_eq!($vec4::splat(2.5).round(), $vec4::splat(3.0));
+            assert_eq!($vec4::splat(-2.5).round(), $vec4::splat(-3.0));
+            assert_eq!($vec4::splat(0.49999997).round(), $vec4::ZERO);
+            assert_eq!($vec4::splat(8388609.0).round(), $vec4::splat(8388609.0));
+            assert_eq!($vec4::splat(-0.25).round().is_negative_bitmask(), 0b1111);
         });
 
         glam_test!(test_floor, {
@@ -868,6 +1180,65 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_trunc, {
+            assert_eq!(
+                $vec4::new(1.5, -1.5, 2.5, -2.5).trunc(),
+                $vec4::new(1.0, -1.0, 2.0, -2.0)
+            );
+            assert_eq!($vec4::splat(INFINITY).trunc(), $vec4::splat(INFINITY));
+            assert_eq!(
+                $vec4::splat(NEG_INFINITY).trunc(),
+                $vec4::splat(NEG_INFINITY)
+            );
+            assert!($vec4::NAN.trunc().is_nan_mask().all());
+            assert_eq!($vec4::splat(-2000000.123).trunc(), $vec4::splat(-2000000.0));
+            // the sign of zero is kept
+            assert_eq!($vec4::splat(-0.5).trunc().is_negative_bitmask(), 0b1111);
+        });
+
+        glam_test!(test_floor_round_ceil_to_ivec, {
+            use glam::{IVec4, UVec4};
+            let v = $vec4::new(-1.5, 2.5, -0.25, 0.75);
+            // `as` truncates towards zero, which is wrong for negative grid coordinates
+            assert_eq!(IVec4::new(-1, 2, 0, 0), v.as_ivec4());
+            assert_eq!(IVec4::new(-2, 2, -1, 0), v.floor_to_ivec());
+            assert_eq!(IVec4::new(-2, 3, 0, 1), v.round_to_ivec());
+            assert_eq!(IVec4::new(-1, 3, 0, 1), v.ceil_to_ivec());
+            // out of range elements saturate and NAN becomes zero
+            assert_eq!(IVec4::MIN, $vec4::splat(-1e20).floor_to_ivec());
+            assert_eq!(IVec4::MAX, $vec4::splat(1e20).ceil_to_ivec());
+            assert_eq!(IVec4::ZERO, $vec4::NAN.round_to_ivec());
+            assert_eq!(UVec4::ZERO, $vec4::splat(-1.5).as_uvec4());
+        });
+
+        glam_test!(test_rgba_u8, {
+            assert_eq!($new(0.0, 0.5, 1.0, 0.2).to_rgba_u8(), [0, 128, 255, 51]);
+            // out of range and NaN elements are clamped
+            assert_eq!(
+                $new(-1.0, 2.0, $t::NAN, $t::INFINITY).to_rgba_u8(),
+                [0, 255, 0, 255]
+            );
+            // rounds to nearest
+            assert_eq!(
+                $new(0.498 / 255.0, 0.502 / 255.0, 254.6 / 255.0, 127.4 / 255.0).to_rgba_u8(),
+                [0, 1, 255, 127]
+            );
+
+            assert_eq!(
+                $vec4::from_rgba_u8([0, 51, 255, 102]),
+                $new(0.0, 0.2, 1.0, 0.4)
+            );
+            for i in 0..=255 {
+                let rgba = [i, 255 - i, i / 2, 255];
+                assert_eq!($vec4::from_rgba_u8(rgba).to_rgba_u8(), rgba);
+            }
+
+            // packed with red in the most significant byte
+            assert_eq!($new(1.0, 0.0, 0.2, 1.0).to_rgba_u32(), 0xff0033ff);
+            assert_eq!($vec4::from_rgba_u32(0xff0033ff), $new(1.0, 0.0, 0.2, 1.0));
+            assert_eq!($vec4::from_rgba_u32(0x12345678).to_rgba_u32(), 0x12345678);
+        });
+
         glam_test!(test_fract, {
             assert_approx_eq!(
                 $vec4::new(1.35, 1.5, -1.5, 1.999).fract(),
@@ -878,6 +1249,8 @@ macro_rules! impl_vec4_float_tests {
                 $vec4::new(0.0, 0.877, 0.123, 0.9),
                 0.002
             );
+            // negative elements result in a positive fraction, unlike the scalar `fract`
+            assert_eq!($vec4::splat(-0.25).fract(), $vec4::splat(0.75));
         });
 
         glam_test!(test_ceil, {
@@ -896,6 +1269,40 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_angle_between, {
+            let angle =
+                $vec4::new(1.0, 0.0, 1.0, 0.0).angle_between($vec4::new(1.0, 1.0, 0.0, 0.0));
+            assert_approx_eq!(core::$t::consts::FRAC_PI_3, angle, 1e-6);
+
+            let angle =
+                $vec4::new(10.0, 0.0, 0.0, 10.0).angle_between($vec4::new(0.0, 5.0, 0.0, 0.0));
+            assert_approx_eq!(core::$t::consts::FRAC_PI_2, angle, 1e-6);
+
+            let angle = $vec4::W.angle_between($vec4::NEG_W);
+            assert_approx_eq!(core::$t::consts::PI, angle, 1e-6);
+
+            // nearly parallel vectors don't produce NaN due to rounding
+            for v in [
+                $vec4::new(1.0, 1.0, 1.0, 1.0),
+                $vec4::new(0.1, 3.0, -0.7, 2.0),
+                $vec4::new(1e-3, 1e-3, 1e-3, 1e-3),
+            ] {
+                assert_approx_eq!(v.angle_between(v * 3.0), 0.0, 1e-3);
+                assert_approx_eq!(v.angle_between(-v * 3.0), core::$t::consts::PI, 1e-3);
+            }
+
+            // small angles near `0` and `π` keep their precision
+            let small = (1e-4 as $t).atan();
+            let x = $vec4::X;
+            assert_approx_eq!(
+                small,
+                x.angle_between($vec4::new(1.0, 0.0, 0.0, 1e-4)),
+                1e-10
+            );
+            let angle = x.angle_between($vec4::new(-1.0, 0.0, 1e-4, 0.0));
+            assert_approx_eq!(core::$t::consts::PI - small, angle, 1e-6);
+        });
+
         glam_test!(test_lerp, {
             let v0 = $vec4::new(-1.0, -1.0, -1.0, -1.0);
             let v1 = $vec4::new(1.0, 1.0, 1.0, 1.0);
@@ -904,6 +1311,80 @@ macro_rules! impl_vec4_float_tests {
             assert_approx_eq!($vec4::ZERO, v0.lerp(v1, 0.5));
         });
 
+        glam_test!(test_smooth_lerp, {
+            let a = $vec4::new(1.0, 2.0, -3.0, 0.0);
+            let b = $vec4::new(5.0, -2.0, 1.0, 8.0);
+            assert_eq!(a, a.smooth_lerp(b, 0.0));
+            assert_eq!(b, a.smooth_lerp(b, 1.0));
+            assert_approx_eq!(a.midpoint(b), a.smooth_lerp(b, 0.5));
+            // eased by 3t^2 - 2t^3
+            assert_approx_eq!(a.lerp(b, 0.15625), a.smooth_lerp(b, 0.25));
+            assert_approx_eq!(a.lerp(b, 0.84375), a.smooth_lerp(b, 0.75));
+            // `s` is clamped
+            assert_eq!(a, a.smooth_lerp(b, -1.0));
+            assert_eq!(b, a.smooth_lerp(b, 2.0));
+        });
+
+        glam_test!(test_move_towards, {
+            // the distance between v0 and v1 is 5.0
+            let v0 = $vec4::new(1.0, 2.0, 3.0, 4.0);
+            let v1 = $vec4::new(4.0, 6.0, 3.0, 4.0);
+            assert_approx_eq!(
+                v0.move_towards(v1, 3.0),
+                $vec4::new(2.8, 4.4, 3.0, 4.0),
+                1e-6
+            );
+            assert_eq!(v0.move_towards(v1, 5.0), v1);
+            // doesn't overshoot
+            assert_eq!(v0.move_towards(v1, 10.0), v1);
+            // zero or negative max_delta doesn't move
+            assert_eq!(v0.move_towards(v1, 0.0), v0);
+            assert_eq!(v0.move_towards(v1, -1.0), v0);
+            // no NaN when already at the target
+            assert_eq!(v0.move_towards(v0, 1.0), v0);
+            assert_eq!($vec4::ZERO.move_towards($vec4::ZERO, 1.0), $vec4::ZERO);
+        });
+
+        glam_test!(test_midpoint, {
+            let v0 = $vec4::new(1.0, 2.0, 3.0, 4.0);
+            let v1 = $vec4::new(3.0, 6.0, 9.0, 12.0);
+            assert_eq!(v0.midpoint(v1), $vec4::new(2.0, 4.0, 6.0, 8.0));
+            assert_eq!(v0.midpoint(v1), v0.lerp(v1, 0.5));
+            assert_eq!(v0.midpoint(v1), v1.midpoint(v0));
+            assert_eq!(v0.midpoint(v0), v0);
+        });
+
+        glam_test!(test_abs_diff_eq, {
+            let a = $vec4::ONE;
+            assert!(a.abs_diff_eq(a, 0.0));
+            // just under, at and just over the threshold
+            assert!(a.abs_diff_eq(a + $vec4::X * 0.09, 0.1));
+            assert!(a.abs_diff_eq(a - $vec4::Y * 0.5, 0.5));
+            assert!(!a.abs_diff_eq(a + $vec4::X * 0.11, 0.1));
+            assert!(!a.abs_diff_eq(a - $vec4::Y * 0.11, 0.1));
+            assert!(!a.abs_diff_eq($vec4::NAN, 1.0));
+        });
+
+        glam_test!(test_float_consts, {
+            assert_eq!($vec4::INFINITY, $vec4::splat(<$t>::INFINITY));
+            assert_eq!($vec4::NEG_INFINITY, $vec4::splat(<$t>::NEG_INFINITY));
+            assert!($vec4::NAN.is_nan_mask().all());
+
+            // bounds of a point set
+            let points = [
+                $vec4::new(1.0, -2.0, 3.0, 0.0),
+                $vec4::new(-3.0, 4.0, 0.0, 1.0),
+                $vec4::new(0.5, 0.0, -5.0, -1.0),
+            ];
+            let (min, max) = points
+                .iter()
+                .fold(($vec4::INFINITY, $vec4::NEG_INFINITY), |(min, max), &p| {
+                    (min.min(p), max.max(p))
+                });
+            assert_eq!($vec4::new(-3.0, -2.0, -5.0, -1.0), min);
+            assert_eq!($vec4::new(1.0, 4.0, 3.0, 1.0), max);
+        });
+
         glam_test!(test_is_finite, {
             assert!($vec4::new(0.0, 0.0, 0.0, 0.0).is_finite());
             assert!($vec4::new(-1e-10, 1.0, 1e10, 42.0).is_finite());
@@ -913,6 +1394,18 @@ macro_rules! impl_vec4_float_tests {
             assert!(!$vec4::new(0.0, 0.0, 0.0, NAN).is_finite());
         });
 
+        glam_test!(test_is_nan, {
+            assert!(!$vec4::ZERO.is_nan());
+            assert!(!$vec4::new(INFINITY, NEG_INFINITY, 0.0, 0.0).is_nan());
+            assert!($vec4::new(0.0, 0.0, 0.0, NAN).is_nan());
+            assert!($vec4::NAN.is_nan());
+            assert_eq!(
+                $vec4::new(NAN, INFINITY, 0.0, NAN).is_nan_mask(),
+                $mask::new(true, false, false, true)
+            );
+            assert_eq!($vec4::NAN.is_nan_mask(), $mask::new(true, true, true, true));
+        });
+
         glam_test!(test_powf, {
             assert_eq!(
                 $vec4::new(2.0, 4.0, 8.0, 16.0).powf(2.0),
@@ -933,6 +1426,169 @@ macro_rules! impl_vec4_float_tests {
             );
         });
 
+        glam_test!(test_distance_3_4_5, {
+            let a = $vec4::new(1.0, -2.0, 0.5, 7.0);
+            let b = a + $vec4::new(0.0, 3.0, 0.0, -4.0);
+            assert_eq!(5.0, a.distance(b));
+            assert_eq!(5.0, b.distance(a));
+            assert_eq!(25.0, a.distance_squared(b));
+            assert_eq!(25.0, b.distance_squared(a));
+            assert_eq!(0.0, a.distance(a));
+        });
+
+        glam_test!(test_min_max_position_nan, {
+            // NaN elements are ignored unless all elements are NaN
+            assert_eq!(2, $vec4::new(NAN, 2.0, 1.0, NAN).min_position());
+            assert_eq!(1, $vec4::new(NAN, 2.0, 1.0, NAN).max_position());
+            assert_eq!(2, $vec4::new(1.0, NAN, 0.0, 2.0).min_position());
+            assert_eq!(3, $vec4::new(1.0, NAN, 0.0, 2.0).max_position());
+            assert_eq!(0, $vec4::NAN.min_position());
+            assert_eq!(0, $vec4::NAN.max_position());
+        });
+
+        glam_test!(test_ln, {
+            assert_approx_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).ln(),
+                $vec4::new(0.0, (2.0 as $t).ln(), (3.0 as $t).ln(), (4.0 as $t).ln())
+            );
+            assert_approx_eq!(
+                $vec4::new(2.0, 3.0, 4.0, 5.0).exp().ln(),
+                $vec4::new(2.0, 3.0, 4.0, 5.0),
+                1e-5
+            );
+            let v = $vec4::new(0.0, -1.0, 1.0, INFINITY).ln();
+            assert_eq!(v.x, NEG_INFINITY);
+            assert!(v.y.is_nan());
+            assert_eq!(v.z, 0.0);
+            assert_eq!(v.w, INFINITY);
+        });
+
+        glam_test!(test_select_bits, {
+            // select copies elements unchanged, including signed zeros and NaN payloads
+            let nan = $t::from_bits(<$t>::NAN.to_bits() | 1);
+            let a = $vec4::from_fn(|i| if i % 2 == 0 { -0.0 } else { nan });
+            let b = $vec4::from_fn(|i| i as $t + 1.0);
+            let bits = |v: $vec4| v.to_array().map(|x| x.to_bits());
+            let all = $vec4::ZERO.cmpeq($vec4::ZERO);
+            let none = $vec4::ZERO.cmpne($vec4::ZERO);
+            assert_eq!(bits(a), bits($vec4::select(all, a, b)));
+            assert_eq!(bits(a), bits($vec4::select(none, b, a)));
+
+            // branch free min and max using comparison masks
+            let c = $vec4::from_fn(|i| if i % 2 == 0 { 3.0 } else { -3.0 });
+            assert_eq!(b.min(c), $vec4::select(b.cmplt(c), b, c));
+            assert_eq!(b.max(c), $vec4::select(b.cmpgt(c), b, c));
+
+            // branch free clamping and sign handling
+            let min = $vec4::splat(-1.5);
+            let max = $vec4::splat(1.5);
+            let clamped = $vec4::select(c.cmplt(min), min, $vec4::select(c.cmpgt(max), max, c));
+            assert_eq!(c.clamp(min, max), clamped);
+            assert_eq!(c.abs(), $vec4::select(c.cmplt($vec4::ZERO), -c, c));
+        });
+
+        glam_test!(test_step_smoothstep, {
+            fn step(edge: $t, x: $t) -> $t {
+                if x < edge {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            fn smoothstep(edge0: $t, edge1: $t, x: $t) -> $t {
+                let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+                t * t * (3.0 - 2.0 * t)
+            }
+            let edge0 = $vec4::from_fn(|i| i as $t - 1.0);
+            let edge1 = edge0 + 2.0;
+            // below, at, inside and above the edges
+            for x in [-3.0, -1.0, -0.5, 0.0, 0.25, 1.0, 1.5, 2.0, 4.0] {
+                let v = $vec4::splat(x);
+                let expected = $vec4::from_fn(|i| step(edge0[i], x));
+                assert_eq!(expected, v.step(edge0));
+                let expected = $vec4::from_fn(|i| smoothstep(edge0[i], edge1[i], x));
+                assert_approx_eq!(expected, v.smoothstep(edge0, edge1), 1e-6);
+            }
+            assert_eq!($vec4::ONE, $vec4::ONE.step($vec4::ONE));
+            assert_eq!(
+                $vec4::ZERO,
+                $vec4::splat(-10.0).smoothstep($vec4::ZERO, $vec4::ONE)
+            );
+            assert_eq!(
+                $vec4::ONE,
+                $vec4::splat(10.0).smoothstep($vec4::ZERO, $vec4::ONE)
+            );
+            assert_eq!(
+                $vec4::splat(0.5),
+                $vec4::splat(0.5).smoothstep($vec4::ZERO, $vec4::ONE)
+            );
+        });
+
+        glam_test!(test_to_degrees_radians, {
+            for i in -720..=720 {
+                let x = i as $t * 0.37;
+                let v = $vec4::splat(x);
+                assert_eq!($vec4::splat(x.to_degrees()), v.to_degrees());
+                assert_eq!($vec4::splat(x.to_radians()), v.to_radians());
+            }
+            let v = $vec4::from_fn(|i| (i as $t + 1.0) * 90.0);
+            assert_eq!(v.to_radians(), v.map(|x| x.to_radians()));
+            assert_approx_eq!(v, v.to_radians().to_degrees(), 1e-4);
+            assert_approx_eq!(
+                $vec4::splat(core::$t::consts::PI),
+                $vec4::splat(180.0).to_radians()
+            );
+        });
+
+        glam_test!(test_div_rem_euclid, {
+            let a = $vec4::new(-7.0, 7.0, -7.0, 7.0);
+            let b = $vec4::new(4.0, -4.0, -4.0, 4.0);
+            assert_eq!(a.div_euclid(b), $vec4::new(-2.0, -1.0, 2.0, 1.0));
+            assert_eq!(a.rem_euclid(b), $vec4::new(1.0, 3.0, 1.0, 3.0));
+            for (x, y) in [
+                (-7.5, 2.0),
+                (7.5, -2.0),
+                (-7.5, -2.0),
+                (-0.5, 3.0),
+                (-1e-30, 1.0),
+            ] {
+                let v = $vec4::new(x, y, x, -y).rem_euclid($vec4::new(y, x, -y, x));
+                let e = $vec4::new(
+                    x.rem_euclid(y),
+                    y.rem_euclid(x),
+                    x.rem_euclid(-y),
+                    (-y).rem_euclid(x),
+                );
+                assert_eq!(v, e);
+                let v = $vec4::new(x, y, x, -y).div_euclid($vec4::new(y, x, -y, x));
+                let e = $vec4::new(
+                    x.div_euclid(y),
+                    y.div_euclid(x),
+                    x.div_euclid(-y),
+                    (-y).div_euclid(x),
+                );
+                assert_eq!(v, e);
+            }
+            assert_eq!(a.div_euclid(b) * b + a.rem_euclid(b), a);
+            // `%` truncates where `rem_euclid` does not
+            for (x, y) in [(-7.5, 2.0), (7.5, -2.0), (-7.5, -2.0), (-0.5, 3.0)] {
+                let v = $vec4::splat(x) % $vec4::splat(y);
+                assert_eq!(v, $vec4::splat(x % y));
+                assert_eq!(v.rem_euclid($vec4::splat(y)), $vec4::splat(x.rem_euclid(y)));
+            }
+        });
+
+        glam_test!(test_rem_signed_zero, {
+            // like the scalar `%`, zero results have the sign of the dividend
+            let r = $vec4::splat(-0.0) % $vec4::ONE;
+            assert_eq!(r.is_negative_bitmask(), 0b1111);
+            let r = $vec4::splat(-1.0) % 1.0;
+            assert_eq!(r.is_negative_bitmask(), 0b1111);
+            assert_eq!(r, $vec4::ZERO);
+            let r = $vec4::splat(1.0) % -1.0;
+            assert_eq!(r.is_negative_bitmask(), 0);
+        });
+
         glam_test!(test_clamp_length, {
             // Too long gets shortened
             assert_eq!(
@@ -949,7 +1605,10 @@ macro_rules! impl_vec4_float_tests {
                 $vec4::new(0.6, 0.8, 0.0, 0.0).clamp_length(10.0, 20.0),
                 $vec4::new(6.0, 8.0, 0.0, 0.0) // lengthened to length 10.0
             );
+            // Zero length is unchanged
+            assert_eq!($vec4::ZERO.clamp_length(1.0, 2.0), $vec4::ZERO);
             should_glam_assert!({ $vec4::ONE.clamp_length(1.0, 0.0) });
+            should_glam_assert!({ $vec4::ONE.clamp_length(-1.0, 1.0) });
         });
 
         glam_test!(test_clamp_length_max, {
@@ -963,6 +1622,12 @@ macro_rules! impl_vec4_float_tests {
                 $vec4::new(2.0, 1.0, 0.0, 0.0).clamp_length_max(5.0),
                 $vec4::new(2.0, 1.0, 0.0, 0.0) // unchanged
             );
+            // Exactly at the limit is unchanged
+            assert_eq!($vec4::X.clamp_length_max(1.0), $vec4::X);
+            // Zero length is unchanged
+            assert_eq!($vec4::ZERO.clamp_length_max(1.0), $vec4::ZERO);
+            assert_eq!($vec4::ZERO.clamp_length_max(0.0), $vec4::ZERO);
+            should_glam_assert!({ $vec4::ONE.clamp_length_max(-1.0) });
         });
 
         glam_test!(test_clamp_length_min, {
@@ -976,6 +1641,9 @@ macro_rules! impl_vec4_float_tests {
                 $vec4::new(0.6, 0.8, 0.0, 0.0).clamp_length_min(10.0),
                 $vec4::new(6.0, 8.0, 0.0, 0.0) // lengthened to length 10.0
             );
+            // Zero length is unchanged
+            assert_eq!($vec4::ZERO.clamp_length_min(1.0), $vec4::ZERO);
+            should_glam_assert!({ $vec4::ONE.clamp_length_min(-1.0) });
         });
 
         glam_test!(test_mul_add, {
@@ -990,6 +1658,97 @@ macro_rules! impl_vec4_float_tests {
     };
 }
 
+macro_rules! impl_vec4_int_tests {
+    ($t:ident, $new:ident, $vec4:ident) => {
+        glam_test!(test_rem_by_zero, {
+            should_panic!({
+                $new(5 as $t, 5 as $t, 5 as $t, 5 as $t) % $new(1 as $t, 1 as $t, 1 as $t, 0 as $t)
+            });
+            should_panic!({ $new(5 as $t, 5 as $t, 5 as $t, 5 as $t) % 0 as $t });
+        });
+
+        glam_test!(test_div_rem_euclid_int, {
+            let a = $vec4::splat(7 as $t);
+            let b = $vec4::splat(4 as $t);
+            assert_eq!($vec4::splat(1 as $t), a.div_euclid(b));
+            assert_eq!($vec4::splat(3 as $t), a.rem_euclid(b));
+            should_panic!({ $vec4::ONE.div_euclid($vec4::X) });
+            should_panic!({ $vec4::ONE.rem_euclid($vec4::X) });
+        });
+
+        glam_test!(test_element_sum_product_wrapping, {
+            assert_eq!($t::MIN, $new($t::MAX, 1, 0, 0).element_sum());
+            assert_eq!(
+                $t::MAX.wrapping_mul(2),
+                $new($t::MAX, 2, 1, 1).element_product()
+            );
+        });
+
+        glam_test!(test_wrapping_saturating_checked, {
+            let max = $new(<$t>::MAX, 1 as $t, 2 as $t, 3 as $t);
+            let min = $new(<$t>::MIN, 1 as $t, 2 as $t, 3 as $t);
+            let one = $new(1 as $t, 1 as $t, 1 as $t, 1 as $t);
+
+            assert_eq!(
+                $new(<$t>::MIN, 2 as $t, 3 as $t, 4 as $t),
+                max.wrapping_add(one)
+            );
+            assert_eq!(
+                $new(<$t>::MAX, 0 as $t, 1 as $t, 2 as $t),
+                min.wrapping_sub(one)
+            );
+
+            assert_eq!(
+                $new(<$t>::MAX, 2 as $t, 3 as $t, 4 as $t),
+                max.saturating_add(one)
+            );
+            assert_eq!(
+                $new(<$t>::MIN, 0 as $t, 1 as $t, 2 as $t),
+                min.saturating_sub(one)
+            );
+
+            // any overflowing element gives `None`
+            assert_eq!(None, max.checked_add(one));
+            assert_eq!(None, min.checked_sub(one));
+            assert_eq!(Some(max), (max - one).checked_add(one));
+            assert_eq!(Some(min), (min + one).checked_sub(one));
+            assert_eq!(Some($vec4::MAX), $vec4::MAX.checked_sub($vec4::ZERO));
+        });
+
+        glam_test!(test_midpoint_int, {
+            assert_eq!(
+                $new(1 as $t, 2 as $t, 3 as $t, 4 as $t)
+                    .midpoint($new(3 as $t, 6 as $t, 9 as $t, 12 as $t)),
+                $new(2 as $t, 4 as $t, 6 as $t, 8 as $t)
+            );
+            // rounds towards negative infinity
+            assert_eq!(
+                $new(0 as $t, 1 as $t, 2 as $t, 3 as $t)
+                    .midpoint($new(1 as $t, 4 as $t, 3 as $t, 6 as $t)),
+                $new(0 as $t, 2 as $t, 2 as $t, 4 as $t)
+            );
+            // doesn't overflow
+            assert_eq!(
+                $vec4::splat($t::MAX).midpoint($vec4::splat($t::MAX)),
+                $vec4::splat($t::MAX)
+            );
+            assert_eq!(
+                $vec4::splat($t::MAX).midpoint($vec4::splat($t::MAX - 1)),
+                $vec4::splat($t::MAX - 1)
+            );
+            assert_eq!(
+                $vec4::splat($t::MAX - 2).midpoint($vec4::splat($t::MAX)),
+                $vec4::splat($t::MAX - 1)
+            );
+            // for signed types the exact result is -0.5 which rounds down to -1
+            assert_eq!(
+                $vec4::splat($t::MIN).midpoint($vec4::splat($t::MAX)),
+                $vec4::splat($t::MIN / 2 + $t::MAX / 2)
+            );
+        });
+    };
+}
+
 macro_rules! impl_vec4_scalar_shift_op_test {
     ($vec4:ident, $t_min:literal, $t_max:literal, $rhs_min:literal, $rhs_max:literal) => {
         glam_test!(test_vec4_scalar_shift_ops, {
@@ -1002,10 +1761,16 @@ macro_rules! impl_vec4_scalar_shift_op_test {
                                     $vec4::new(x, y, z, w) << rhs,
                                     $vec4::new(x << rhs, y << rhs, z << rhs, w << rhs)
                                 );
+                                let mut v = $vec4::new(x, y, z, w);
+                                v <<= rhs;
+                                assert_eq!(v, $vec4::new(x << rhs, y << rhs, z << rhs, w << rhs));
                                 assert_eq!(
                                     $vec4::new(x, y, z, w) >> rhs,
                                     $vec4::new(x >> rhs, y >> rhs, z >> rhs, w >> rhs)
                                 );
+                                let mut v = $vec4::new(x, y, z, w);
+                                v >>= rhs;
+                                assert_eq!(v, $vec4::new(x >> rhs, y >> rhs, z >> rhs, w >> rhs));
                             }
                         }
                     }
@@ -1060,11 +1825,23 @@ macro_rules! impl_vec4_shift_op_test {
                                                     << $rhs::new(x2, y2, z2, w2),
                                                 $vec4::new(x1 << x2, y1 << y2, z1 << z2, w1 << w2)
                                             );
+                                            let mut v = $vec4::new(x1, y1, z1, w1);
+                                            v <<= $rhs::new(x2, y2, z2, w2);
+                                            assert_eq!(
+                                                v,
+                                                $vec4::new(x1 << x2, y1 << y2, z1 << z2, w1 << w2)
+                                            );
                                             assert_eq!(
                                                 $vec4::new(x1, y1, z1, w1)
                                                     >> $rhs::new(x2, y2, z2, w2),
                                                 $vec4::new(x1 >> x2, y1 >> y2, z1 >> z2, w1 >> w2)
                                             );
+                                            let mut v = $vec4::new(x1, y1, z1, w1);
+                                            v >>= $rhs::new(x2, y2, z2, w2);
+                                            assert_eq!(
+                                                v,
+                                                $vec4::new(x1 >> x2, y1 >> y2, z1 >> z2, w1 >> w2)
+                                            );
                                         }
                                     }
                                 }
@@ -1102,14 +1879,23 @@ macro_rules! impl_vec4_scalar_bit_op_tests {
                                     $vec4::new(x, y, z, w) & rhs,
                                     $vec4::new(x & rhs, y & rhs, z & rhs, w & rhs)
                                 );
+                                let mut v = $vec4::new(x, y, z, w);
+                                v &= rhs;
+                                assert_eq!(v, $vec4::new(x & rhs, y & rhs, z & rhs, w & rhs));
                                 assert_eq!(
                                     $vec4::new(x, y, z, w) | rhs,
                                     $vec4::new(x | rhs, y | rhs, z | rhs, w | rhs)
                                 );
+                                let mut v = $vec4::new(x, y, z, w);
+                                v |= rhs;
+                                assert_eq!(v, $vec4::new(x | rhs, y | rhs, z | rhs, w | rhs));
                                 assert_eq!(
                                     $vec4::new(x, y, z, w) ^ rhs,
                                     $vec4::new(x ^ rhs, y ^ rhs, z ^ rhs, w ^ rhs)
                                 );
+                                let mut v = $vec4::new(x, y, z, w);
+                                v ^= rhs;
+                                assert_eq!(v, $vec4::new(x ^ rhs, y ^ rhs, z ^ rhs, w ^ rhs));
                             }
                         }
                     }
@@ -1137,16 +1923,34 @@ macro_rules! impl_vec4_bit_op_tests {
                                                     & $vec4::new(x2, y2, z2, w2),
                                                 $vec4::new(x1 & x2, y1 & y2, z1 & z2, w1 & w2)
                                             );
+                                            let mut v = $vec4::new(x1, y1, z1, w1);
+                                            v &= $vec4::new(x2, y2, z2, w2);
+                                            assert_eq!(
+                                                v,
+                                                $vec4::new(x1 & x2, y1 & y2, z1 & z2, w1 & w2)
+                                            );
                                             assert_eq!(
                                                 $vec4::new(x1, y1, z1, w1)
                                                     | $vec4::new(x2, y2, z2, w2),
                                                 $vec4::new(x1 | x2, y1 | y2, z1 | z2, w1 | w2)
                                             );
+                                            let mut v = $vec4::new(x1, y1, z1, w1);
+                                            v |= $vec4::new(x2, y2, z2, w2);
+                                            assert_eq!(
+                                                v,
+                                                $vec4::new(x1 | x2, y1 | y2, z1 | z2, w1 | w2)
+                                            );
                                             assert_eq!(
                                                 $vec4::new(x1, y1, z1, w1)
                                                     ^ $vec4::new(x2, y2, z2, w2),
                                                 $vec4::new(x1 ^ x2, y1 ^ y2, z1 ^ z2, w1 ^ w2)
                                             );
+                                            let mut v = $vec4::new(x1, y1, z1, w1);
+                                            v ^= $vec4::new(x2, y2, z2, w2);
+                                            assert_eq!(
+                                                v,
+                                                $vec4::new(x1 ^ x2, y1 ^ y2, z1 ^ z2, w1 ^ w2)
+                                            );
                                         }
                                     }
                                 }
@@ -1168,6 +1972,24 @@ mod vec4 {
     use glam::BVec4A;
     use glam::{vec4, Vec2, Vec3, Vec4};
 
+    #[cfg(not(feature = "scalar-math"))]
+    glam_test!(test_mask_hash_matches_bvec4, {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash<T: Hash>(v: T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            v.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        // masks hash their bitmask so the result does not depend on the storage
+        let a = glam::BVec4A::new(true, false, true, false);
+        let b = glam::BVec4::new(true, false, true, false);
+        assert_eq!(a.bitmask(), b.bitmask());
+        assert_eq!(hash(a), hash(b));
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(16, mem::size_of::<Vec4>());
@@ -1333,6 +2155,94 @@ mod vec4 {
             U64Vec4::new(1, 2, 3, 4).as_i64vec4()
         );
         assert_eq!(UVec4::new(1, 2, 3, 4), U64Vec4::new(1, 2, 3, 4).as_uvec4());
+
+        // every f32 survives the round trip through f64
+        let v = Vec4::new(0.1, -2.5e-7, 1e30, -3.0);
+        assert_eq!(v, v.as_dvec4().as_vec4());
+        // but only f64 values that are representable as f32 do
+        let v = DVec4::new(0.5, -1.25, 1024.0, -3.0);
+        assert_eq!(v, v.as_vec4().as_dvec4());
+        assert_ne!(DVec4::splat(0.1), DVec4::splat(0.1).as_vec4().as_dvec4());
+    });
+
+    glam_test!(test_int_from, {
+        use glam::{I64Vec4, IVec4, U64Vec4, UVec4};
+        // lossless conversions implement `From`
+        assert_eq!(
+            I64Vec4::new(-1, i32::MIN as i64, i32::MAX as i64, 2),
+            I64Vec4::from(IVec4::new(-1, i32::MIN, i32::MAX, 2))
+        );
+        assert_eq!(
+            I64Vec4::new(1, u32::MAX as i64, 0, 2),
+            I64Vec4::from(UVec4::new(1, u32::MAX, 0, 2))
+        );
+        assert_eq!(
+            U64Vec4::new(1, u32::MAX as u64, 0, 2),
+            U64Vec4::from(UVec4::new(1, u32::MAX, 0, 2))
+        );
+    });
+
+    glam_test!(test_int_try_from, {
+        use glam::{I64Vec4, IVec4, U64Vec4, UVec4};
+        assert_eq!(Ok(UVec4::splat(2)), UVec4::try_from(IVec4::splat(2)));
+        assert_eq!(
+            Ok(IVec4::new(-1, i32::MIN, i32::MAX, 2)),
+            IVec4::try_from(I64Vec4::new(-1, i32::MIN as i64, i32::MAX as i64, 2))
+        );
+        // the error reports the first element that is out of range
+        let index = |e: glam::TryFromIntVecError| e.index();
+        assert_eq!(
+            Err(1),
+            UVec4::try_from(IVec4::new(1, -2, -3, 4)).map_err(index)
+        );
+        assert_eq!(
+            Err(0),
+            IVec4::try_from(UVec4::splat(u32::MAX)).map_err(index)
+        );
+        assert_eq!(
+            Err(3),
+            IVec4::try_from(I64Vec4::new(0, 0, 0, i64::MIN)).map_err(index)
+        );
+        assert_eq!(Err(0), U64Vec4::try_from(I64Vec4::NEG_ONE).map_err(index));
+        assert_eq!(Err(0), I64Vec4::try_from(U64Vec4::MAX).map_err(index));
+        assert_eq!(Err(0), UVec4::try_from(U64Vec4::MAX).map_err(index));
+        assert_eq!(
+            "element `y` is out of range of the target type",
+            UVec4::try_from(-IVec4::Y).unwrap_err().to_string()
+        );
+    });
+
+    glam_test!(test_int_saturating_as, {
+        use glam::{I64Vec4, IVec4, U64Vec4, UVec4};
+        // `as` wraps around where the saturating casts clamp to the target range
+        let v = IVec4::new(-1, 5, i32::MIN, i32::MAX);
+        assert_eq!(
+            UVec4::new(u32::MAX, 5, 1 << 31, i32::MAX as u32),
+            v.as_uvec4()
+        );
+        assert_eq!(
+            UVec4::new(0, 5, 0, i32::MAX as u32),
+            v.saturating_as_uvec4()
+        );
+        assert_eq!(
+            U64Vec4::new(0, 5, 0, i32::MAX as u64),
+            v.saturating_as_u64vec4()
+        );
+        assert_eq!(IVec4::splat(i32::MAX), UVec4::MAX.saturating_as_ivec4());
+        let v = I64Vec4::new(i64::MAX, -5, i64::MIN, 5);
+        assert_eq!(
+            IVec4::new(i32::MAX, -5, i32::MIN, 5),
+            v.saturating_as_ivec4()
+        );
+        assert_eq!(UVec4::new(u32::MAX, 0, 0, 5), v.saturating_as_uvec4());
+        assert_eq!(
+            U64Vec4::new(i64::MAX as u64, 0, 0, 5),
+            v.saturating_as_u64vec4()
+        );
+        let v = U64Vec4::new(u64::MAX, 5, 0, 1);
+        assert_eq!(IVec4::new(i32::MAX, 5, 0, 1), v.saturating_as_ivec4());
+        assert_eq!(UVec4::new(u32::MAX, 5, 0, 1), v.saturating_as_uvec4());
+        assert_eq!(I64Vec4::new(i64::MAX, 5, 0, 1), v.saturating_as_i64vec4());
     });
 
     glam_test!(test_vec3a, {
@@ -1347,6 +2257,157 @@ mod vec4 {
         );
     });
 
+    glam_test!(test_truncate_a_extend, {
+        use glam::Vec3A;
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(Vec3A::new(1.0, 2.0, 3.0), v.truncate_a());
+        assert_eq!(Vec3A::from(v.truncate()), v.truncate_a());
+        assert_eq!(v, v.truncate_a().extend(4.0));
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 5.0), v.truncate_a().extend(5.0));
+
+        // the discarded `w` must not affect the `Vec3A`
+        let v = Vec4::new(1.0, 2.0, 3.0, f32::NAN);
+        let a = v.truncate_a();
+        assert!(!a.is_nan());
+        assert!(a.is_finite());
+        assert_eq!(14.0, a.length_squared());
+        assert_eq!(Vec3A::new(1.0, 2.0, 3.0), a);
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 4.0), a.extend(4.0));
+    });
+
+    glam_test!(test_swizzle_vec3a, {
+        use glam::{Vec3A, Vec4Swizzles, Vec4ToVec3ASwizzles};
+        let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        // dropped lanes
+        assert_eq!(Vec3A::new(1.0, 2.0, 3.0), v.xyz_a());
+        assert_eq!(Vec3A::new(2.0, 3.0, 4.0), v.yzw_a());
+        assert_eq!(Vec3A::new(4.0, 1.0, 2.0), v.wxy_a());
+        // duplicated lanes
+        assert_eq!(Vec3A::new(4.0, 4.0, 4.0), v.www_a());
+        assert_eq!(Vec3A::new(1.0, 4.0, 1.0), v.xwx_a());
+        // matches converting the `Vec3` swizzle
+        assert_eq!(Vec3A::from(v.zyx()), v.zyx_a());
+        assert_eq!(Vec3A::from(v.wzz()), v.wzz_a());
+        // the unused padding lane must not leak into the result
+        assert_eq!([4.0, 3.0, 2.0], v.wzy_a().to_array());
+        assert_eq!(3.0, v.xxx_a().element_sum());
+        assert_eq!(27.0, v.zzz_a().length_squared());
+    });
+
+    /// Reference `f32` to half conversion, rounding to nearest even in `f64` arithmetic.
+    fn f16_from_f32_reference(v: f32) -> u16 {
+        fn round_ties_even(q: f64) -> f64 {
+            let r = q.floor();
+            let d = q - r;
+            if d > 0.5 || (d == 0.5 && r % 2.0 != 0.0) {
+                r + 1.0
+            } else {
+                r
+            }
+        }
+        let sign = if v.is_sign_negative() { 0x8000 } else { 0 };
+        let a = (v as f64).abs();
+        if v.is_nan() {
+            return 0x7e00;
+        }
+        if a >= 65520.0 {
+            return sign | 0x7c00;
+        }
+        if a < 2f64.powi(-14) {
+            return sign | round_ties_even(a * 2f64.powi(24)) as u16;
+        }
+        let mut e = 15;
+        while 2f64.powi(e) > a {
+            e -= 1;
+        }
+        let q = round_ties_even(a * 2f64.powi(10 - e));
+        let (q, e) = if q == 2048.0 { (1024.0, e + 1) } else { (q, e) };
+        sign | (((e + 15) as u16) << 10) | (q as u16 - 1024)
+    }
+
+    /// Reference half to `f32` conversion.
+    fn f16_to_f32_reference(h: u16) -> f64 {
+        let sign = if h & 0x8000 != 0 { -1.0 } else { 1.0 };
+        let exp = ((h >> 10) & 0x1f) as i32;
+        let man = (h & 0x3ff) as f64;
+        sign * match exp {
+            0 => man * 2f64.powi(-24),
+            0x1f if man == 0.0 => f64::INFINITY,
+            0x1f => f64::NAN,
+            _ => (1024.0 + man) * 2f64.powi(exp - 25),
+        }
+    }
+
+    fn assert_f16_eq(expected: u16, actual: u16, input: f32) {
+        let is_nan = |h: u16| h & 0x7c00 == 0x7c00 && h & 0x3ff != 0;
+        if is_nan(expected) {
+            assert!(is_nan(actual), "{:?} -> {:#06x}", input, actual);
+        } else {
+            assert_eq!(expected, actual, "{:?} -> {:#06x}", input, actual);
+        }
+    }
+
+    glam_test!(test_f16_array, {
+        assert_eq!(
+            Vec4::new(1.0, -2.0, 0.5, 65504.0).to_f16_array(),
+            [0x3c00, 0xc000, 0x3800, 0x7bff]
+        );
+        assert_eq!(
+            Vec4::from_f16_array([0x3c00, 0xc000, 0x3800, 0x7bff]),
+            Vec4::new(1.0, -2.0, 0.5, 65504.0)
+        );
+        // overflow, underflow and signed zero
+        assert_eq!(
+            Vec4::new(65520.0, f32::NEG_INFINITY, 1e-8, -0.0).to_f16_array(),
+            [0x7c00, 0xfc00, 0x0000, 0x8000]
+        );
+        // smallest subnormal and largest subnormal rounding up to the smallest normal
+        assert_eq!(
+            Vec4::new(2f32.powi(-24), -(2f32.powi(-14) - 2f32.powi(-26)), 0.0, 0.0).to_f16_array(),
+            [0x0001, 0x8400, 0x0000, 0x0000]
+        );
+        let nan = Vec4::new(f32::NAN, 0.0, 0.0, 0.0).to_f16_array();
+        assert!(Vec4::from_f16_array(nan).x.is_nan());
+
+        // every half converts to `f32` exactly and back to the same half
+        for h in 0..=u16::MAX {
+            let v = Vec4::from_f16_array([h, !h, h ^ 0x8000, h.rotate_left(8)]);
+            for (h, v) in [h, !h, h ^ 0x8000, h.rotate_left(8)]
+                .iter()
+                .zip(v.to_array())
+            {
+                let expected = f16_to_f32_reference(*h);
+                assert!(
+                    expected == v as f64 || (expected.is_nan() && v.is_nan()),
+                    "{:#06x} -> {:?}",
+                    h,
+                    v
+                );
+                assert_f16_eq(*h, Vec4::splat(v).to_f16_array()[0], v);
+            }
+        }
+
+        // values half way between adjacent halves and their neighbours round to nearest even
+        for h in 0..0x7c00 {
+            let lo = f16_to_f32_reference(h);
+            let hi = f16_to_f32_reference(h + 1);
+            let mid = ((lo + hi) * 0.5) as f32;
+            let below = f32::from_bits(mid.to_bits() - 1);
+            let above = f32::from_bits(mid.to_bits() + 1);
+            let v = Vec4::new(mid, -mid, below, above);
+            for (input, actual) in v.to_array().iter().zip(v.to_f16_array()) {
+                assert_f16_eq(f16_from_f32_reference(*input), actual, *input);
+            }
+        }
+
+        // a sweep over all `f32` bit patterns
+        for bits in (0..=u32::MAX).step_by(65_537) {
+            let input = f32::from_bits(bits);
+            let actual = Vec4::splat(input).to_f16_array()[3];
+            assert_f16_eq(f16_from_f32_reference(input), actual, input);
+        }
+    });
+
     #[cfg(all(
         any(target_feature = "sse2", target_feature = "simd128"),
         not(feature = "scalar-math")
@@ -1380,6 +2441,34 @@ mod dvec4 {
 mod ivec4 {
     use glam::{ivec4, BVec4, IVec2, IVec3, IVec4, UVec4};
 
+    glam_test!(test_ord_negative, {
+        // negative components order before positive ones, `x` first
+        let mut v = vec![
+            ivec4(1, -1, 0, 0),
+            ivec4(-1, 1, 0, 0),
+            ivec4(-1, -2, 0, 0),
+            ivec4(0, 0, 0, 0),
+            ivec4(-2, 5, 0, 0),
+            ivec4(-1, -2, -1, 0),
+        ];
+        v.sort();
+        assert_eq!(
+            v,
+            [
+                ivec4(-2, 5, 0, 0),
+                ivec4(-1, -2, -1, 0),
+                ivec4(-1, -2, 0, 0),
+                ivec4(-1, 1, 0, 0),
+                ivec4(0, 0, 0, 0),
+                ivec4(1, -1, 0, 0),
+            ]
+        );
+
+        let set: std::collections::BTreeSet<IVec4> = v.into_iter().collect();
+        assert_eq!(Some(&ivec4(-2, 5, 0, 0)), set.iter().next());
+        assert_eq!(Some(&ivec4(1, -1, 0, 0)), set.iter().next_back());
+    });
+
     glam_test!(test_align, {
         use std::mem;
         assert_eq!(16, mem::size_of::<IVec4>());
@@ -1393,6 +2482,7 @@ mod ivec4 {
 
     impl_vec4_signed_tests!(i32, ivec4, IVec4, IVec3, IVec2, BVec4);
     impl_vec4_eq_hash_tests!(i32, ivec4);
+    impl_vec4_int_tests!(i32, ivec4, IVec4);
 
     impl_vec4_scalar_shift_op_tests!(IVec4, -2, 2);
     impl_vec4_shift_op_tests!(IVec4);
@@ -1417,6 +2507,7 @@ mod uvec4 {
 
     impl_vec4_tests!(u32, uvec4, UVec4, UVec3, UVec2, BVec4);
     impl_vec4_eq_hash_tests!(u32, uvec4);
+    impl_vec4_int_tests!(u32, uvec4, UVec4);
 
     impl_vec4_scalar_shift_op_tests!(UVec4, 0, 2);
     impl_vec4_shift_op_tests!(UVec4);
@@ -1441,6 +2532,7 @@ mod i64vec4 {
 
     impl_vec4_signed_tests!(i64, i64vec4, I64Vec4, I64Vec3, I64Vec2, BVec4);
     impl_vec4_eq_hash_tests!(i64, i64vec4);
+    impl_vec4_int_tests!(i64, i64vec4, I64Vec4);
 
     impl_vec4_scalar_shift_op_tests!(I64Vec4, -2, 2);
     impl_vec4_shift_op_tests!(I64Vec4);
@@ -1465,6 +2557,7 @@ mod u64vec4 {
 
     impl_vec4_tests!(u64, u64vec4, U64Vec4, U64Vec3, U64Vec2, BVec4);
     impl_vec4_eq_hash_tests!(u64, u64vec4);
+    impl_vec4_int_tests!(u64, u64vec4, U64Vec4);
 
     impl_vec4_scalar_shift_op_tests!(U64Vec4, 0, 2);
     impl_vec4_shift_op_tests!(U64Vec4);